@@ -0,0 +1,251 @@
+use crate::core::microfacet::TrowbridgeReitzDistribution;
+
+// --- Microfacet Albedo Tables (Multiple-Scattering Compensation) ---
+// Directional albedo E(mu) of single-scattering GGX BxDFs, which falls
+// short of 1 as roughness grows because light bouncing between microfacets
+// is dropped. The Kulla-Conty lobes give the missing 1 - E back.
+// Tables are indexed by mu = cos(theta_o), r = sqrt(alpha) (the roughness
+// passed to TrowbridgeReitzDistribution::new) and, for dielectrics, the
+// relative IOR. They live in albedo_tables.rs, the output of the test
+// module's `tables_source()` (Monte Carlo with fixed seeds); run the
+// ignored `regenerate_tables` test to rewrite it.
+
+pub const N_MU: usize = 32;
+pub const N_ROUGH: usize = 32;
+pub const N_DIELECTRIC_MU: usize = 16;
+pub const N_DIELECTRIC_ROUGH: usize = 16;
+pub const N_ETA: usize = 16;
+const ETA_MAX: f32 = 3.0;
+
+// Measured values can land on digits clippy mistakes for constants like pi / 4
+#[allow(clippy::approx_constant)]
+mod tables {
+    use super::{N_DIELECTRIC_MU, N_DIELECTRIC_ROUGH, N_ETA, N_MU, N_ROUGH};
+    include!("albedo_tables.rs");
+}
+use tables::*;
+
+// Grid coordinate of x in [0, max] over n points: (lower index, fraction)
+fn grid(x: f32, max: f32, n: usize) -> (usize, f32) {
+    let t = (x / max).clamp(0.0, 1.0) * (n - 1) as f32;
+    let i = (t as usize).min(n - 2);
+    (i, t - i as f32)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn lookup_2d<const A: usize, const B: usize>(table: &[[f32; B]; A], a: (usize, f32), b: (usize, f32)) -> f32 {
+    let row = |i: usize| lerp(table[i][b.0], table[i][b.0 + 1], b.1);
+    lerp(row(a.0), row(a.0 + 1), a.1)
+}
+
+// Roughness coordinate of a distribution; anisotropic lobes use the mean alpha
+fn rough_coord(distribution: &TrowbridgeReitzDistribution) -> f32 {
+    (distribution.alpha_x * distribution.alpha_y).sqrt().sqrt()
+}
+
+/// Albedo of a GGX reflector with Fresnel = 1, seen from cos(theta) = mu
+pub fn ggx_albedo(distribution: &TrowbridgeReitzDistribution, mu: f32) -> f32 {
+    let r = grid(rough_coord(distribution), 1.0, N_ROUGH);
+    lookup_2d(&GGX_ALBEDO, r, grid(mu.abs(), 1.0, N_MU))
+}
+
+/// Cosine-weighted hemispherical average of `ggx_albedo`
+pub fn ggx_average_albedo(distribution: &TrowbridgeReitzDistribution) -> f32 {
+    let (i, t) = grid(rough_coord(distribution), 1.0, N_ROUGH);
+    lerp(GGX_AVERAGE_ALBEDO[i], GGX_AVERAGE_ALBEDO[i + 1], t)
+}
+
+// Table and coordinates for a dielectric seen from the side of wo: `eta`
+// is inside / outside, and wo below the surface looks out through 1 / eta
+fn dielectric_coords(distribution: &TrowbridgeReitzDistribution, eta: f32, inside: bool) -> (bool, (usize, f32), (usize, f32)) {
+    let (eta, inside) = if eta < 1.0 { (1.0 / eta, !inside) } else { (eta, inside) };
+    let e = grid(eta - 1.0, ETA_MAX - 1.0, N_ETA);
+    let r = grid(rough_coord(distribution), 1.0, N_DIELECTRIC_ROUGH);
+    (inside, e, r)
+}
+
+/// Reflection + transmission albedo (energy, i.e. importance transport)
+/// of a rough dielectric of relative IOR `eta`, from cos(theta_o) = mu;
+/// negative mu looks from inside
+pub fn dielectric_albedo(distribution: &TrowbridgeReitzDistribution, eta: f32, mu: f32) -> f32 {
+    let (inside, e, r) = dielectric_coords(distribution, eta, mu < 0.0);
+    let table = if inside { &DIELECTRIC_ALBEDO_INSIDE } else { &DIELECTRIC_ALBEDO_OUTSIDE };
+    let m = grid(mu.abs(), 1.0, N_DIELECTRIC_MU);
+    let plane = |i: usize| lookup_2d(&table[i], r, m);
+    lerp(plane(e.0), plane(e.0 + 1), e.1)
+}
+
+/// Cosine-weighted average of `dielectric_albedo` over one side
+pub fn dielectric_average_albedo(distribution: &TrowbridgeReitzDistribution, eta: f32, inside: bool) -> f32 {
+    let (inside, e, r) = dielectric_coords(distribution, eta, inside);
+    let table = if inside { &DIELECTRIC_AVERAGE_INSIDE } else { &DIELECTRIC_AVERAGE_OUTSIDE };
+    lookup_2d(table, e, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use super::*;
+    use crate::core::bsdf::{DielectricBxDF, TransportMode};
+    use crate::core::geometry::{Point2, Vector3};
+    use crate::core::math::RNG;
+
+    // --- Table Generation ---
+
+    fn grid_value(i: usize, max: f32, n: usize) -> f32 {
+        max * i as f32 / (n - 1) as f32
+    }
+
+    // Stratified Monte Carlo average of `weight` over [0,1)^2, n x n strata
+    fn stratified(seed: u64, n: usize, mut weight: impl FnMut(Point2) -> f32) -> f32 {
+        let mut rng = RNG::new(seed, 0);
+        let mut sum = 0.0f64;
+        for y in 0..n {
+            for x in 0..n {
+                let u = Point2 {
+                    x: ((x as f32 + rng.next_f32()) / n as f32).min(1.0 - f32::EPSILON),
+                    y: ((y as f32 + rng.next_f32()) / n as f32).min(1.0 - f32::EPSILON),
+                };
+                sum += weight(u) as f64;
+            }
+        }
+        (sum / (n * n) as f64) as f32
+    }
+
+    fn direction(mu: f32) -> Vector3 {
+        let mu = mu.clamp(-1.0, 1.0);
+        let mu = if mu.abs() < 1e-3 { 1e-3f32.copysign(mu) } else { mu };
+        Vector3 { x: (1.0 - mu * mu).sqrt(), y: 0.0, z: mu }
+    }
+
+    // 2 * integral of E(mu) mu dmu over the grid (trapezoid rule)
+    fn average(e: &[f32]) -> f32 {
+        let n = e.len();
+        let h = 1.0 / (n - 1) as f32;
+        (0..n - 1)
+            .map(|i| {
+                let (m0, m1) = (grid_value(i, 1.0, n), grid_value(i + 1, 1.0, n));
+                h * (e[i] * m0 + e[i + 1] * m1)
+            })
+            .sum()
+    }
+
+    fn mc_ggx_albedo(r: f32, mu: f32, seed: u64) -> f32 {
+        let distribution = TrowbridgeReitzDistribution::new(r, r);
+        let wo = direction(mu);
+        // VNDF sampling: the estimator of E is G2 / G1(wo)
+        stratified(seed, 128, |u| {
+            let wh = distribution.sample_wh(wo, u);
+            let wi = wh * (2.0 * wo.dot(wh)) - wo;
+            if wi.z <= 0.0 { 0.0 } else { distribution.g(wo, wi) / distribution.g1(wo) }
+        })
+    }
+
+    fn mc_dielectric_albedo(eta: f32, r: f32, mu: f32, seed: u64) -> f32 {
+        let bxdf = DielectricBxDF::new(eta, TrowbridgeReitzDistribution::new(r, r));
+        let wo = direction(mu);
+        stratified(seed, 128, |u| match bxdf.sample_f(wo, u, TransportMode::Importance) {
+            Some((f, wi, pdf, _)) if pdf > 0.0 => f.values[0] * wi.z.abs() / pdf,
+            _ => 0.0,
+        })
+    }
+
+    fn write_array(out: &mut String, v: &[f32]) {
+        out.push('[');
+        for (i, x) in v.iter().enumerate() {
+            if i > 0 { out.push_str(", "); }
+            let _ = write!(out, "{:.5}", x);
+        }
+        out.push(']');
+    }
+
+    // Rust source of albedo_tables.rs, computed from scratch
+    fn tables_source() -> String {
+        let mut out = String::from("// Generated by albedo::tables_source(); do not edit.\n\n");
+
+        let ggx: Vec<Vec<f32>> = (0..N_ROUGH)
+            .map(|i| {
+                (0..N_MU)
+                    .map(|j| mc_ggx_albedo(grid_value(i, 1.0, N_ROUGH), grid_value(j, 1.0, N_MU), (i * N_MU + j) as u64))
+                    .collect()
+            })
+            .collect();
+        out.push_str("pub const GGX_ALBEDO: [[f32; N_MU]; N_ROUGH] = [\n");
+        for row in &ggx {
+            out.push_str("    ");
+            write_array(&mut out, row);
+            out.push_str(",\n");
+        }
+        out.push_str("];\n\npub const GGX_AVERAGE_ALBEDO: [f32; N_ROUGH] = ");
+        write_array(&mut out, &ggx.iter().map(|row| average(row)).collect::<Vec<_>>());
+        out.push_str(";\n");
+
+        for (name, sign) in [("OUTSIDE", 1.0f32), ("INSIDE", -1.0)] {
+            let mut averages = Vec::new();
+            let _ = write!(out, "\npub const DIELECTRIC_ALBEDO_{name}: [[[f32; N_DIELECTRIC_MU]; N_DIELECTRIC_ROUGH]; N_ETA] = [\n");
+            for e in 0..N_ETA {
+                let eta = 1.0 + grid_value(e, ETA_MAX - 1.0, N_ETA);
+                out.push_str("    [\n");
+                let mut row_averages = Vec::new();
+                for r in 0..N_DIELECTRIC_ROUGH {
+                    let rough = grid_value(r, 1.0, N_DIELECTRIC_ROUGH);
+                    let row: Vec<f32> = (0..N_DIELECTRIC_MU)
+                        .map(|m| {
+                            let seed = ((e * N_DIELECTRIC_ROUGH + r) * N_DIELECTRIC_MU + m) as u64;
+                            mc_dielectric_albedo(eta, rough, sign * grid_value(m, 1.0, N_DIELECTRIC_MU), seed)
+                        })
+                        .collect();
+                    row_averages.push(average(&row));
+                    out.push_str("        ");
+                    write_array(&mut out, &row);
+                    out.push_str(",\n");
+                }
+                averages.push(row_averages);
+                out.push_str("    ],\n");
+            }
+            out.push_str("];\n");
+            let _ = write!(out, "\npub const DIELECTRIC_AVERAGE_{name}: [[f32; N_DIELECTRIC_ROUGH]; N_ETA] = [\n");
+            for row in &averages {
+                out.push_str("    ");
+                write_array(&mut out, row);
+                out.push_str(",\n");
+            }
+            out.push_str("];\n");
+        }
+        out
+    }
+
+    #[test]
+    fn tables_match_monte_carlo() {
+        // Fresh seeds, so this checks the estimate rather than replaying it
+        for (i, j) in [(4, 8), (16, 16), (28, 2)] {
+            let r = grid_value(i, 1.0, N_ROUGH);
+            let mu = grid_value(j, 1.0, N_MU);
+            let expected = mc_ggx_albedo(r, mu, 1000 + i as u64);
+            let got = ggx_albedo(&TrowbridgeReitzDistribution::new(r, r), mu);
+            assert!((got - expected).abs() < 0.01, "GGX r = {}, mu = {}: table {} vs {}", r, mu, got, expected);
+        }
+        for (e, r, m) in [(4, 4, 8), (10, 12, 3)] {
+            let eta = 1.0 + grid_value(e, ETA_MAX - 1.0, N_ETA);
+            let rough = grid_value(r, 1.0, N_DIELECTRIC_ROUGH);
+            let distribution = TrowbridgeReitzDistribution::new(rough, rough);
+            for sign in [1.0, -1.0] {
+                let mu = sign * grid_value(m, 1.0, N_DIELECTRIC_MU);
+                let expected = mc_dielectric_albedo(eta, rough, mu, 2000 + e as u64);
+                let got = dielectric_albedo(&distribution, eta, mu);
+                assert!((got - expected).abs() < 0.01, "eta = {}, r = {}, mu = {}: table {} vs {}", eta, rough, mu, got, expected);
+            }
+        }
+    }
+
+    #[test]
+    #[ignore = "slow; rewrites src/core/albedo_tables.rs"]
+    fn regenerate_tables() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/core/albedo_tables.rs");
+        std::fs::write(path, tables_source()).expect("writing albedo_tables.rs");
+    }
+}
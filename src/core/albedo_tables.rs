@@ -0,0 +1,658 @@
+// Generated by albedo::tables_source(); do not edit.
+
+pub const GGX_ALBEDO: [[f32; N_MU]; N_ROUGH] = [
+    [0.89202, 0.99934, 0.99982, 0.99991, 0.99998, 0.99999, 0.99999, 0.99987, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 0.99994, 1.00000, 1.00000, 1.00000, 1.00000],
+    [0.89271, 0.99948, 0.99993, 0.99997, 0.99998, 0.99999, 0.99999, 0.99999, 1.00000, 1.00000, 1.00000, 1.00000, 0.99994, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+    [0.95415, 0.99019, 0.99748, 0.99910, 0.99931, 0.99966, 0.99958, 0.99991, 0.99976, 0.99995, 0.99990, 0.99997, 0.99991, 0.99998, 0.99998, 0.99999, 0.99999, 0.99999, 0.99987, 0.99999, 0.99999, 0.99999, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 0.99994, 1.00000],
+    [0.97831, 0.95470, 0.98727, 0.99456, 0.99736, 0.99774, 0.99874, 0.99916, 0.99937, 0.99938, 0.99932, 0.99951, 0.99981, 0.99977, 0.99973, 0.99956, 0.99969, 0.99977, 0.99989, 0.99978, 0.99991, 0.99985, 0.99973, 0.99992, 0.99992, 0.99999, 0.99993, 0.99981, 0.99987, 0.99994, 0.99988, 0.99994],
+    [0.98742, 0.91118, 0.96249, 0.98197, 0.98994, 0.99340, 0.99574, 0.99657, 0.99750, 0.99793, 0.99867, 0.99848, 0.99895, 0.99919, 0.99922, 0.99907, 0.99928, 0.99934, 0.99946, 0.99964, 0.99959, 0.99970, 0.99944, 0.99963, 0.99971, 0.99978, 0.99966, 0.99973, 0.99967, 0.99987, 0.99975, 0.99982],
+    [0.99189, 0.89174, 0.92912, 0.95964, 0.97553, 0.98390, 0.98872, 0.99204, 0.99372, 0.99487, 0.99587, 0.99653, 0.99711, 0.99727, 0.99773, 0.99794, 0.99814, 0.99838, 0.99822, 0.99861, 0.99862, 0.99897, 0.99908, 0.99867, 0.99905, 0.99903, 0.99902, 0.99926, 0.99921, 0.99966, 0.99909, 0.99930],
+    [0.99427, 0.89403, 0.90326, 0.93169, 0.95368, 0.96797, 0.97636, 0.98261, 0.98671, 0.98952, 0.99118, 0.99202, 0.99369, 0.99438, 0.99552, 0.99491, 0.99648, 0.99674, 0.99725, 0.99714, 0.99737, 0.99746, 0.99756, 0.99833, 0.99797, 0.99765, 0.99828, 0.99837, 0.99816, 0.99859, 0.99901, 0.99854],
+    [0.99567, 0.90508, 0.89039, 0.90757, 0.92902, 0.94636, 0.95960, 0.96847, 0.97509, 0.97979, 0.98346, 0.98629, 0.98845, 0.99018, 0.99130, 0.99198, 0.99233, 0.99343, 0.99444, 0.99497, 0.99473, 0.99514, 0.99552, 0.99581, 0.99578, 0.99601, 0.99671, 0.99628, 0.99697, 0.99707, 0.99651, 0.99747],
+    [0.99661, 0.91735, 0.88875, 0.89207, 0.90773, 0.92449, 0.93825, 0.95010, 0.95945, 0.96623, 0.97162, 0.97605, 0.97918, 0.98200, 0.98412, 0.98572, 0.98812, 0.98885, 0.98914, 0.99060, 0.99128, 0.99166, 0.99245, 0.99283, 0.99327, 0.99397, 0.99342, 0.99479, 0.99449, 0.99474, 0.99447, 0.99491],
+    [0.99701, 0.92822, 0.89313, 0.88512, 0.89187, 0.90419, 0.91753, 0.92977, 0.94026, 0.94910, 0.95618, 0.96205, 0.96692, 0.97098, 0.97428, 0.97694, 0.97946, 0.98122, 0.98271, 0.98386, 0.98540, 0.98633, 0.98759, 0.98807, 0.98883, 0.98968, 0.98977, 0.99029, 0.99090, 0.99119, 0.99165, 0.99180],
+    [0.99736, 0.93659, 0.89962, 0.88390, 0.88265, 0.88896, 0.89885, 0.90963, 0.92040, 0.92966, 0.93803, 0.94542, 0.95156, 0.95646, 0.96106, 0.96491, 0.96853, 0.97121, 0.97308, 0.97570, 0.97736, 0.97865, 0.98032, 0.98166, 0.98282, 0.98320, 0.98435, 0.98528, 0.98559, 0.98632, 0.98680, 0.98721],
+    [0.99756, 0.94303, 0.90642, 0.88603, 0.87804, 0.87862, 0.88422, 0.89206, 0.90105, 0.90998, 0.91858, 0.92627, 0.93329, 0.93942, 0.94485, 0.94976, 0.95400, 0.95751, 0.96070, 0.96366, 0.96626, 0.96860, 0.97066, 0.97222, 0.97379, 0.97516, 0.97617, 0.97722, 0.97837, 0.97904, 0.98042, 0.98100],
+    [0.99769, 0.94758, 0.91190, 0.88906, 0.87663, 0.87231, 0.87302, 0.87752, 0.88378, 0.89105, 0.89876, 0.90617, 0.91332, 0.92010, 0.92608, 0.93170, 0.93678, 0.94119, 0.94510, 0.94892, 0.95202, 0.95482, 0.95760, 0.96001, 0.96204, 0.96375, 0.96563, 0.96716, 0.96854, 0.96980, 0.97103, 0.97192],
+    [0.99766, 0.95055, 0.91571, 0.89162, 0.87623, 0.86800, 0.86478, 0.86559, 0.86873, 0.87405, 0.87997, 0.88639, 0.89289, 0.89946, 0.90540, 0.91121, 0.91687, 0.92185, 0.92655, 0.93067, 0.93458, 0.93808, 0.94150, 0.94427, 0.94711, 0.94932, 0.95163, 0.95380, 0.95574, 0.95733, 0.95899, 0.96037],
+    [0.99780, 0.95204, 0.91829, 0.89339, 0.87604, 0.86468, 0.85819, 0.85574, 0.85613, 0.85848, 0.86228, 0.86707, 0.87253, 0.87810, 0.88386, 0.88945, 0.89490, 0.90006, 0.90534, 0.90961, 0.91401, 0.91805, 0.92197, 0.92543, 0.92854, 0.93167, 0.93409, 0.93694, 0.93931, 0.94140, 0.94349, 0.94528],
+    [0.99762, 0.95259, 0.91926, 0.89408, 0.87504, 0.86163, 0.85263, 0.84728, 0.84475, 0.84454, 0.84606, 0.84900, 0.85248, 0.85705, 0.86171, 0.86658, 0.87173, 0.87656, 0.88137, 0.88606, 0.89055, 0.89491, 0.89919, 0.90308, 0.90663, 0.91014, 0.91327, 0.91647, 0.91930, 0.92205, 0.92431, 0.92655],
+    [0.99761, 0.95211, 0.91901, 0.89325, 0.87322, 0.85807, 0.84698, 0.83898, 0.83414, 0.83161, 0.83081, 0.83149, 0.83346, 0.83618, 0.83945, 0.84328, 0.84737, 0.85175, 0.85610, 0.86053, 0.86486, 0.86917, 0.87346, 0.87746, 0.88132, 0.88510, 0.88862, 0.89205, 0.89531, 0.89832, 0.90132, 0.90386],
+    [0.99743, 0.95085, 0.91743, 0.89106, 0.87010, 0.85344, 0.84066, 0.83084, 0.82378, 0.81883, 0.81604, 0.81465, 0.81464, 0.81557, 0.81736, 0.81983, 0.82276, 0.82608, 0.82951, 0.83348, 0.83722, 0.84108, 0.84508, 0.84906, 0.85283, 0.85658, 0.86023, 0.86387, 0.86724, 0.87070, 0.87367, 0.87674],
+    [0.99716, 0.94901, 0.91461, 0.88759, 0.86556, 0.84773, 0.83323, 0.82180, 0.81292, 0.80611, 0.80124, 0.79795, 0.79599, 0.79504, 0.79525, 0.79629, 0.79774, 0.79980, 0.80219, 0.80507, 0.80808, 0.81129, 0.81475, 0.81826, 0.82160, 0.82518, 0.82867, 0.83213, 0.83554, 0.83902, 0.84211, 0.84545],
+    [0.99711, 0.94641, 0.91087, 0.88274, 0.85978, 0.84082, 0.82490, 0.81192, 0.80135, 0.79279, 0.78604, 0.78101, 0.77716, 0.77459, 0.77308, 0.77231, 0.77236, 0.77285, 0.77417, 0.77584, 0.77783, 0.78011, 0.78260, 0.78524, 0.78822, 0.79125, 0.79431, 0.79736, 0.80048, 0.80359, 0.80675, 0.80985],
+    [0.99699, 0.94328, 0.90637, 0.87700, 0.85280, 0.83246, 0.81542, 0.80090, 0.78887, 0.77884, 0.77028, 0.76352, 0.75798, 0.75377, 0.75048, 0.74823, 0.74669, 0.74584, 0.74562, 0.74579, 0.74661, 0.74774, 0.74920, 0.75096, 0.75297, 0.75519, 0.75750, 0.76003, 0.76256, 0.76537, 0.76797, 0.77072],
+    [0.99647, 0.93992, 0.90106, 0.87020, 0.84459, 0.82305, 0.80456, 0.78877, 0.77522, 0.76362, 0.75370, 0.74529, 0.73831, 0.73241, 0.72761, 0.72370, 0.72053, 0.71823, 0.71649, 0.71539, 0.71480, 0.71468, 0.71485, 0.71560, 0.71640, 0.71756, 0.71905, 0.72065, 0.72253, 0.72434, 0.72643, 0.72866],
+    [0.99628, 0.93617, 0.89506, 0.86246, 0.83532, 0.81236, 0.79251, 0.77543, 0.76046, 0.74740, 0.73619, 0.72625, 0.71776, 0.71037, 0.70403, 0.69856, 0.69406, 0.69018, 0.68697, 0.68444, 0.68249, 0.68098, 0.67991, 0.67917, 0.67892, 0.67899, 0.67927, 0.67991, 0.68073, 0.68172, 0.68286, 0.68424],
+    [0.99619, 0.93184, 0.88824, 0.85382, 0.82504, 0.80049, 0.77938, 0.76088, 0.74458, 0.73028, 0.71758, 0.70635, 0.69634, 0.68755, 0.67979, 0.67295, 0.66700, 0.66167, 0.65703, 0.65311, 0.64981, 0.64697, 0.64452, 0.64258, 0.64102, 0.63980, 0.63897, 0.63832, 0.63795, 0.63785, 0.63811, 0.63847],
+    [0.99567, 0.92708, 0.88094, 0.84447, 0.81396, 0.78793, 0.76530, 0.74536, 0.72770, 0.71203, 0.69811, 0.68550, 0.67422, 0.66412, 0.65492, 0.64675, 0.63945, 0.63278, 0.62694, 0.62172, 0.61693, 0.61276, 0.60903, 0.60586, 0.60294, 0.60049, 0.59835, 0.59660, 0.59509, 0.59388, 0.59287, 0.59214],
+    [0.99533, 0.92248, 0.87328, 0.83438, 0.80213, 0.77440, 0.75023, 0.72892, 0.70993, 0.69297, 0.67768, 0.66385, 0.65131, 0.63986, 0.62958, 0.62011, 0.61149, 0.60373, 0.59662, 0.58998, 0.58403, 0.57865, 0.57367, 0.56921, 0.56514, 0.56145, 0.55814, 0.55515, 0.55242, 0.55004, 0.54791, 0.54600],
+    [0.99542, 0.91726, 0.86504, 0.82389, 0.78961, 0.76017, 0.73440, 0.71166, 0.69134, 0.67306, 0.65645, 0.64148, 0.62778, 0.61523, 0.60372, 0.59316, 0.58334, 0.57442, 0.56610, 0.55838, 0.55131, 0.54471, 0.53870, 0.53305, 0.52784, 0.52306, 0.51853, 0.51449, 0.51070, 0.50712, 0.50387, 0.50090],
+    [0.99474, 0.91193, 0.85653, 0.81282, 0.77658, 0.74534, 0.71798, 0.69369, 0.67212, 0.65257, 0.63474, 0.61857, 0.60375, 0.59014, 0.57748, 0.56583, 0.55506, 0.54500, 0.53572, 0.52704, 0.51890, 0.51134, 0.50426, 0.49764, 0.49143, 0.48558, 0.48008, 0.47493, 0.47014, 0.46560, 0.46139, 0.45739],
+    [0.99409, 0.90630, 0.84751, 0.80139, 0.76296, 0.72988, 0.70103, 0.67536, 0.65231, 0.63151, 0.61259, 0.59530, 0.57941, 0.56478, 0.55109, 0.53851, 0.52673, 0.51587, 0.50560, 0.49600, 0.48699, 0.47850, 0.47057, 0.46305, 0.45592, 0.44929, 0.44295, 0.43691, 0.43126, 0.42587, 0.42074, 0.41593],
+    [0.99407, 0.90039, 0.83841, 0.78955, 0.74901, 0.71425, 0.68360, 0.65651, 0.63219, 0.61017, 0.59006, 0.57173, 0.55487, 0.53926, 0.52480, 0.51126, 0.49867, 0.48707, 0.47588, 0.46550, 0.45571, 0.44651, 0.43785, 0.42963, 0.42181, 0.41438, 0.40732, 0.40063, 0.39433, 0.38825, 0.38245, 0.37693],
+    [0.99330, 0.89434, 0.82880, 0.77740, 0.73468, 0.69797, 0.66587, 0.63735, 0.61178, 0.58861, 0.56745, 0.54819, 0.53040, 0.51387, 0.49864, 0.48434, 0.47103, 0.45852, 0.44679, 0.43585, 0.42536, 0.41560, 0.40629, 0.39748, 0.38910, 0.38111, 0.37354, 0.36631, 0.35938, 0.35283, 0.34648, 0.34053],
+    [0.99299, 0.88810, 0.81927, 0.76504, 0.72016, 0.68162, 0.64793, 0.61801, 0.59127, 0.56695, 0.54481, 0.52455, 0.50597, 0.48877, 0.47277, 0.45780, 0.44385, 0.43077, 0.41850, 0.40698, 0.39608, 0.38579, 0.37600, 0.36670, 0.35796, 0.34965, 0.34168, 0.33410, 0.32680, 0.31983, 0.31318, 0.30687],
+];
+
+pub const GGX_AVERAGE_ALBEDO: [f32; N_ROUGH] = [0.99999, 1.00000, 0.99993, 0.99957, 0.99878, 0.99717, 0.99473, 0.99096, 0.98585, 0.97891, 0.97022, 0.95936, 0.94632, 0.93097, 0.91325, 0.89324, 0.87092, 0.84635, 0.81978, 0.79133, 0.76132, 0.72998, 0.69759, 0.66447, 0.63100, 0.59742, 0.56407, 0.53121, 0.49906, 0.46788, 0.43780, 0.40899];
+
+pub const DIELECTRIC_ALBEDO_OUTSIDE: [[[f32; N_DIELECTRIC_MU]; N_DIELECTRIC_ROUGH]; N_ETA] = [
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95478, 0.99835, 0.99962, 0.99969, 0.99997, 1.00014, 1.00001, 1.00012, 0.99978, 0.99983, 0.99996, 0.99988, 1.00001, 0.99989, 0.99983, 1.00025],
+        [0.99093, 0.97269, 0.99424, 0.99773, 0.99925, 0.99965, 0.99986, 0.99961, 0.99954, 0.99968, 0.99982, 1.00023, 0.99973, 0.99934, 1.00027, 0.99988],
+        [0.99279, 0.93161, 0.97510, 0.99019, 0.99524, 0.99728, 0.99807, 0.99890, 0.99921, 0.99937, 0.99920, 0.99942, 0.99979, 1.00028, 0.99947, 0.99976],
+        [1.00129, 0.92247, 0.95040, 0.97304, 0.98543, 0.99130, 0.99393, 0.99593, 0.99686, 0.99766, 0.99763, 0.99913, 0.99919, 0.99910, 1.00022, 1.00016],
+        [0.98599, 0.92379, 0.93349, 0.95435, 0.96993, 0.97973, 0.98671, 0.99032, 0.99322, 0.99447, 0.99609, 0.99767, 0.99822, 0.99845, 0.99895, 0.99954],
+        [0.98158, 0.91673, 0.91897, 0.93530, 0.95136, 0.96507, 0.97392, 0.98055, 0.98584, 0.98922, 0.99220, 0.99467, 0.99648, 0.99719, 0.99826, 0.99934],
+        [0.97768, 0.89812, 0.89676, 0.91250, 0.92929, 0.94459, 0.95762, 0.96700, 0.97443, 0.98095, 0.98588, 0.98942, 0.99242, 0.99503, 0.99710, 0.99834],
+        [0.97309, 0.87112, 0.86836, 0.88306, 0.90234, 0.92049, 0.93599, 0.94925, 0.96034, 0.96929, 0.97609, 0.98245, 0.98717, 0.99177, 0.99456, 0.99693],
+        [0.97539, 0.84066, 0.83323, 0.84946, 0.87003, 0.89155, 0.91018, 0.92693, 0.94103, 0.95339, 0.96400, 0.97216, 0.98024, 0.98619, 0.99148, 0.99472],
+        [0.98423, 0.80956, 0.79826, 0.81259, 0.83410, 0.85759, 0.87987, 0.89927, 0.91855, 0.93438, 0.94799, 0.96029, 0.97026, 0.97932, 0.98616, 0.99250],
+        [0.98831, 0.78076, 0.76502, 0.77596, 0.79764, 0.82167, 0.84596, 0.86974, 0.89099, 0.91095, 0.92882, 0.94398, 0.95768, 0.97056, 0.98067, 0.98869],
+        [0.96930, 0.75491, 0.73297, 0.74186, 0.76223, 0.78655, 0.81181, 0.83764, 0.86154, 0.88555, 0.90475, 0.92518, 0.94340, 0.95905, 0.97389, 0.98393],
+        [0.96417, 0.72996, 0.70567, 0.71170, 0.72960, 0.75299, 0.77825, 0.80460, 0.83131, 0.85625, 0.88099, 0.90333, 0.92537, 0.94611, 0.96448, 0.97901],
+        [0.97156, 0.70909, 0.68054, 0.68443, 0.70058, 0.72196, 0.74645, 0.77274, 0.79942, 0.82630, 0.85303, 0.87976, 0.90508, 0.93005, 0.95270, 0.97159],
+        [0.96148, 0.68857, 0.65889, 0.66081, 0.67467, 0.69394, 0.71787, 0.74293, 0.76899, 0.79716, 0.82488, 0.85344, 0.88282, 0.91147, 0.94018, 0.96400],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.96034, 0.99825, 0.99945, 0.99985, 0.99990, 1.00006, 1.00006, 0.99998, 0.99999, 1.00000, 0.99961, 0.99956, 1.00014, 1.00001, 1.00022, 1.00006],
+        [0.98916, 0.97003, 0.99417, 0.99740, 0.99884, 0.99934, 0.99959, 0.99980, 0.99975, 0.99956, 0.99965, 0.99980, 0.99969, 0.99969, 0.99948, 1.00015],
+        [0.99192, 0.92649, 0.97197, 0.98847, 0.99467, 0.99694, 0.99816, 0.99906, 0.99932, 0.99930, 0.99936, 0.99969, 0.99918, 0.99964, 0.99972, 1.00018],
+        [0.99117, 0.91979, 0.94505, 0.97038, 0.98339, 0.99049, 0.99345, 0.99548, 0.99692, 0.99746, 0.99851, 0.99898, 0.99906, 0.99930, 0.99965, 0.99941],
+        [1.00146, 0.92656, 0.93069, 0.95077, 0.96763, 0.97763, 0.98519, 0.98988, 0.99214, 0.99457, 0.99569, 0.99724, 0.99766, 0.99791, 0.99885, 0.99974],
+        [0.99304, 0.92813, 0.92339, 0.93423, 0.95055, 0.96242, 0.97299, 0.98017, 0.98466, 0.98874, 0.99152, 0.99332, 0.99522, 0.99589, 0.99747, 0.99766],
+        [0.99465, 0.91913, 0.91107, 0.91953, 0.93241, 0.94492, 0.95721, 0.96628, 0.97434, 0.98028, 0.98391, 0.98795, 0.99064, 0.99360, 0.99523, 0.99642],
+        [0.99274, 0.90066, 0.89273, 0.89918, 0.91130, 0.92481, 0.93851, 0.95018, 0.96020, 0.96732, 0.97409, 0.98002, 0.98477, 0.98832, 0.99152, 0.99380],
+        [0.98360, 0.87929, 0.86611, 0.87203, 0.88493, 0.89963, 0.91480, 0.92887, 0.94118, 0.95244, 0.96113, 0.96873, 0.97637, 0.98228, 0.98744, 0.99011],
+        [0.99192, 0.85264, 0.83537, 0.83992, 0.85358, 0.87027, 0.88745, 0.90402, 0.91811, 0.93243, 0.94450, 0.95557, 0.96464, 0.97298, 0.98000, 0.98572],
+        [0.97752, 0.82969, 0.80424, 0.80671, 0.81950, 0.83618, 0.85489, 0.87436, 0.89161, 0.90845, 0.92355, 0.93847, 0.95111, 0.96201, 0.97149, 0.97866],
+        [0.98133, 0.80446, 0.77245, 0.77310, 0.78444, 0.80163, 0.82061, 0.84172, 0.86254, 0.88200, 0.90069, 0.91746, 0.93385, 0.94823, 0.96146, 0.97037],
+        [0.98848, 0.78013, 0.74433, 0.74133, 0.75040, 0.76689, 0.78653, 0.80840, 0.82991, 0.85113, 0.87285, 0.89370, 0.91341, 0.93121, 0.94847, 0.96088],
+        [0.98928, 0.75759, 0.71784, 0.71106, 0.71936, 0.73454, 0.75317, 0.77375, 0.79674, 0.82025, 0.84321, 0.86746, 0.88945, 0.91223, 0.93281, 0.94945],
+        [0.98813, 0.73558, 0.69366, 0.68482, 0.69034, 0.70353, 0.72136, 0.74136, 0.76406, 0.78753, 0.81167, 0.83792, 0.86333, 0.88946, 0.91516, 0.93501],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95493, 0.99798, 0.99966, 1.00010, 0.99990, 0.99995, 1.00000, 0.99996, 0.99994, 0.99996, 1.00008, 0.99994, 0.99993, 1.00028, 1.00000, 0.99992],
+        [0.99038, 0.97012, 0.99359, 0.99793, 0.99887, 0.99948, 0.99926, 0.99984, 0.99999, 0.99982, 0.99973, 0.99996, 0.99996, 1.00025, 0.99998, 0.99982],
+        [0.99696, 0.92463, 0.97031, 0.98804, 0.99375, 0.99627, 0.99812, 0.99910, 0.99900, 0.99952, 0.99936, 0.99953, 0.99959, 0.99986, 0.99972, 0.99991],
+        [1.00054, 0.91783, 0.94258, 0.96806, 0.98224, 0.98927, 0.99333, 0.99642, 0.99694, 0.99774, 0.99843, 0.99877, 0.99900, 0.99905, 0.99964, 0.99944],
+        [0.99502, 0.92656, 0.92804, 0.94846, 0.96511, 0.97729, 0.98530, 0.98958, 0.99214, 0.99391, 0.99564, 0.99654, 0.99704, 0.99823, 0.99845, 0.99881],
+        [0.99353, 0.93195, 0.92312, 0.93381, 0.94947, 0.96196, 0.97240, 0.97902, 0.98503, 0.98828, 0.99063, 0.99247, 0.99457, 0.99575, 0.99690, 0.99705],
+        [0.98934, 0.92548, 0.91623, 0.92162, 0.93287, 0.94503, 0.95592, 0.96555, 0.97307, 0.97854, 0.98353, 0.98716, 0.98985, 0.99183, 0.99381, 0.99489],
+        [0.99703, 0.91656, 0.90122, 0.90404, 0.91445, 0.92656, 0.93721, 0.94897, 0.95791, 0.96555, 0.97280, 0.97826, 0.98250, 0.98601, 0.98877, 0.99227],
+        [0.99463, 0.89662, 0.87953, 0.88161, 0.89067, 0.90317, 0.91649, 0.92805, 0.93942, 0.94977, 0.95872, 0.96666, 0.97293, 0.97792, 0.98318, 0.98570],
+        [0.98515, 0.87371, 0.85248, 0.85350, 0.86270, 0.87528, 0.89074, 0.90327, 0.91735, 0.92954, 0.94104, 0.95093, 0.95976, 0.96786, 0.97502, 0.97867],
+        [0.98881, 0.85168, 0.82379, 0.82136, 0.82987, 0.84317, 0.85926, 0.87424, 0.89020, 0.90528, 0.91842, 0.93220, 0.94400, 0.95419, 0.96266, 0.96992],
+        [0.98530, 0.82692, 0.79361, 0.78859, 0.79568, 0.80861, 0.82487, 0.84133, 0.85952, 0.87650, 0.89415, 0.90877, 0.92376, 0.93758, 0.94932, 0.95854],
+        [0.99081, 0.80361, 0.76475, 0.75585, 0.76135, 0.77353, 0.78922, 0.80725, 0.82596, 0.84535, 0.86456, 0.88340, 0.90105, 0.91814, 0.93343, 0.94449],
+        [0.98269, 0.78219, 0.73845, 0.72569, 0.72945, 0.73927, 0.75471, 0.77259, 0.79127, 0.81166, 0.83290, 0.85374, 0.87418, 0.89463, 0.91438, 0.92866],
+        [0.99062, 0.76231, 0.71212, 0.69729, 0.69822, 0.70681, 0.72032, 0.73753, 0.75657, 0.77785, 0.79921, 0.82180, 0.84532, 0.86917, 0.89226, 0.91045],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95814, 0.99786, 0.99949, 0.99993, 0.99979, 1.00006, 0.99999, 1.00007, 1.00002, 0.99996, 0.99996, 0.99999, 0.99992, 1.00003, 1.00004, 0.99996],
+        [0.98734, 0.96883, 0.99363, 0.99744, 0.99917, 0.99904, 0.99939, 0.99994, 0.99976, 0.99981, 0.99983, 0.99991, 1.00002, 0.99996, 0.99995, 0.99997],
+        [0.99560, 0.92360, 0.96958, 0.98699, 0.99384, 0.99709, 0.99750, 0.99809, 0.99907, 0.99937, 0.99967, 0.99947, 0.99953, 0.99986, 0.99965, 0.99975],
+        [0.99850, 0.91748, 0.94098, 0.96688, 0.98223, 0.98972, 0.99334, 0.99580, 0.99672, 0.99756, 0.99867, 0.99873, 0.99882, 0.99903, 0.99936, 0.99941],
+        [0.99462, 0.92688, 0.92795, 0.94743, 0.96447, 0.97595, 0.98378, 0.98893, 0.99129, 0.99420, 0.99523, 0.99660, 0.99720, 0.99784, 0.99832, 0.99842],
+        [0.99766, 0.93353, 0.92357, 0.93315, 0.94813, 0.96072, 0.97161, 0.97755, 0.98354, 0.98753, 0.99008, 0.99209, 0.99415, 0.99549, 0.99598, 0.99667],
+        [0.99675, 0.93108, 0.91843, 0.92172, 0.93240, 0.94404, 0.95549, 0.96443, 0.97131, 0.97715, 0.98200, 0.98511, 0.98829, 0.99045, 0.99232, 0.99326],
+        [0.99651, 0.92210, 0.90684, 0.90720, 0.91561, 0.92622, 0.93734, 0.94747, 0.95605, 0.96383, 0.97053, 0.97607, 0.98041, 0.98383, 0.98694, 0.98853],
+        [0.99804, 0.90454, 0.88699, 0.88676, 0.89402, 0.90425, 0.91608, 0.92772, 0.93789, 0.94674, 0.95550, 0.96226, 0.96876, 0.97403, 0.97914, 0.98234],
+        [0.99594, 0.88607, 0.86245, 0.86050, 0.86692, 0.87792, 0.89012, 0.90300, 0.91425, 0.92617, 0.93695, 0.94594, 0.95558, 0.96266, 0.96890, 0.97344],
+        [0.99644, 0.86458, 0.83507, 0.83071, 0.83563, 0.84660, 0.85884, 0.87340, 0.88748, 0.90099, 0.91368, 0.92611, 0.93664, 0.94661, 0.95503, 0.96119],
+        [0.98641, 0.84236, 0.80555, 0.79863, 0.80181, 0.81140, 0.82551, 0.83996, 0.85581, 0.87175, 0.88692, 0.90109, 0.91534, 0.92760, 0.93871, 0.94728],
+        [0.98531, 0.81846, 0.77773, 0.76611, 0.76755, 0.77563, 0.78960, 0.80486, 0.82158, 0.83856, 0.85592, 0.87273, 0.88961, 0.90531, 0.91973, 0.93043],
+        [0.98651, 0.79729, 0.74965, 0.73505, 0.73439, 0.74069, 0.75341, 0.76822, 0.78503, 0.80326, 0.82208, 0.84119, 0.86062, 0.87902, 0.89702, 0.91088],
+        [0.99287, 0.77638, 0.72374, 0.70563, 0.70263, 0.70744, 0.71759, 0.73209, 0.74876, 0.76692, 0.78652, 0.80738, 0.82739, 0.84938, 0.87116, 0.88843],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95901, 0.99829, 0.99982, 0.99978, 0.99996, 0.99988, 0.99996, 0.99999, 1.00001, 0.99998, 0.99999, 0.99999, 0.99998, 0.99997, 0.99999, 1.00000],
+        [0.99144, 0.96871, 0.99370, 0.99763, 0.99879, 0.99915, 0.99945, 0.99952, 0.99989, 0.99993, 0.99995, 0.99991, 0.99987, 0.99998, 0.99998, 0.99995],
+        [0.99885, 0.92310, 0.96880, 0.98653, 0.99378, 0.99647, 0.99840, 0.99871, 0.99939, 0.99945, 0.99923, 0.99936, 0.99941, 0.99976, 0.99978, 0.99978],
+        [1.00176, 0.91671, 0.94105, 0.96670, 0.98071, 0.98887, 0.99354, 0.99509, 0.99669, 0.99770, 0.99766, 0.99869, 0.99883, 0.99865, 0.99917, 0.99921],
+        [0.99760, 0.92741, 0.92675, 0.94569, 0.96346, 0.97600, 0.98372, 0.98839, 0.99127, 0.99337, 0.99470, 0.99630, 0.99698, 0.99742, 0.99791, 0.99834],
+        [0.98645, 0.93414, 0.92318, 0.93285, 0.94704, 0.96038, 0.96995, 0.97724, 0.98224, 0.98653, 0.98934, 0.99179, 0.99275, 0.99485, 0.99565, 0.99634],
+        [0.99822, 0.93339, 0.91962, 0.92274, 0.93255, 0.94303, 0.95437, 0.96290, 0.97069, 0.97582, 0.97993, 0.98372, 0.98667, 0.98888, 0.99094, 0.99251],
+        [0.99055, 0.92525, 0.90908, 0.90889, 0.91527, 0.92573, 0.93640, 0.94585, 0.95415, 0.96177, 0.96789, 0.97401, 0.97828, 0.98126, 0.98450, 0.98665],
+        [0.99343, 0.91115, 0.89141, 0.89004, 0.89554, 0.90452, 0.91549, 0.92558, 0.93512, 0.94474, 0.95208, 0.95935, 0.96545, 0.97086, 0.97532, 0.97877],
+        [0.98636, 0.89269, 0.86897, 0.86476, 0.86971, 0.87837, 0.88982, 0.90118, 0.91189, 0.92256, 0.93251, 0.94117, 0.94906, 0.95708, 0.96264, 0.96733],
+        [0.99724, 0.87251, 0.84257, 0.83620, 0.83940, 0.84800, 0.85961, 0.87112, 0.88446, 0.89625, 0.90859, 0.91935, 0.92984, 0.93894, 0.94769, 0.95331],
+        [0.98705, 0.85111, 0.81544, 0.80453, 0.80536, 0.81329, 0.82443, 0.83783, 0.85155, 0.86563, 0.88004, 0.89345, 0.90628, 0.91734, 0.92833, 0.93639],
+        [0.98491, 0.82917, 0.78652, 0.77180, 0.77029, 0.77738, 0.78769, 0.80131, 0.81560, 0.83123, 0.84752, 0.86279, 0.87782, 0.89292, 0.90635, 0.91667],
+        [0.98501, 0.80767, 0.75815, 0.74050, 0.73703, 0.74117, 0.75100, 0.76334, 0.77883, 0.79516, 0.81202, 0.82935, 0.84704, 0.86383, 0.88078, 0.89388],
+        [0.98627, 0.78677, 0.73273, 0.71049, 0.70478, 0.70700, 0.71471, 0.72646, 0.74073, 0.75652, 0.77436, 0.79325, 0.81274, 0.83215, 0.85185, 0.86781],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95891, 0.99823, 0.99968, 0.99984, 0.99985, 1.00007, 0.99999, 0.99999, 0.99997, 1.00000, 0.99999, 1.00000, 0.99998, 1.00000, 1.00000, 1.00000],
+        [0.99010, 0.96922, 0.99320, 0.99770, 0.99899, 0.99943, 0.99958, 0.99981, 0.99963, 0.99980, 0.99984, 0.99997, 1.00002, 0.99990, 0.99998, 0.99991],
+        [0.99487, 0.92302, 0.96857, 0.98720, 0.99395, 0.99676, 0.99778, 0.99893, 0.99909, 0.99940, 0.99947, 0.99946, 0.99953, 0.99959, 0.99990, 0.99987],
+        [0.99336, 0.91635, 0.93975, 0.96582, 0.98124, 0.98882, 0.99315, 0.99484, 0.99650, 0.99704, 0.99801, 0.99849, 0.99878, 0.99908, 0.99885, 0.99913],
+        [1.00171, 0.92709, 0.92663, 0.94553, 0.96232, 0.97445, 0.98293, 0.98817, 0.99072, 0.99326, 0.99435, 0.99577, 0.99664, 0.99713, 0.99743, 0.99783],
+        [0.99358, 0.93555, 0.92373, 0.93233, 0.94603, 0.95960, 0.96847, 0.97674, 0.98188, 0.98627, 0.98818, 0.99085, 0.99288, 0.99432, 0.99460, 0.99579],
+        [0.98819, 0.93446, 0.92096, 0.92224, 0.93141, 0.94249, 0.95319, 0.96238, 0.96949, 0.97492, 0.98012, 0.98329, 0.98590, 0.98881, 0.98972, 0.99103],
+        [0.99811, 0.92931, 0.91043, 0.90984, 0.91635, 0.92436, 0.93526, 0.94482, 0.95289, 0.95996, 0.96704, 0.97104, 0.97599, 0.97975, 0.98196, 0.98436],
+        [0.99373, 0.91521, 0.89528, 0.89158, 0.89609, 0.90407, 0.91338, 0.92360, 0.93383, 0.94202, 0.94932, 0.95652, 0.96221, 0.96709, 0.97198, 0.97472],
+        [0.98600, 0.89801, 0.87311, 0.86835, 0.87120, 0.87903, 0.88858, 0.89861, 0.90920, 0.91908, 0.92865, 0.93675, 0.94454, 0.95172, 0.95798, 0.96232],
+        [0.99257, 0.87631, 0.84682, 0.83966, 0.84128, 0.84768, 0.85790, 0.86897, 0.88069, 0.89207, 0.90358, 0.91340, 0.92319, 0.93265, 0.93983, 0.94578],
+        [0.98851, 0.85779, 0.81987, 0.80809, 0.80798, 0.81372, 0.82302, 0.83453, 0.84734, 0.86054, 0.87392, 0.88603, 0.89772, 0.90842, 0.91830, 0.92609],
+        [0.99471, 0.83620, 0.79175, 0.77590, 0.77310, 0.77718, 0.78587, 0.79755, 0.81055, 0.82440, 0.83848, 0.85346, 0.86756, 0.88162, 0.89365, 0.90385],
+        [0.98736, 0.81414, 0.76528, 0.74424, 0.73899, 0.74089, 0.74825, 0.75859, 0.77182, 0.78657, 0.80125, 0.81747, 0.83388, 0.85018, 0.86634, 0.87812],
+        [0.98882, 0.79484, 0.73854, 0.71386, 0.70483, 0.70533, 0.71087, 0.72048, 0.73242, 0.74664, 0.76313, 0.78003, 0.79770, 0.81646, 0.83362, 0.84931],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95580, 0.99854, 0.99969, 0.99970, 0.99991, 0.99988, 1.00006, 0.99996, 1.00000, 0.99997, 1.00001, 1.00000, 0.99996, 1.00001, 0.99999, 1.00000],
+        [0.99172, 0.96909, 0.99325, 0.99769, 0.99906, 0.99944, 0.99942, 0.99950, 0.99980, 0.99987, 0.99999, 0.99994, 0.99994, 0.99999, 0.99999, 0.99998],
+        [0.99849, 0.92306, 0.96862, 0.98707, 0.99342, 0.99576, 0.99783, 0.99866, 0.99922, 0.99928, 0.99922, 0.99959, 0.99973, 0.99967, 0.99976, 0.99954],
+        [0.99435, 0.91597, 0.94047, 0.96593, 0.98089, 0.98807, 0.99254, 0.99473, 0.99611, 0.99733, 0.99749, 0.99827, 0.99882, 0.99868, 0.99921, 0.99939],
+        [0.99902, 0.92757, 0.92657, 0.94523, 0.96296, 0.97506, 0.98244, 0.98812, 0.99036, 0.99251, 0.99394, 0.99515, 0.99631, 0.99699, 0.99709, 0.99803],
+        [1.00364, 0.93676, 0.92345, 0.93247, 0.94525, 0.95821, 0.96838, 0.97579, 0.98061, 0.98457, 0.98792, 0.99026, 0.99248, 0.99382, 0.99436, 0.99475],
+        [0.99468, 0.93653, 0.92038, 0.92240, 0.93057, 0.94194, 0.95215, 0.96087, 0.96788, 0.97413, 0.97835, 0.98210, 0.98540, 0.98737, 0.98885, 0.99019],
+        [0.99233, 0.93107, 0.91267, 0.91100, 0.91519, 0.92400, 0.93405, 0.94292, 0.95122, 0.95855, 0.96452, 0.96942, 0.97348, 0.97709, 0.98035, 0.98249],
+        [0.99102, 0.91710, 0.89686, 0.89274, 0.89673, 0.90388, 0.91295, 0.92228, 0.93077, 0.93859, 0.94648, 0.95315, 0.95877, 0.96391, 0.96826, 0.97143],
+        [0.99210, 0.90184, 0.87592, 0.86970, 0.87166, 0.87838, 0.88728, 0.89651, 0.90643, 0.91602, 0.92487, 0.93279, 0.94008, 0.94639, 0.95261, 0.95667],
+        [0.98706, 0.88195, 0.85154, 0.84196, 0.84239, 0.84818, 0.85593, 0.86656, 0.87692, 0.88777, 0.89793, 0.90815, 0.91689, 0.92549, 0.93307, 0.93817],
+        [0.99549, 0.86252, 0.82496, 0.81050, 0.80854, 0.81327, 0.82106, 0.83157, 0.84244, 0.85475, 0.86634, 0.87847, 0.88974, 0.89948, 0.90952, 0.91683],
+        [0.98650, 0.84129, 0.79643, 0.77865, 0.77411, 0.77705, 0.78401, 0.79350, 0.80552, 0.81752, 0.83071, 0.84421, 0.85783, 0.87027, 0.88235, 0.89148],
+        [0.98750, 0.82042, 0.76928, 0.74656, 0.73909, 0.73901, 0.74530, 0.75395, 0.76502, 0.77814, 0.79217, 0.80676, 0.82240, 0.83740, 0.85176, 0.86307],
+        [0.98783, 0.79940, 0.74217, 0.71602, 0.70520, 0.70327, 0.70664, 0.71436, 0.72459, 0.73700, 0.75133, 0.76711, 0.78357, 0.80052, 0.81754, 0.83217],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95746, 0.99802, 0.99940, 0.99984, 1.00003, 0.99998, 0.99989, 0.99995, 0.99997, 1.00002, 1.00001, 0.99999, 0.99998, 1.00000, 1.00000, 0.99994],
+        [0.98965, 0.96874, 0.99309, 0.99778, 0.99897, 0.99895, 0.99947, 0.99967, 0.99975, 0.99987, 0.99991, 0.99981, 0.99973, 0.99999, 0.99999, 0.99991],
+        [0.99327, 0.92313, 0.96879, 0.98684, 0.99375, 0.99629, 0.99760, 0.99826, 0.99905, 0.99920, 0.99932, 0.99965, 0.99942, 0.99952, 0.99981, 0.99978],
+        [0.99707, 0.91667, 0.94017, 0.96541, 0.98044, 0.98841, 0.99234, 0.99457, 0.99616, 0.99712, 0.99753, 0.99827, 0.99832, 0.99853, 0.99893, 0.99886],
+        [0.99835, 0.92806, 0.92652, 0.94572, 0.96212, 0.97419, 0.98197, 0.98624, 0.99079, 0.99279, 0.99473, 0.99563, 0.99611, 0.99662, 0.99712, 0.99714],
+        [0.99831, 0.93605, 0.92352, 0.93237, 0.94524, 0.95765, 0.96788, 0.97545, 0.98118, 0.98494, 0.98805, 0.98985, 0.99204, 0.99271, 0.99354, 0.99483],
+        [0.99593, 0.93827, 0.92159, 0.92210, 0.93120, 0.94182, 0.95173, 0.95948, 0.96662, 0.97291, 0.97716, 0.98104, 0.98360, 0.98594, 0.98728, 0.98861],
+        [0.99308, 0.93229, 0.91369, 0.91101, 0.91587, 0.92380, 0.93359, 0.94204, 0.94919, 0.95656, 0.96273, 0.96797, 0.97198, 0.97561, 0.97825, 0.97954],
+        [0.99893, 0.91986, 0.89952, 0.89401, 0.89675, 0.90316, 0.91152, 0.92065, 0.92946, 0.93667, 0.94399, 0.95013, 0.95591, 0.96048, 0.96535, 0.96750],
+        [0.99001, 0.90369, 0.87845, 0.87142, 0.87234, 0.87778, 0.88576, 0.89442, 0.90325, 0.91248, 0.92100, 0.92810, 0.93547, 0.94157, 0.94813, 0.95086],
+        [0.99279, 0.88523, 0.85412, 0.84452, 0.84276, 0.84704, 0.85477, 0.86349, 0.87367, 0.88309, 0.89240, 0.90221, 0.91094, 0.91883, 0.92598, 0.93079],
+        [1.00337, 0.86672, 0.82792, 0.81267, 0.80945, 0.81322, 0.81870, 0.82857, 0.83855, 0.84864, 0.85986, 0.87067, 0.88133, 0.89159, 0.90084, 0.90791],
+        [0.98566, 0.84482, 0.79960, 0.78114, 0.77442, 0.77548, 0.78141, 0.78989, 0.79992, 0.81132, 0.82388, 0.83573, 0.84804, 0.85959, 0.87127, 0.87983],
+        [0.99579, 0.82478, 0.77197, 0.74831, 0.73900, 0.73749, 0.74130, 0.74906, 0.75800, 0.77046, 0.78263, 0.79703, 0.81040, 0.82431, 0.83824, 0.84909],
+        [0.98633, 0.80639, 0.74515, 0.71695, 0.70437, 0.70036, 0.70206, 0.70821, 0.71745, 0.72830, 0.74103, 0.75558, 0.77032, 0.78585, 0.80276, 0.81587],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95841, 0.99764, 0.99947, 0.99972, 0.99988, 0.99998, 1.00006, 1.00004, 0.99996, 0.99998, 1.00001, 0.99998, 0.99999, 1.00000, 0.99994, 1.00000],
+        [0.98948, 0.96877, 0.99298, 0.99752, 0.99908, 0.99908, 0.99943, 0.99973, 0.99986, 0.99969, 0.99971, 0.99998, 0.99982, 0.99986, 0.99987, 0.99987],
+        [0.99333, 0.92319, 0.96925, 0.98685, 0.99345, 0.99652, 0.99786, 0.99829, 0.99874, 0.99895, 0.99905, 0.99940, 0.99948, 0.99963, 0.99977, 0.99966],
+        [0.99963, 0.91700, 0.94042, 0.96589, 0.98045, 0.98780, 0.99239, 0.99416, 0.99587, 0.99636, 0.99759, 0.99806, 0.99828, 0.99852, 0.99878, 0.99838],
+        [0.99760, 0.92782, 0.92691, 0.94542, 0.96231, 0.97354, 0.98126, 0.98698, 0.99040, 0.99277, 0.99357, 0.99492, 0.99556, 0.99641, 0.99680, 0.99765],
+        [0.99652, 0.93737, 0.92504, 0.93207, 0.94513, 0.95702, 0.96692, 0.97461, 0.97990, 0.98377, 0.98680, 0.98893, 0.99039, 0.99204, 0.99262, 0.99326],
+        [0.99944, 0.93798, 0.92190, 0.92283, 0.93037, 0.94051, 0.95033, 0.95835, 0.96573, 0.97100, 0.97582, 0.97934, 0.98290, 0.98448, 0.98625, 0.98761],
+        [1.00202, 0.93377, 0.91444, 0.91133, 0.91540, 0.92312, 0.93162, 0.94019, 0.94847, 0.95507, 0.96076, 0.96608, 0.96923, 0.97276, 0.97618, 0.97844],
+        [1.00569, 0.92119, 0.90078, 0.89475, 0.89626, 0.90254, 0.90973, 0.91858, 0.92664, 0.93403, 0.94139, 0.94708, 0.95352, 0.95755, 0.96189, 0.96451],
+        [0.99150, 0.90715, 0.88048, 0.87219, 0.87253, 0.87692, 0.88509, 0.89238, 0.90060, 0.90897, 0.91696, 0.92458, 0.93120, 0.93737, 0.94301, 0.94681],
+        [0.99539, 0.88788, 0.85652, 0.84484, 0.84316, 0.84623, 0.85301, 0.86137, 0.86983, 0.87864, 0.88742, 0.89694, 0.90465, 0.91228, 0.91946, 0.92462],
+        [0.98575, 0.86958, 0.83082, 0.81421, 0.80980, 0.81180, 0.81673, 0.82533, 0.83474, 0.84406, 0.85416, 0.86445, 0.87379, 0.88273, 0.89180, 0.89872],
+        [0.98950, 0.85082, 0.80308, 0.78207, 0.77432, 0.77375, 0.77830, 0.78539, 0.79471, 0.80481, 0.81594, 0.82727, 0.83812, 0.84969, 0.86062, 0.86925],
+        [0.99021, 0.82810, 0.77474, 0.74946, 0.73871, 0.73539, 0.73822, 0.74393, 0.75233, 0.76302, 0.77461, 0.78679, 0.79995, 0.81287, 0.82585, 0.83650],
+        [0.98784, 0.80819, 0.74740, 0.71798, 0.70329, 0.69831, 0.69842, 0.70259, 0.71042, 0.71980, 0.73119, 0.74406, 0.75805, 0.77292, 0.78791, 0.80112],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95899, 0.99856, 0.99983, 0.99975, 0.99996, 0.99994, 1.00001, 0.99996, 0.99999, 0.99999, 0.99999, 0.99993, 1.00000, 1.00000, 0.99999, 1.00000],
+        [0.98934, 0.96944, 0.99342, 0.99785, 0.99853, 0.99928, 0.99962, 0.99964, 0.99967, 0.99974, 0.99997, 0.99985, 0.99986, 0.99997, 0.99999, 0.99996],
+        [0.99230, 0.92333, 0.96900, 0.98682, 0.99376, 0.99612, 0.99765, 0.99816, 0.99858, 0.99905, 0.99935, 0.99961, 0.99967, 0.99939, 0.99972, 0.99968],
+        [0.99435, 0.91649, 0.94037, 0.96584, 0.98034, 0.98790, 0.99194, 0.99466, 0.99578, 0.99711, 0.99754, 0.99807, 0.99796, 0.99796, 0.99863, 0.99868],
+        [0.99485, 0.92887, 0.92737, 0.94506, 0.96195, 0.97426, 0.98212, 0.98657, 0.98944, 0.99123, 0.99332, 0.99501, 0.99536, 0.99551, 0.99695, 0.99681],
+        [0.99287, 0.93772, 0.92501, 0.93226, 0.94496, 0.95745, 0.96667, 0.97375, 0.97949, 0.98287, 0.98629, 0.98842, 0.98974, 0.99064, 0.99219, 0.99292],
+        [0.99660, 0.93918, 0.92248, 0.92256, 0.93003, 0.94000, 0.94938, 0.95764, 0.96464, 0.97026, 0.97507, 0.97801, 0.98136, 0.98319, 0.98468, 0.98646],
+        [0.99694, 0.93427, 0.91495, 0.91104, 0.91508, 0.92358, 0.93069, 0.93895, 0.94679, 0.95258, 0.95879, 0.96410, 0.96771, 0.97163, 0.97414, 0.97652],
+        [0.99512, 0.92383, 0.90097, 0.89479, 0.89650, 0.90170, 0.90880, 0.91681, 0.92437, 0.93204, 0.93807, 0.94350, 0.95009, 0.95446, 0.95917, 0.96215],
+        [1.00370, 0.90808, 0.88185, 0.87225, 0.87280, 0.87617, 0.88271, 0.89017, 0.89784, 0.90599, 0.91400, 0.92063, 0.92702, 0.93321, 0.93817, 0.94199],
+        [0.98464, 0.89075, 0.85808, 0.84517, 0.84294, 0.84512, 0.85090, 0.85828, 0.86635, 0.87444, 0.88359, 0.89174, 0.89929, 0.90589, 0.91364, 0.91831],
+        [0.99248, 0.87180, 0.83237, 0.81501, 0.80988, 0.80973, 0.81459, 0.82180, 0.82963, 0.83884, 0.84838, 0.85725, 0.86696, 0.87528, 0.88369, 0.89013],
+        [0.99809, 0.85140, 0.80455, 0.78350, 0.77380, 0.77261, 0.77507, 0.78152, 0.78942, 0.79878, 0.80910, 0.81892, 0.83012, 0.84074, 0.85082, 0.85916],
+        [0.98632, 0.83228, 0.77684, 0.75110, 0.73742, 0.73286, 0.73499, 0.73940, 0.74677, 0.75625, 0.76590, 0.77667, 0.78945, 0.80124, 0.81398, 0.82409],
+        [0.99169, 0.81251, 0.74943, 0.71801, 0.70200, 0.69495, 0.69428, 0.69711, 0.70312, 0.71171, 0.72219, 0.73379, 0.74660, 0.75964, 0.77384, 0.78730],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.96068, 0.99831, 0.99935, 0.99989, 0.99989, 0.99997, 0.99997, 1.00001, 0.99999, 0.99997, 1.00002, 0.99999, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.98953, 0.96881, 0.99343, 0.99760, 0.99881, 0.99937, 0.99941, 0.99966, 0.99982, 0.99999, 0.99995, 0.99983, 0.99991, 0.99996, 0.99999, 0.99987],
+        [0.99739, 0.92413, 0.96934, 0.98724, 0.99348, 0.99662, 0.99725, 0.99855, 0.99891, 0.99918, 0.99929, 0.99910, 0.99964, 0.99949, 0.99935, 0.99950],
+        [0.99753, 0.91766, 0.94094, 0.96591, 0.98053, 0.98794, 0.99182, 0.99416, 0.99565, 0.99695, 0.99759, 0.99734, 0.99876, 0.99839, 0.99883, 0.99895],
+        [0.99757, 0.92926, 0.92763, 0.94468, 0.96225, 0.97372, 0.98053, 0.98596, 0.98896, 0.99220, 0.99321, 0.99446, 0.99472, 0.99496, 0.99654, 0.99675],
+        [1.00416, 0.93806, 0.92528, 0.93186, 0.94446, 0.95630, 0.96576, 0.97305, 0.97867, 0.98212, 0.98527, 0.98777, 0.98895, 0.99081, 0.99216, 0.99256],
+        [0.99264, 0.93910, 0.92288, 0.92299, 0.92983, 0.93918, 0.94885, 0.95741, 0.96353, 0.96950, 0.97351, 0.97699, 0.97983, 0.98260, 0.98435, 0.98584],
+        [0.99882, 0.93469, 0.91569, 0.91108, 0.91456, 0.92161, 0.93024, 0.93741, 0.94524, 0.95131, 0.95758, 0.96186, 0.96590, 0.96992, 0.97266, 0.97438],
+        [1.00719, 0.92473, 0.90193, 0.89500, 0.89552, 0.90068, 0.90823, 0.91497, 0.92201, 0.92904, 0.93561, 0.94162, 0.94682, 0.95177, 0.95561, 0.95865],
+        [0.99603, 0.91086, 0.88285, 0.87324, 0.87172, 0.87630, 0.88158, 0.88730, 0.89539, 0.90294, 0.90968, 0.91664, 0.92379, 0.92872, 0.93415, 0.93776],
+        [0.99109, 0.89418, 0.86022, 0.84694, 0.84270, 0.84460, 0.84914, 0.85549, 0.86309, 0.87086, 0.87857, 0.88628, 0.89345, 0.90046, 0.90698, 0.91178],
+        [0.98860, 0.87219, 0.83357, 0.81614, 0.80928, 0.80939, 0.81237, 0.81846, 0.82581, 0.83369, 0.84250, 0.85103, 0.85958, 0.86833, 0.87630, 0.88241],
+        [0.99969, 0.85408, 0.80622, 0.78280, 0.77363, 0.77069, 0.77288, 0.77747, 0.78493, 0.79244, 0.80221, 0.81188, 0.82175, 0.83136, 0.84118, 0.84898],
+        [0.99017, 0.83433, 0.77834, 0.75031, 0.73682, 0.73129, 0.73128, 0.73451, 0.74081, 0.74885, 0.75874, 0.76835, 0.77868, 0.79097, 0.80239, 0.81269],
+        [0.98128, 0.81424, 0.75066, 0.71815, 0.70043, 0.69255, 0.69076, 0.69156, 0.69707, 0.70382, 0.71288, 0.72365, 0.73557, 0.74824, 0.76150, 0.77376],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95889, 0.99791, 0.99976, 0.99977, 0.99986, 0.99991, 1.00001, 1.00002, 1.00001, 0.99995, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.99025, 0.96930, 0.99353, 0.99740, 0.99892, 0.99915, 0.99957, 0.99962, 0.99989, 0.99966, 0.99988, 0.99988, 0.99989, 0.99991, 0.99986, 0.99993],
+        [0.99422, 0.92434, 0.96947, 0.98706, 0.99312, 0.99636, 0.99788, 0.99848, 0.99873, 0.99898, 0.99894, 0.99946, 0.99923, 0.99952, 0.99979, 0.99972],
+        [0.99862, 0.91809, 0.94191, 0.96570, 0.98037, 0.98768, 0.99237, 0.99467, 0.99540, 0.99693, 0.99739, 0.99735, 0.99836, 0.99844, 0.99865, 0.99869],
+        [0.99242, 0.92875, 0.92806, 0.94536, 0.96174, 0.97337, 0.98099, 0.98532, 0.98874, 0.99147, 0.99296, 0.99384, 0.99509, 0.99574, 0.99678, 0.99652],
+        [1.00274, 0.93705, 0.92550, 0.93235, 0.94432, 0.95636, 0.96549, 0.97290, 0.97823, 0.98211, 0.98453, 0.98711, 0.98898, 0.99007, 0.99094, 0.99311],
+        [0.99892, 0.94069, 0.92195, 0.92274, 0.92985, 0.93898, 0.94839, 0.95552, 0.96283, 0.96808, 0.97266, 0.97596, 0.97944, 0.98167, 0.98338, 0.98481],
+        [0.99677, 0.93588, 0.91602, 0.91178, 0.91485, 0.92162, 0.92881, 0.93683, 0.94352, 0.94955, 0.95579, 0.96050, 0.96472, 0.96774, 0.97080, 0.97257],
+        [0.99890, 0.92499, 0.90337, 0.89525, 0.89590, 0.89972, 0.90649, 0.91324, 0.92043, 0.92747, 0.93309, 0.93875, 0.94385, 0.94903, 0.95305, 0.95587],
+        [1.00052, 0.91132, 0.88390, 0.87364, 0.87111, 0.87378, 0.88013, 0.88569, 0.89273, 0.90004, 0.90667, 0.91307, 0.91866, 0.92462, 0.93006, 0.93381],
+        [0.99450, 0.89444, 0.86045, 0.84687, 0.84232, 0.84270, 0.84726, 0.85267, 0.85932, 0.86733, 0.87422, 0.88185, 0.88842, 0.89494, 0.90158, 0.90605],
+        [0.99775, 0.87631, 0.83475, 0.81588, 0.80893, 0.80714, 0.80992, 0.81569, 0.82187, 0.82901, 0.83727, 0.84513, 0.85256, 0.86153, 0.86864, 0.87477],
+        [0.98863, 0.85761, 0.80794, 0.78398, 0.77308, 0.76892, 0.76958, 0.77323, 0.78017, 0.78722, 0.79563, 0.80473, 0.81373, 0.82338, 0.83165, 0.83938],
+        [0.98859, 0.83613, 0.78017, 0.75036, 0.73623, 0.72885, 0.72744, 0.73018, 0.73590, 0.74259, 0.75066, 0.76027, 0.77045, 0.78138, 0.79190, 0.80198],
+        [1.00335, 0.81555, 0.75224, 0.71809, 0.69892, 0.68969, 0.68607, 0.68679, 0.69046, 0.69673, 0.70458, 0.71422, 0.72519, 0.73694, 0.74927, 0.76110],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95739, 0.99779, 0.99978, 0.99997, 0.99992, 0.99982, 0.99999, 0.99999, 1.00004, 0.99995, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.98998, 0.96891, 0.99347, 0.99761, 0.99905, 0.99922, 0.99961, 0.99962, 0.99961, 0.99982, 0.99964, 0.99976, 0.99993, 0.99995, 0.99993, 0.99980],
+        [1.00025, 0.92480, 0.96993, 0.98742, 0.99345, 0.99609, 0.99722, 0.99808, 0.99847, 0.99900, 0.99942, 0.99932, 0.99967, 0.99964, 0.99949, 0.99963],
+        [0.99252, 0.91795, 0.94126, 0.96632, 0.98005, 0.98799, 0.99161, 0.99390, 0.99575, 0.99683, 0.99728, 0.99812, 0.99754, 0.99835, 0.99858, 0.99824],
+        [1.00251, 0.92921, 0.92843, 0.94526, 0.96158, 0.97332, 0.98096, 0.98549, 0.98888, 0.99087, 0.99203, 0.99402, 0.99461, 0.99551, 0.99582, 0.99618],
+        [0.99954, 0.93808, 0.92590, 0.93281, 0.94424, 0.95562, 0.96505, 0.97164, 0.97765, 0.98079, 0.98436, 0.98636, 0.98824, 0.98958, 0.99104, 0.99158],
+        [0.99290, 0.94021, 0.92343, 0.92262, 0.92983, 0.93848, 0.94758, 0.95543, 0.96179, 0.96783, 0.97134, 0.97527, 0.97803, 0.98044, 0.98286, 0.98311],
+        [1.00109, 0.93642, 0.91612, 0.91117, 0.91395, 0.92030, 0.92767, 0.93533, 0.94230, 0.94889, 0.95352, 0.95823, 0.96225, 0.96615, 0.96934, 0.97155],
+        [1.00524, 0.92611, 0.90327, 0.89635, 0.89526, 0.89894, 0.90517, 0.91161, 0.91865, 0.92417, 0.93089, 0.93670, 0.94161, 0.94598, 0.95010, 0.95296],
+        [0.99301, 0.91118, 0.88437, 0.87384, 0.87090, 0.87323, 0.87806, 0.88372, 0.88994, 0.89670, 0.90258, 0.90941, 0.91534, 0.92064, 0.92579, 0.92976],
+        [1.00733, 0.89650, 0.86184, 0.84687, 0.84149, 0.84213, 0.84548, 0.85040, 0.85673, 0.86294, 0.87020, 0.87732, 0.88355, 0.88984, 0.89564, 0.90126],
+        [0.98888, 0.87794, 0.83600, 0.81655, 0.80823, 0.80575, 0.80824, 0.81182, 0.81771, 0.82469, 0.83159, 0.83974, 0.84675, 0.85437, 0.86159, 0.86751],
+        [0.99695, 0.85875, 0.80872, 0.78414, 0.77158, 0.76714, 0.76784, 0.77017, 0.77566, 0.78214, 0.78957, 0.79753, 0.80585, 0.81472, 0.82356, 0.83043],
+        [0.99628, 0.83747, 0.78085, 0.75056, 0.73474, 0.72682, 0.72437, 0.72629, 0.73024, 0.73560, 0.74343, 0.75258, 0.76154, 0.77192, 0.78164, 0.79110],
+        [0.99173, 0.81827, 0.75293, 0.71780, 0.69804, 0.68716, 0.68249, 0.68232, 0.68470, 0.68983, 0.69670, 0.70546, 0.71492, 0.72638, 0.73861, 0.74990],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95808, 0.99796, 0.99950, 0.99995, 1.00003, 0.99997, 0.99988, 0.99999, 0.99998, 1.00000, 1.00000, 0.99999, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.98738, 0.96966, 0.99320, 0.99792, 0.99868, 0.99923, 0.99966, 0.99978, 0.99964, 0.99967, 0.99978, 0.99990, 0.99987, 0.99986, 0.99987, 0.99986],
+        [0.99398, 0.92529, 0.96976, 0.98735, 0.99382, 0.99648, 0.99792, 0.99851, 0.99887, 0.99904, 0.99946, 0.99921, 0.99920, 0.99947, 0.99963, 0.99980],
+        [1.00350, 0.91897, 0.94229, 0.96661, 0.97953, 0.98747, 0.99186, 0.99391, 0.99554, 0.99686, 0.99694, 0.99772, 0.99829, 0.99848, 0.99828, 0.99829],
+        [0.99541, 0.92953, 0.92858, 0.94600, 0.96187, 0.97267, 0.98033, 0.98529, 0.98879, 0.99064, 0.99225, 0.99355, 0.99413, 0.99493, 0.99557, 0.99582],
+        [0.99389, 0.93876, 0.92575, 0.93287, 0.94417, 0.95549, 0.96476, 0.97159, 0.97697, 0.98024, 0.98389, 0.98667, 0.98824, 0.98932, 0.99042, 0.99137],
+        [1.00451, 0.94025, 0.92472, 0.92289, 0.92907, 0.93778, 0.94641, 0.95396, 0.96099, 0.96564, 0.97039, 0.97396, 0.97709, 0.97947, 0.98149, 0.98306],
+        [0.99200, 0.93620, 0.91662, 0.91187, 0.91398, 0.91986, 0.92727, 0.93405, 0.93983, 0.94661, 0.95222, 0.95658, 0.96021, 0.96401, 0.96732, 0.96911],
+        [0.99543, 0.92693, 0.90357, 0.89493, 0.89508, 0.89838, 0.90390, 0.91001, 0.91705, 0.92290, 0.92890, 0.93432, 0.93888, 0.94358, 0.94755, 0.95062],
+        [1.00376, 0.91388, 0.88536, 0.87380, 0.87105, 0.87197, 0.87614, 0.88146, 0.88742, 0.89331, 0.90029, 0.90605, 0.91128, 0.91701, 0.92179, 0.92522],
+        [0.99887, 0.89711, 0.86226, 0.84715, 0.84111, 0.84051, 0.84330, 0.84767, 0.85322, 0.85946, 0.86635, 0.87241, 0.87843, 0.88446, 0.89031, 0.89490],
+        [0.99133, 0.87916, 0.83677, 0.81604, 0.80774, 0.80459, 0.80552, 0.80913, 0.81430, 0.81992, 0.82702, 0.83391, 0.84102, 0.84804, 0.85441, 0.85977],
+        [0.98851, 0.85937, 0.80942, 0.78450, 0.77191, 0.76527, 0.76400, 0.76645, 0.77094, 0.77669, 0.78400, 0.79097, 0.79893, 0.80679, 0.81495, 0.82212],
+        [0.98504, 0.84011, 0.78103, 0.75084, 0.73296, 0.72477, 0.72163, 0.72199, 0.72534, 0.72995, 0.73659, 0.74492, 0.75347, 0.76266, 0.77231, 0.78086],
+        [0.99421, 0.81918, 0.75320, 0.71794, 0.69622, 0.68478, 0.67863, 0.67681, 0.67889, 0.68320, 0.68913, 0.69727, 0.70598, 0.71635, 0.72752, 0.73875],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95791, 0.99840, 0.99965, 0.99992, 1.00006, 0.99993, 0.99998, 1.00000, 0.99999, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.98745, 0.96927, 0.99357, 0.99775, 0.99871, 0.99921, 0.99960, 0.99950, 0.99962, 0.99961, 0.99987, 0.99972, 0.99983, 0.99972, 0.99993, 0.99999],
+        [0.99608, 0.92595, 0.96983, 0.98745, 0.99295, 0.99604, 0.99753, 0.99804, 0.99845, 0.99900, 0.99923, 0.99918, 0.99923, 0.99920, 0.99961, 0.99911],
+        [0.99310, 0.91903, 0.94246, 0.96613, 0.98012, 0.98751, 0.99164, 0.99358, 0.99517, 0.99631, 0.99685, 0.99795, 0.99829, 0.99818, 0.99783, 0.99882],
+        [1.00464, 0.93054, 0.92946, 0.94592, 0.96226, 0.97325, 0.98006, 0.98491, 0.98829, 0.99085, 0.99186, 0.99296, 0.99400, 0.99496, 0.99525, 0.99637],
+        [0.99323, 0.93906, 0.92657, 0.93190, 0.94424, 0.95518, 0.96420, 0.97148, 0.97624, 0.98038, 0.98314, 0.98594, 0.98709, 0.98881, 0.99010, 0.99098],
+        [1.00039, 0.94084, 0.92344, 0.92278, 0.92868, 0.93736, 0.94621, 0.95315, 0.95886, 0.96508, 0.96934, 0.97329, 0.97591, 0.97831, 0.98071, 0.98163],
+        [1.00302, 0.93807, 0.91709, 0.91153, 0.91313, 0.91906, 0.92589, 0.93255, 0.93974, 0.94553, 0.95066, 0.95484, 0.95883, 0.96290, 0.96531, 0.96806],
+        [0.99832, 0.92692, 0.90388, 0.89513, 0.89429, 0.89757, 0.90301, 0.90845, 0.91451, 0.92065, 0.92619, 0.93115, 0.93632, 0.94039, 0.94482, 0.94792],
+        [1.01147, 0.91254, 0.88538, 0.87402, 0.87018, 0.87103, 0.87465, 0.87995, 0.88543, 0.89141, 0.89681, 0.90346, 0.90897, 0.91301, 0.91776, 0.92191],
+        [1.00398, 0.89734, 0.86363, 0.84665, 0.84046, 0.83936, 0.84154, 0.84507, 0.85002, 0.85594, 0.86226, 0.86814, 0.87405, 0.87959, 0.88527, 0.88999],
+        [0.99544, 0.88043, 0.83660, 0.81683, 0.80632, 0.80280, 0.80355, 0.80621, 0.81076, 0.81618, 0.82229, 0.82818, 0.83534, 0.84160, 0.84813, 0.85342],
+        [0.98394, 0.86122, 0.81029, 0.78401, 0.77043, 0.76359, 0.76144, 0.76310, 0.76609, 0.77182, 0.77794, 0.78455, 0.79164, 0.79922, 0.80701, 0.81347],
+        [0.98570, 0.84014, 0.78167, 0.75050, 0.73219, 0.72288, 0.71838, 0.71769, 0.72026, 0.72458, 0.73026, 0.73774, 0.74504, 0.75382, 0.76286, 0.77127],
+        [0.99195, 0.81976, 0.75421, 0.71727, 0.69521, 0.68211, 0.67525, 0.67234, 0.67386, 0.67648, 0.68204, 0.68862, 0.69717, 0.70662, 0.71726, 0.72760],
+    ],
+];
+
+pub const DIELECTRIC_AVERAGE_OUTSIDE: [[f32; N_DIELECTRIC_ROUGH]; N_ETA] = [
+    [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+    [1.00000, 0.99993, 0.99939, 0.99792, 0.99537, 0.99161, 0.98617, 0.97815, 0.96750, 0.95387, 0.93749, 0.91870, 0.89824, 0.87657, 0.85369, 0.83078],
+    [1.00000, 0.99994, 0.99923, 0.99771, 0.99496, 0.99094, 0.98529, 0.97774, 0.96750, 0.95432, 0.93771, 0.91818, 0.89635, 0.87215, 0.84671, 0.82005],
+    [1.00000, 0.99998, 0.99942, 0.99763, 0.99475, 0.99045, 0.98477, 0.97694, 0.96643, 0.95292, 0.93599, 0.91525, 0.89156, 0.86559, 0.83759, 0.80834],
+    [1.00000, 0.99996, 0.99937, 0.99751, 0.99460, 0.99008, 0.98408, 0.97569, 0.96496, 0.95070, 0.93318, 0.91135, 0.88630, 0.85840, 0.82817, 0.79633],
+    [1.00000, 0.99996, 0.99936, 0.99751, 0.99430, 0.98967, 0.98335, 0.97452, 0.96320, 0.94844, 0.92961, 0.90704, 0.88042, 0.85065, 0.81874, 0.78507],
+    [1.00000, 0.99996, 0.99937, 0.99754, 0.99418, 0.98920, 0.98272, 0.97386, 0.96165, 0.94606, 0.92643, 0.90252, 0.87462, 0.84322, 0.80951, 0.77409],
+    [1.00000, 0.99996, 0.99938, 0.99744, 0.99405, 0.98896, 0.98207, 0.97283, 0.95998, 0.94355, 0.92303, 0.89805, 0.86871, 0.83602, 0.80064, 0.76344],
+    [1.00000, 0.99995, 0.99932, 0.99741, 0.99386, 0.98881, 0.98176, 0.97171, 0.95855, 0.94136, 0.91962, 0.89345, 0.86299, 0.82898, 0.79186, 0.75351],
+    [1.00000, 0.99995, 0.99929, 0.99735, 0.99368, 0.98851, 0.98080, 0.97056, 0.95684, 0.93898, 0.91649, 0.88903, 0.85743, 0.82194, 0.78385, 0.74411],
+    [1.00000, 0.99996, 0.99934, 0.99735, 0.99364, 0.98818, 0.98025, 0.96953, 0.95536, 0.93663, 0.91331, 0.88485, 0.85191, 0.81547, 0.77589, 0.73503],
+    [1.00000, 0.99996, 0.99936, 0.99732, 0.99372, 0.98784, 0.97972, 0.96877, 0.95390, 0.93429, 0.91027, 0.88065, 0.84676, 0.80906, 0.76832, 0.72656],
+    [1.00000, 0.99996, 0.99932, 0.99732, 0.99367, 0.98780, 0.97934, 0.96793, 0.95255, 0.93222, 0.90716, 0.87669, 0.84170, 0.80297, 0.76140, 0.71840],
+    [1.00000, 0.99996, 0.99930, 0.99732, 0.99353, 0.98749, 0.97877, 0.96713, 0.95103, 0.93009, 0.90410, 0.87293, 0.83686, 0.79717, 0.75448, 0.71090],
+    [1.00000, 0.99996, 0.99930, 0.99739, 0.99352, 0.98722, 0.97852, 0.96611, 0.94943, 0.92820, 0.90121, 0.86897, 0.83214, 0.79150, 0.74801, 0.70355],
+    [1.00000, 0.99997, 0.99926, 0.99717, 0.99339, 0.98712, 0.97804, 0.96519, 0.94821, 0.92593, 0.89867, 0.86538, 0.82766, 0.78593, 0.74167, 0.69648],
+];
+
+pub const DIELECTRIC_ALBEDO_INSIDE: [[[f32; N_DIELECTRIC_MU]; N_DIELECTRIC_ROUGH]; N_ETA] = [
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95124, 0.99710, 0.99854, 0.99926, 0.99972, 0.99984, 0.99887, 1.00167, 0.99919, 0.99957, 0.99991, 0.99986, 0.99988, 0.99989, 0.99983, 1.00025],
+        [0.96231, 0.95565, 0.98771, 0.99566, 0.99725, 0.99397, 1.00052, 0.99495, 0.99894, 0.99964, 0.99912, 0.99961, 0.99958, 0.99932, 1.00021, 0.99984],
+        [0.93423, 0.88339, 0.94192, 0.97115, 0.97765, 0.99062, 0.98487, 0.98514, 0.99165, 0.99723, 0.99534, 0.99691, 0.99844, 0.99940, 0.99903, 0.99938],
+        [0.88855, 0.84019, 0.87521, 0.90933, 0.94041, 0.95478, 0.95955, 0.96398, 0.97667, 0.98062, 0.98801, 0.99410, 0.99504, 0.99662, 0.99817, 0.99857],
+        [0.83144, 0.80143, 0.82062, 0.84885, 0.87524, 0.89395, 0.90902, 0.92337, 0.95278, 0.96879, 0.97355, 0.98498, 0.98875, 0.99163, 0.99442, 0.99616],
+        [0.76222, 0.74832, 0.75929, 0.77639, 0.80131, 0.82708, 0.85539, 0.88031, 0.91162, 0.94101, 0.95584, 0.96745, 0.97652, 0.98397, 0.98842, 0.99191],
+        [0.68723, 0.68336, 0.69464, 0.71024, 0.73030, 0.75918, 0.79637, 0.82272, 0.86947, 0.89710, 0.92862, 0.94372, 0.95823, 0.97076, 0.97970, 0.98463],
+        [0.60782, 0.61186, 0.62472, 0.64546, 0.67303, 0.70780, 0.74552, 0.78449, 0.81971, 0.86293, 0.88434, 0.91379, 0.93523, 0.95364, 0.96536, 0.97411],
+        [0.53398, 0.54207, 0.56592, 0.59207, 0.62637, 0.65919, 0.70431, 0.74380, 0.77890, 0.81335, 0.84439, 0.87650, 0.90609, 0.93019, 0.94679, 0.95855],
+        [0.46369, 0.48078, 0.51438, 0.55116, 0.59137, 0.62267, 0.67633, 0.69010, 0.74097, 0.77594, 0.80592, 0.84305, 0.87419, 0.90321, 0.92265, 0.93905],
+        [0.39967, 0.43111, 0.47429, 0.51945, 0.57106, 0.60430, 0.64364, 0.67092, 0.71050, 0.74314, 0.77668, 0.80920, 0.84284, 0.87338, 0.89454, 0.91237],
+        [0.34989, 0.39228, 0.44916, 0.50067, 0.53960, 0.57588, 0.61661, 0.65528, 0.68573, 0.71939, 0.74809, 0.77945, 0.81235, 0.84142, 0.86277, 0.87985],
+        [0.30657, 0.36531, 0.42925, 0.48617, 0.52849, 0.56440, 0.59369, 0.62227, 0.65947, 0.69260, 0.72416, 0.75317, 0.78277, 0.81052, 0.82680, 0.84244],
+        [0.27375, 0.34744, 0.42210, 0.47704, 0.51358, 0.54853, 0.57875, 0.61283, 0.64300, 0.67225, 0.69832, 0.72877, 0.75590, 0.77927, 0.78914, 0.79875],
+        [0.24639, 0.33636, 0.41895, 0.47063, 0.51901, 0.54264, 0.56772, 0.59578, 0.62599, 0.65395, 0.67917, 0.70657, 0.73152, 0.74946, 0.75110, 0.75095],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95323, 0.99789, 0.99958, 0.99904, 1.00008, 0.99965, 0.99917, 1.00044, 1.00206, 0.99670, 0.99950, 1.00070, 1.00004, 1.00000, 1.00021, 1.00006],
+        [0.97252, 0.95870, 0.98831, 0.99423, 0.99778, 0.99662, 0.99680, 0.99865, 0.99679, 0.99663, 0.99857, 0.99896, 1.00119, 0.99904, 0.99926, 0.99998],
+        [0.95858, 0.89239, 0.94781, 0.97079, 0.98426, 0.99008, 0.98964, 0.99459, 0.98785, 0.99001, 0.99295, 0.99683, 0.99638, 0.99727, 0.99969, 0.99942],
+        [0.93178, 0.86078, 0.88831, 0.92132, 0.94740, 0.96809, 0.96665, 0.97118, 0.97397, 0.97931, 0.98663, 0.99230, 0.99275, 0.99514, 0.99520, 0.99615],
+        [0.89541, 0.84094, 0.84358, 0.87184, 0.89253, 0.91993, 0.92945, 0.93612, 0.94478, 0.95490, 0.96617, 0.96906, 0.98608, 0.98587, 0.98786, 0.99209],
+        [0.85217, 0.81672, 0.80792, 0.81737, 0.83702, 0.85634, 0.86943, 0.88770, 0.90318, 0.91651, 0.93592, 0.96058, 0.95564, 0.97139, 0.97586, 0.98209],
+        [0.80266, 0.77519, 0.76858, 0.76781, 0.78223, 0.79298, 0.81153, 0.82908, 0.84721, 0.86174, 0.89295, 0.91374, 0.93260, 0.94620, 0.95743, 0.96813],
+        [0.74798, 0.72443, 0.71771, 0.71645, 0.72591, 0.73404, 0.74946, 0.76816, 0.79587, 0.82829, 0.84666, 0.87491, 0.89049, 0.91216, 0.92933, 0.94680],
+        [0.69092, 0.66781, 0.66419, 0.66535, 0.66975, 0.68108, 0.69782, 0.72087, 0.74219, 0.76607, 0.79729, 0.81941, 0.84423, 0.87364, 0.89436, 0.91685],
+        [0.63380, 0.61291, 0.60771, 0.61324, 0.62464, 0.63714, 0.65621, 0.67870, 0.70592, 0.72411, 0.74904, 0.77506, 0.80075, 0.82198, 0.84942, 0.87932],
+        [0.57934, 0.55805, 0.55782, 0.56593, 0.58237, 0.59583, 0.62380, 0.64476, 0.66051, 0.68548, 0.70781, 0.73237, 0.75136, 0.77267, 0.79989, 0.83154],
+        [0.52946, 0.50837, 0.51351, 0.52924, 0.54587, 0.56874, 0.59078, 0.60591, 0.64018, 0.64707, 0.67385, 0.69497, 0.71243, 0.72436, 0.74844, 0.77625],
+        [0.48259, 0.46651, 0.47766, 0.49856, 0.52240, 0.53796, 0.56829, 0.57397, 0.60731, 0.61584, 0.64128, 0.66103, 0.67028, 0.67832, 0.69501, 0.71576],
+        [0.44247, 0.42992, 0.44849, 0.47301, 0.50049, 0.52379, 0.54829, 0.57001, 0.59403, 0.60342, 0.61692, 0.63275, 0.63543, 0.63816, 0.64230, 0.65170],
+        [0.41010, 0.40313, 0.42765, 0.45529, 0.48582, 0.50968, 0.52228, 0.54422, 0.57149, 0.57260, 0.59453, 0.60589, 0.60142, 0.59572, 0.58791, 0.58519],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95452, 0.99755, 0.99881, 0.99934, 0.99931, 1.00004, 1.00005, 0.99994, 0.99936, 0.99918, 0.99966, 1.00057, 0.99983, 1.00020, 0.99992, 0.99985],
+        [0.97732, 0.95856, 0.98821, 0.99462, 0.99637, 0.99864, 0.99904, 0.99794, 0.99810, 1.00012, 1.00292, 0.99921, 0.99917, 0.99875, 0.99949, 0.99937],
+        [0.96883, 0.89345, 0.94715, 0.97064, 0.98433, 0.98720, 0.99169, 0.99824, 0.99661, 0.99313, 0.99369, 0.99523, 0.99463, 0.99899, 0.99762, 0.99816],
+        [0.95060, 0.86898, 0.89292, 0.92898, 0.95134, 0.96592, 0.97194, 0.98274, 0.98478, 0.98293, 0.98175, 0.98508, 0.98778, 0.99694, 0.99521, 0.99465],
+        [0.92532, 0.86394, 0.85531, 0.88410, 0.90425, 0.92260, 0.93998, 0.94942, 0.95388, 0.95611, 0.95921, 0.96913, 0.97578, 0.97984, 0.98706, 0.98784],
+        [0.89393, 0.84328, 0.83015, 0.83293, 0.85246, 0.87231, 0.88888, 0.90169, 0.90750, 0.92182, 0.92757, 0.94109, 0.95332, 0.96241, 0.97657, 0.97387],
+        [0.85851, 0.82038, 0.80317, 0.79989, 0.80462, 0.81503, 0.83024, 0.84210, 0.85833, 0.86444, 0.88583, 0.90125, 0.92412, 0.93863, 0.94741, 0.95212],
+        [0.81759, 0.78204, 0.76373, 0.75798, 0.75712, 0.75947, 0.77293, 0.78060, 0.79769, 0.81241, 0.82763, 0.85136, 0.87780, 0.89525, 0.90387, 0.92241],
+        [0.77421, 0.73833, 0.71827, 0.71364, 0.70843, 0.71275, 0.71752, 0.72982, 0.74284, 0.75844, 0.78454, 0.79715, 0.81483, 0.83819, 0.85997, 0.87805],
+        [0.72964, 0.69189, 0.67298, 0.66447, 0.66051, 0.66179, 0.66932, 0.68007, 0.69407, 0.71689, 0.72585, 0.74256, 0.76448, 0.78440, 0.80555, 0.82452],
+        [0.68376, 0.64423, 0.62567, 0.61664, 0.61818, 0.61723, 0.62806, 0.63633, 0.64292, 0.66018, 0.67550, 0.68901, 0.70583, 0.72028, 0.73911, 0.76160],
+        [0.64004, 0.59424, 0.57796, 0.57527, 0.57602, 0.58116, 0.59133, 0.60639, 0.61330, 0.62880, 0.63000, 0.64185, 0.64645, 0.65885, 0.67413, 0.69129],
+        [0.59820, 0.55118, 0.53573, 0.53694, 0.53974, 0.54368, 0.55350, 0.57030, 0.58368, 0.59145, 0.59965, 0.60002, 0.60413, 0.60677, 0.60848, 0.61676],
+        [0.56230, 0.51098, 0.50085, 0.50405, 0.50787, 0.51691, 0.52742, 0.54627, 0.56046, 0.55900, 0.56535, 0.56221, 0.55804, 0.55130, 0.54648, 0.54345],
+        [0.52631, 0.47767, 0.46995, 0.47549, 0.49001, 0.49845, 0.50814, 0.52217, 0.53116, 0.53024, 0.53226, 0.52878, 0.51376, 0.50165, 0.48703, 0.47277],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95498, 0.99765, 1.00004, 0.99983, 1.00014, 0.99984, 1.00050, 0.99931, 0.99988, 0.99988, 1.00026, 0.99983, 0.99924, 0.99967, 0.99989, 0.99996],
+        [0.98024, 0.95916, 0.98824, 0.99476, 0.99650, 0.99765, 0.99934, 0.99853, 0.99942, 1.00310, 0.99825, 0.99961, 0.99974, 0.99742, 0.99946, 0.99944],
+        [0.97489, 0.89575, 0.94940, 0.97285, 0.98393, 0.99090, 0.99423, 0.99126, 1.00022, 0.99393, 0.99685, 0.99531, 0.99622, 0.99429, 0.99838, 0.99768],
+        [0.96153, 0.87444, 0.90261, 0.93271, 0.95249, 0.96829, 0.97375, 0.98124, 0.98447, 0.98329, 0.98664, 0.98236, 0.98744, 0.99326, 0.99345, 0.99371],
+        [0.94301, 0.86705, 0.86317, 0.88385, 0.90632, 0.92757, 0.94291, 0.95606, 0.96037, 0.96482, 0.96520, 0.96846, 0.97362, 0.98222, 0.98796, 0.98678],
+        [0.91917, 0.86033, 0.84336, 0.84363, 0.86239, 0.87837, 0.89650, 0.90458, 0.91662, 0.92704, 0.92903, 0.93459, 0.95025, 0.96062, 0.96210, 0.96809],
+        [0.89175, 0.84318, 0.81804, 0.81424, 0.81638, 0.83282, 0.84080, 0.85511, 0.86415, 0.87191, 0.87974, 0.89804, 0.91067, 0.92322, 0.94427, 0.94681],
+        [0.86006, 0.81389, 0.79248, 0.77938, 0.77718, 0.78139, 0.78734, 0.79743, 0.80642, 0.81174, 0.82473, 0.83964, 0.85952, 0.88083, 0.89765, 0.90594],
+        [0.82561, 0.77944, 0.75393, 0.74270, 0.73636, 0.73243, 0.73453, 0.74085, 0.74768, 0.75814, 0.76736, 0.78490, 0.80197, 0.82190, 0.84265, 0.85703],
+        [0.78949, 0.73867, 0.71322, 0.69898, 0.69112, 0.68918, 0.68432, 0.69119, 0.69221, 0.70350, 0.70629, 0.71882, 0.73994, 0.75390, 0.78066, 0.79219],
+        [0.75127, 0.69899, 0.67176, 0.65542, 0.64618, 0.64059, 0.63870, 0.64012, 0.64378, 0.65476, 0.66721, 0.66230, 0.67502, 0.68916, 0.70279, 0.71439],
+        [0.71516, 0.65447, 0.62805, 0.60994, 0.60226, 0.59819, 0.59765, 0.59954, 0.60712, 0.59785, 0.60848, 0.60158, 0.61554, 0.62063, 0.62785, 0.63407],
+        [0.67843, 0.61306, 0.58433, 0.57068, 0.56218, 0.56136, 0.56135, 0.56532, 0.56948, 0.56906, 0.55599, 0.56609, 0.55726, 0.55627, 0.56036, 0.55510],
+        [0.64409, 0.57304, 0.54613, 0.53321, 0.52830, 0.52747, 0.52893, 0.53502, 0.53778, 0.54153, 0.53529, 0.52294, 0.50737, 0.49393, 0.49117, 0.47664],
+        [0.61113, 0.53824, 0.51092, 0.50164, 0.49779, 0.49949, 0.50830, 0.50881, 0.50824, 0.50771, 0.49925, 0.47911, 0.45956, 0.44099, 0.42531, 0.40403],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95522, 0.99751, 0.99901, 0.99957, 0.99982, 0.99974, 0.99975, 1.00000, 0.99994, 0.99950, 1.00002, 0.99965, 0.99966, 0.99974, 0.99994, 1.00000],
+        [0.98147, 0.95895, 0.99082, 0.99452, 0.99736, 0.99781, 0.99791, 0.99908, 1.00065, 1.00132, 0.99799, 0.99865, 0.99960, 1.00100, 1.00170, 0.99995],
+        [0.97906, 0.89611, 0.94837, 0.97310, 0.98435, 0.98797, 0.99208, 0.99304, 0.99362, 0.99686, 0.99221, 0.99352, 0.99591, 0.99958, 0.99708, 0.99697],
+        [0.96879, 0.87558, 0.89666, 0.92955, 0.95063, 0.96855, 0.97384, 0.98267, 0.98508, 0.98620, 0.98910, 0.98897, 0.98619, 0.98913, 0.99518, 0.99459],
+        [0.95420, 0.88254, 0.86827, 0.88813, 0.90733, 0.92875, 0.93901, 0.95476, 0.96117, 0.96846, 0.96676, 0.97010, 0.97031, 0.97253, 0.98623, 0.98418],
+        [0.93654, 0.87132, 0.84662, 0.84910, 0.86478, 0.88468, 0.90058, 0.91206, 0.92020, 0.93123, 0.93394, 0.93870, 0.94507, 0.95571, 0.96649, 0.97444],
+        [0.91345, 0.85950, 0.83319, 0.82149, 0.82769, 0.83337, 0.85242, 0.85661, 0.87083, 0.87907, 0.88388, 0.89746, 0.90477, 0.91946, 0.93485, 0.94220],
+        [0.88906, 0.83787, 0.80689, 0.79426, 0.79028, 0.78907, 0.80048, 0.80312, 0.80975, 0.81277, 0.81915, 0.83829, 0.84984, 0.87018, 0.88532, 0.90102],
+        [0.86048, 0.80635, 0.77923, 0.76283, 0.75057, 0.74756, 0.74718, 0.74635, 0.75239, 0.75647, 0.76103, 0.78078, 0.78849, 0.80983, 0.83203, 0.84329],
+        [0.83186, 0.77425, 0.74344, 0.72472, 0.71376, 0.70266, 0.69828, 0.69679, 0.69597, 0.70570, 0.70776, 0.71197, 0.72230, 0.73815, 0.75775, 0.77124],
+        [0.80052, 0.73661, 0.70424, 0.68084, 0.66669, 0.65847, 0.65164, 0.65073, 0.64518, 0.64823, 0.64052, 0.64689, 0.66468, 0.66546, 0.67616, 0.69275],
+        [0.76853, 0.69733, 0.66264, 0.63799, 0.62357, 0.61342, 0.60510, 0.60126, 0.60132, 0.60308, 0.59005, 0.59080, 0.59791, 0.59765, 0.59886, 0.60100],
+        [0.73822, 0.66213, 0.62141, 0.59902, 0.58420, 0.57677, 0.56814, 0.56063, 0.56832, 0.55086, 0.54480, 0.53090, 0.53621, 0.52409, 0.52193, 0.52063],
+        [0.70704, 0.62147, 0.58302, 0.55915, 0.54494, 0.53973, 0.53233, 0.53251, 0.52071, 0.51046, 0.49769, 0.48549, 0.47654, 0.46270, 0.45052, 0.43693],
+        [0.67733, 0.58705, 0.54627, 0.52456, 0.51387, 0.50436, 0.50260, 0.49896, 0.48811, 0.48613, 0.45611, 0.43953, 0.42638, 0.40662, 0.38174, 0.36381],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95557, 0.99701, 0.99979, 0.99970, 0.99970, 0.99997, 1.00037, 0.99929, 1.00026, 0.99982, 0.99975, 0.99980, 1.00179, 1.00089, 1.00043, 1.00000],
+        [0.98281, 0.96009, 0.98831, 0.99845, 0.99689, 0.99921, 0.99805, 0.99874, 0.99999, 0.99881, 0.99821, 1.00162, 0.99840, 0.99869, 1.00054, 0.99899],
+        [0.98309, 0.89932, 0.95078, 0.97508, 0.98595, 0.99017, 0.99383, 0.99417, 0.99509, 1.00001, 0.99579, 0.99243, 0.99714, 0.99624, 1.00001, 0.99791],
+        [0.97512, 0.88075, 0.89928, 0.93269, 0.95612, 0.96683, 0.97446, 0.97673, 0.98986, 0.98837, 0.98930, 0.98924, 0.98708, 0.98883, 0.99509, 0.99517],
+        [0.96259, 0.88061, 0.87003, 0.88726, 0.90779, 0.93068, 0.94136, 0.95460, 0.96225, 0.97145, 0.97113, 0.96997, 0.96983, 0.97534, 0.98420, 0.98483],
+        [0.94771, 0.88208, 0.85662, 0.85381, 0.87140, 0.88472, 0.90083, 0.91306, 0.91912, 0.93269, 0.94031, 0.93878, 0.94708, 0.95103, 0.96129, 0.96827],
+        [0.92993, 0.87168, 0.84536, 0.82823, 0.83064, 0.84083, 0.85095, 0.86664, 0.87568, 0.88031, 0.89023, 0.89791, 0.90984, 0.91511, 0.93395, 0.94012],
+        [0.90914, 0.85297, 0.82173, 0.80610, 0.80021, 0.79788, 0.79780, 0.81256, 0.81381, 0.82200, 0.82902, 0.84413, 0.84872, 0.86477, 0.88027, 0.89498],
+        [0.88652, 0.83081, 0.79689, 0.77725, 0.76682, 0.75931, 0.75715, 0.75538, 0.75802, 0.76282, 0.76258, 0.77662, 0.77780, 0.80309, 0.81776, 0.83735],
+        [0.86218, 0.80081, 0.76378, 0.74168, 0.72801, 0.71368, 0.70913, 0.70367, 0.70223, 0.69650, 0.70230, 0.71060, 0.71746, 0.73031, 0.74224, 0.76775],
+        [0.83514, 0.76621, 0.72833, 0.70332, 0.68456, 0.67301, 0.65822, 0.65722, 0.65218, 0.64143, 0.63581, 0.64009, 0.65542, 0.65362, 0.66315, 0.67641],
+        [0.80875, 0.72864, 0.68926, 0.66182, 0.64218, 0.62722, 0.61832, 0.60728, 0.60416, 0.58705, 0.58460, 0.58013, 0.58013, 0.57853, 0.58095, 0.58542],
+        [0.78163, 0.69558, 0.65021, 0.62165, 0.60237, 0.58579, 0.57331, 0.56385, 0.55560, 0.53765, 0.53799, 0.52082, 0.50154, 0.50721, 0.50065, 0.49644],
+        [0.75478, 0.66057, 0.61171, 0.58085, 0.56082, 0.54699, 0.53550, 0.52771, 0.51605, 0.49804, 0.48848, 0.47195, 0.44167, 0.44057, 0.42647, 0.41680],
+        [0.72756, 0.62489, 0.57555, 0.54697, 0.52691, 0.51267, 0.50067, 0.48889, 0.47662, 0.46778, 0.43484, 0.42528, 0.39455, 0.37814, 0.36079, 0.34393],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95569, 0.99733, 0.99934, 0.99970, 0.99989, 1.00043, 0.99993, 0.99988, 1.00007, 1.00020, 0.99939, 0.99927, 1.00029, 1.00074, 0.99976, 0.99996],
+        [0.98333, 0.95968, 0.98871, 0.99505, 0.99833, 0.99805, 1.00045, 0.99903, 0.99893, 0.99967, 1.00000, 1.00010, 0.99486, 0.99805, 0.99914, 0.99951],
+        [0.98368, 0.89776, 0.94879, 0.97367, 0.98491, 0.98955, 0.99250, 0.99740, 0.99423, 0.99502, 0.99618, 0.99518, 1.00007, 0.99432, 0.99744, 0.99850],
+        [0.97778, 0.88177, 0.90547, 0.93286, 0.95643, 0.96887, 0.97636, 0.98400, 0.98648, 0.98451, 0.98839, 0.99138, 0.98729, 0.98701, 0.99151, 0.99453],
+        [0.96834, 0.88684, 0.87147, 0.88908, 0.91132, 0.93085, 0.94455, 0.95512, 0.96239, 0.96678, 0.97061, 0.97525, 0.97433, 0.97370, 0.98055, 0.98833],
+        [0.95622, 0.88647, 0.85852, 0.85698, 0.87580, 0.88880, 0.90153, 0.91318, 0.92694, 0.93420, 0.94005, 0.93928, 0.94007, 0.94933, 0.96275, 0.96700],
+        [0.94155, 0.87827, 0.85021, 0.83475, 0.83567, 0.84407, 0.85655, 0.86507, 0.87562, 0.88297, 0.89360, 0.90051, 0.89719, 0.91469, 0.92926, 0.93838],
+        [0.92482, 0.86797, 0.83187, 0.81455, 0.80369, 0.80664, 0.80566, 0.81132, 0.81791, 0.82682, 0.83315, 0.84184, 0.85143, 0.86243, 0.87724, 0.89397],
+        [0.90596, 0.84196, 0.80837, 0.78461, 0.77243, 0.76533, 0.76185, 0.76297, 0.76241, 0.75868, 0.76798, 0.77199, 0.78023, 0.79760, 0.81431, 0.83319],
+        [0.88503, 0.81842, 0.77885, 0.75456, 0.73628, 0.72228, 0.71946, 0.70967, 0.70113, 0.70295, 0.69805, 0.70293, 0.71040, 0.72428, 0.73712, 0.75502],
+        [0.86213, 0.78657, 0.74484, 0.71907, 0.69580, 0.68387, 0.66745, 0.65989, 0.64825, 0.63961, 0.63782, 0.63647, 0.64605, 0.64700, 0.65418, 0.66706],
+        [0.83899, 0.75365, 0.71098, 0.67900, 0.65560, 0.63736, 0.62474, 0.61239, 0.59691, 0.58784, 0.57359, 0.57494, 0.57144, 0.56779, 0.56890, 0.57462],
+        [0.81491, 0.72108, 0.67190, 0.63845, 0.61380, 0.59879, 0.57883, 0.56773, 0.54736, 0.53580, 0.52201, 0.51020, 0.49347, 0.49293, 0.48538, 0.48590],
+        [0.79116, 0.68986, 0.63428, 0.60046, 0.57365, 0.55778, 0.53778, 0.52708, 0.50217, 0.48794, 0.47009, 0.45186, 0.43924, 0.42417, 0.41134, 0.40153],
+        [0.76767, 0.65410, 0.59809, 0.56083, 0.53690, 0.51774, 0.50411, 0.48738, 0.47572, 0.44921, 0.42351, 0.39602, 0.38973, 0.36163, 0.34250, 0.33115],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95624, 0.99756, 0.99953, 0.99997, 0.99918, 0.99991, 1.00007, 1.00083, 1.00026, 1.00033, 1.00027, 1.00072, 1.00075, 0.99992, 0.99969, 0.99994],
+        [0.98430, 0.95943, 0.98870, 0.99519, 0.99750, 0.99813, 0.99806, 0.99923, 0.99880, 0.99846, 0.99915, 0.99832, 1.00407, 0.99953, 0.99804, 1.00039],
+        [0.98586, 0.89927, 0.94945, 0.97428, 0.98628, 0.99053, 0.99343, 0.99458, 0.99546, 0.99591, 0.99741, 0.99565, 0.99671, 0.99687, 0.99653, 0.99940],
+        [0.98144, 0.88326, 0.90048, 0.93447, 0.95708, 0.96738, 0.97898, 0.97977, 0.98638, 0.98736, 0.98910, 0.98723, 0.98981, 0.98827, 0.99050, 0.99407],
+        [0.97330, 0.88644, 0.87358, 0.88984, 0.91276, 0.93491, 0.95275, 0.95152, 0.96359, 0.96857, 0.97464, 0.97256, 0.97652, 0.97879, 0.97934, 0.97955],
+        [0.96302, 0.89036, 0.86496, 0.85638, 0.87626, 0.89033, 0.90227, 0.91609, 0.92424, 0.93819, 0.94306, 0.94751, 0.94647, 0.94335, 0.96181, 0.96911],
+        [0.95103, 0.88594, 0.85477, 0.83933, 0.84061, 0.84529, 0.85503, 0.86705, 0.87394, 0.88592, 0.89252, 0.90068, 0.90171, 0.91530, 0.92803, 0.93573],
+        [0.93636, 0.87608, 0.83732, 0.81816, 0.81145, 0.80769, 0.80984, 0.81897, 0.82428, 0.82975, 0.83868, 0.84200, 0.84971, 0.86341, 0.87818, 0.89304],
+        [0.92010, 0.85567, 0.81756, 0.79570, 0.77831, 0.77011, 0.76647, 0.76502, 0.76903, 0.76729, 0.77202, 0.78068, 0.78593, 0.79215, 0.81186, 0.83200],
+        [0.90298, 0.83141, 0.79180, 0.76360, 0.74507, 0.72994, 0.72241, 0.71464, 0.70854, 0.70198, 0.70441, 0.70813, 0.71322, 0.71551, 0.73328, 0.75173],
+        [0.88258, 0.80543, 0.75956, 0.72952, 0.70382, 0.68781, 0.67597, 0.66449, 0.65099, 0.64453, 0.63862, 0.63563, 0.63763, 0.63048, 0.64919, 0.66010],
+        [0.86230, 0.77362, 0.72528, 0.69077, 0.66601, 0.64693, 0.62811, 0.61441, 0.59771, 0.58685, 0.57592, 0.57073, 0.55736, 0.55266, 0.56431, 0.56869],
+        [0.84252, 0.74290, 0.68910, 0.64944, 0.62556, 0.60227, 0.58673, 0.56891, 0.54857, 0.52796, 0.51909, 0.50519, 0.48743, 0.48253, 0.47686, 0.47573],
+        [0.82125, 0.70986, 0.65289, 0.61316, 0.58564, 0.56361, 0.54246, 0.52285, 0.49837, 0.47443, 0.45886, 0.45010, 0.42397, 0.42061, 0.40376, 0.38986],
+        [0.80023, 0.67843, 0.61619, 0.57727, 0.54924, 0.52119, 0.50136, 0.48321, 0.45526, 0.42903, 0.40483, 0.39203, 0.36706, 0.34929, 0.33519, 0.32109],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95627, 0.99737, 0.99909, 0.99968, 1.00007, 0.99952, 0.99979, 0.99974, 0.99962, 0.99955, 0.99947, 0.99983, 1.00030, 0.99990, 0.99950, 0.99999],
+        [0.98506, 0.95974, 0.98952, 0.99529, 0.99767, 0.99833, 0.99857, 0.99871, 0.99921, 0.99873, 1.00145, 0.99873, 0.99956, 0.99953, 0.99970, 1.00071],
+        [0.98703, 0.89902, 0.95188, 0.97367, 0.98492, 0.99063, 0.99428, 0.99422, 0.99489, 0.99941, 0.99668, 0.99718, 1.00186, 0.99622, 0.99797, 0.99785],
+        [0.98359, 0.88338, 0.90048, 0.93283, 0.95592, 0.96646, 0.97681, 0.98440, 0.98529, 0.98457, 0.98990, 0.98787, 0.99094, 0.98918, 0.99188, 0.99263],
+        [0.97675, 0.89007, 0.88040, 0.89007, 0.91376, 0.93343, 0.94760, 0.95418, 0.96125, 0.96719, 0.97177, 0.97481, 0.97390, 0.97276, 0.98183, 0.98649],
+        [0.96833, 0.89707, 0.86503, 0.86176, 0.87386, 0.88771, 0.90451, 0.92057, 0.92591, 0.93511, 0.94131, 0.94877, 0.94360, 0.95100, 0.95900, 0.96718],
+        [0.95767, 0.89360, 0.85721, 0.84102, 0.84389, 0.84549, 0.85816, 0.86693, 0.87701, 0.88701, 0.89299, 0.90222, 0.90735, 0.90550, 0.92584, 0.93671],
+        [0.94494, 0.87948, 0.84411, 0.82261, 0.81504, 0.81226, 0.81306, 0.82409, 0.82655, 0.82772, 0.83527, 0.84364, 0.85173, 0.85985, 0.87438, 0.89207],
+        [0.93137, 0.86432, 0.82612, 0.80282, 0.78201, 0.77306, 0.76924, 0.76947, 0.76843, 0.76517, 0.77006, 0.77965, 0.78395, 0.79374, 0.81024, 0.82617],
+        [0.91576, 0.84342, 0.80254, 0.77041, 0.74829, 0.73547, 0.72626, 0.71699, 0.71046, 0.70795, 0.70684, 0.70545, 0.70818, 0.72603, 0.72919, 0.74774],
+        [0.89915, 0.81670, 0.77101, 0.73654, 0.71503, 0.69617, 0.68086, 0.66351, 0.65249, 0.64512, 0.63617, 0.63358, 0.63917, 0.62315, 0.64476, 0.65664],
+        [0.88265, 0.79059, 0.73742, 0.70028, 0.67407, 0.65316, 0.63404, 0.61438, 0.59871, 0.58719, 0.57316, 0.55982, 0.55185, 0.55596, 0.55725, 0.56218],
+        [0.86309, 0.75997, 0.70100, 0.66321, 0.63157, 0.61025, 0.58793, 0.56628, 0.54550, 0.52748, 0.51296, 0.49629, 0.48632, 0.48417, 0.47533, 0.47043],
+        [0.84460, 0.72890, 0.66721, 0.62660, 0.59196, 0.56875, 0.54536, 0.52190, 0.49582, 0.47055, 0.45718, 0.43847, 0.41464, 0.41468, 0.39637, 0.38727],
+        [0.82676, 0.69975, 0.63073, 0.58764, 0.55241, 0.52944, 0.50591, 0.47689, 0.44673, 0.42773, 0.40629, 0.37993, 0.35489, 0.34946, 0.32867, 0.31489],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95661, 0.99794, 0.99928, 0.99957, 1.00027, 1.00029, 1.00001, 1.00033, 0.99987, 0.99988, 1.00033, 0.99994, 0.99947, 0.99940, 0.99956, 0.99975],
+        [0.98560, 0.95923, 0.98872, 0.99486, 0.99761, 0.99765, 0.99865, 0.99887, 0.99913, 0.99917, 1.00068, 0.99897, 0.99913, 0.99852, 0.99849, 0.99982],
+        [0.98872, 0.90069, 0.95196, 0.97384, 0.98521, 0.99150, 0.99167, 0.99630, 0.99580, 0.99714, 0.99646, 0.99731, 0.99607, 0.99810, 0.99655, 0.99820],
+        [0.98545, 0.88322, 0.90207, 0.93357, 0.95793, 0.97092, 0.97657, 0.98007, 0.98486, 0.98734, 0.98869, 0.99131, 0.99049, 0.99399, 0.99222, 0.99323],
+        [0.97997, 0.89034, 0.87488, 0.89200, 0.91223, 0.93098, 0.94756, 0.95793, 0.96629, 0.96721, 0.97321, 0.97053, 0.97602, 0.97764, 0.97940, 0.98690],
+        [0.97247, 0.89651, 0.86631, 0.86446, 0.87389, 0.89241, 0.90571, 0.91980, 0.92974, 0.93766, 0.94239, 0.95083, 0.94782, 0.95053, 0.95937, 0.96731],
+        [0.96349, 0.89552, 0.86059, 0.84451, 0.84295, 0.84988, 0.86082, 0.86836, 0.88100, 0.88671, 0.89674, 0.90341, 0.90769, 0.91539, 0.92141, 0.93493],
+        [0.95257, 0.88733, 0.84749, 0.82739, 0.81697, 0.81590, 0.81755, 0.82122, 0.82886, 0.82990, 0.84156, 0.84355, 0.85460, 0.86169, 0.87355, 0.89142],
+        [0.94117, 0.87114, 0.83069, 0.80710, 0.78739, 0.77831, 0.77090, 0.77117, 0.76919, 0.77131, 0.77443, 0.77869, 0.78864, 0.79826, 0.80737, 0.82735],
+        [0.92692, 0.85116, 0.80886, 0.77932, 0.75492, 0.73990, 0.72855, 0.71802, 0.70841, 0.70883, 0.70619, 0.71086, 0.71267, 0.72324, 0.72776, 0.74634],
+        [0.91406, 0.82860, 0.77887, 0.74496, 0.71950, 0.70055, 0.68110, 0.66567, 0.65413, 0.64294, 0.63683, 0.63676, 0.62842, 0.64591, 0.64193, 0.65296],
+        [0.89691, 0.80069, 0.74656, 0.71100, 0.68139, 0.65917, 0.63720, 0.61273, 0.59911, 0.58266, 0.57070, 0.56303, 0.55555, 0.55023, 0.55631, 0.55861],
+        [0.88096, 0.77305, 0.71446, 0.67347, 0.64171, 0.61489, 0.59097, 0.56406, 0.54378, 0.52321, 0.50728, 0.49416, 0.48485, 0.48072, 0.46849, 0.46748],
+        [0.86544, 0.74438, 0.67925, 0.63586, 0.60201, 0.57173, 0.54542, 0.51842, 0.49026, 0.46784, 0.44424, 0.43319, 0.42327, 0.40255, 0.39138, 0.38498],
+        [0.84765, 0.71616, 0.64505, 0.59651, 0.55898, 0.53041, 0.50247, 0.47458, 0.44285, 0.41860, 0.39585, 0.37328, 0.35696, 0.33987, 0.32283, 0.30973],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95591, 0.99748, 0.99960, 1.00001, 1.00020, 0.99952, 0.99985, 1.00025, 1.00045, 0.99923, 0.99978, 0.99969, 0.99957, 0.99889, 0.99914, 1.00000],
+        [0.98578, 0.95990, 0.98977, 0.99507, 0.99684, 0.99847, 0.99841, 0.99882, 1.00051, 0.99915, 0.99928, 0.99932, 0.99900, 0.99849, 0.99865, 0.99950],
+        [0.98874, 0.89982, 0.94982, 0.97539, 0.98563, 0.98952, 0.99232, 0.99718, 0.99829, 0.99572, 0.99738, 1.00010, 0.99791, 0.99650, 0.99751, 0.99681],
+        [0.98691, 0.88490, 0.90289, 0.93358, 0.95615, 0.96930, 0.97554, 0.98129, 0.98678, 0.98895, 0.98978, 0.98853, 0.98847, 0.99274, 0.99115, 0.99652],
+        [0.98226, 0.89332, 0.87694, 0.89296, 0.91449, 0.93285, 0.94534, 0.95607, 0.96510, 0.97128, 0.97115, 0.97527, 0.97818, 0.97250, 0.97987, 0.98675],
+        [0.97550, 0.89749, 0.86597, 0.86343, 0.87407, 0.89365, 0.90453, 0.91751, 0.92832, 0.93713, 0.94504, 0.94821, 0.94969, 0.95249, 0.95803, 0.96982],
+        [0.96806, 0.89875, 0.86348, 0.84665, 0.84367, 0.85025, 0.86048, 0.87191, 0.88415, 0.89062, 0.89814, 0.90395, 0.90999, 0.91413, 0.92239, 0.94087],
+        [0.95815, 0.89293, 0.84756, 0.82974, 0.81845, 0.81364, 0.81674, 0.81875, 0.83159, 0.83604, 0.84308, 0.84245, 0.85446, 0.86414, 0.87594, 0.88956],
+        [0.94754, 0.87733, 0.83416, 0.80569, 0.79198, 0.77980, 0.77318, 0.77358, 0.76994, 0.76659, 0.77649, 0.78041, 0.78819, 0.79006, 0.80604, 0.82306],
+        [0.93574, 0.85998, 0.81240, 0.78198, 0.76039, 0.74240, 0.73016, 0.71853, 0.70949, 0.71034, 0.71072, 0.70595, 0.71389, 0.71990, 0.72832, 0.74362],
+        [0.92345, 0.83718, 0.78570, 0.75235, 0.72286, 0.70158, 0.68727, 0.66756, 0.65520, 0.64407, 0.63866, 0.63286, 0.63486, 0.63669, 0.64174, 0.65060],
+        [0.90993, 0.81191, 0.75783, 0.71891, 0.68667, 0.66170, 0.63922, 0.61305, 0.59679, 0.58654, 0.57024, 0.56298, 0.55381, 0.56095, 0.55391, 0.55808],
+        [0.89532, 0.78486, 0.72439, 0.67977, 0.64584, 0.61655, 0.59340, 0.56429, 0.54350, 0.52497, 0.51302, 0.49267, 0.48820, 0.46732, 0.46728, 0.46471],
+        [0.88099, 0.75653, 0.69027, 0.64228, 0.60316, 0.57683, 0.54720, 0.51610, 0.49263, 0.46690, 0.44567, 0.42693, 0.41307, 0.41066, 0.39059, 0.38003],
+        [0.86678, 0.72850, 0.65575, 0.60493, 0.56370, 0.53061, 0.50312, 0.47014, 0.44368, 0.40743, 0.39246, 0.37705, 0.35197, 0.33313, 0.32229, 0.30840],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95637, 0.99753, 1.00019, 1.00002, 0.99982, 0.99991, 0.99960, 0.99942, 0.99974, 0.99943, 1.00052, 0.99970, 0.99951, 0.99971, 1.00115, 0.99993],
+        [0.98593, 0.95978, 0.98919, 0.99655, 0.99718, 0.99904, 0.99898, 0.99833, 0.99941, 0.99956, 0.99955, 0.99924, 0.99919, 1.00260, 0.99928, 0.99917],
+        [0.98994, 0.90038, 0.95153, 0.97423, 0.98483, 0.99157, 0.99344, 0.99415, 0.99680, 0.99594, 0.99890, 0.99562, 0.99526, 0.99656, 0.99533, 1.00013],
+        [0.98769, 0.88460, 0.90183, 0.93484, 0.95571, 0.96821, 0.97649, 0.98453, 0.98470, 0.98708, 0.99055, 0.99175, 0.99349, 0.99078, 0.99061, 0.99445],
+        [0.98464, 0.89453, 0.87625, 0.89131, 0.91593, 0.93428, 0.94522, 0.95580, 0.96840, 0.96942, 0.97233, 0.97266, 0.97571, 0.98249, 0.98013, 0.98506],
+        [0.97890, 0.90120, 0.86752, 0.86473, 0.87530, 0.89045, 0.90566, 0.91763, 0.93013, 0.93808, 0.94472, 0.94825, 0.94866, 0.95540, 0.95544, 0.96977],
+        [0.97119, 0.90066, 0.86393, 0.84737, 0.84245, 0.85089, 0.86161, 0.87307, 0.88171, 0.88651, 0.90175, 0.90859, 0.91173, 0.91811, 0.92550, 0.93601],
+        [0.96293, 0.89284, 0.85469, 0.83166, 0.82002, 0.81615, 0.81707, 0.82122, 0.82608, 0.83763, 0.84508, 0.84711, 0.86088, 0.86396, 0.87200, 0.89016],
+        [0.95387, 0.88244, 0.83774, 0.81113, 0.79374, 0.78327, 0.77321, 0.77370, 0.77539, 0.77499, 0.77521, 0.78336, 0.79175, 0.79891, 0.80566, 0.82501],
+        [0.94292, 0.86207, 0.81907, 0.78742, 0.76152, 0.74774, 0.73350, 0.72039, 0.71228, 0.71263, 0.70777, 0.71294, 0.71489, 0.72411, 0.72734, 0.74260],
+        [0.93235, 0.84380, 0.79199, 0.75694, 0.72776, 0.70470, 0.68623, 0.66887, 0.65528, 0.64518, 0.64053, 0.63594, 0.63251, 0.63156, 0.64254, 0.65021],
+        [0.92069, 0.81946, 0.76288, 0.72309, 0.69184, 0.66300, 0.63920, 0.61459, 0.60175, 0.58550, 0.56828, 0.56379, 0.55999, 0.55850, 0.55236, 0.55456],
+        [0.90712, 0.79332, 0.73172, 0.68656, 0.64968, 0.62084, 0.59111, 0.56829, 0.54156, 0.52161, 0.50628, 0.49359, 0.48642, 0.47101, 0.46469, 0.46207],
+        [0.89455, 0.76816, 0.69829, 0.64613, 0.60912, 0.57753, 0.54187, 0.51351, 0.48915, 0.46499, 0.44400, 0.42471, 0.41985, 0.39821, 0.38895, 0.37903],
+        [0.88229, 0.73969, 0.66476, 0.60942, 0.56794, 0.53351, 0.50316, 0.46148, 0.43694, 0.41329, 0.39114, 0.37504, 0.34920, 0.33625, 0.32013, 0.30561],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95631, 0.99818, 0.99917, 0.99963, 0.99988, 0.99965, 0.99979, 1.00012, 0.99981, 0.99994, 1.00001, 1.00027, 0.99956, 1.00022, 1.00016, 0.99988],
+        [0.98660, 0.96107, 0.98879, 0.99491, 0.99778, 0.99808, 0.99872, 0.99883, 0.99859, 1.00061, 0.99962, 0.99921, 0.99913, 0.99917, 0.99924, 0.99852],
+        [0.98991, 0.90060, 0.95128, 0.97546, 0.98525, 0.98986, 0.99271, 0.99463, 0.99556, 0.99571, 0.99630, 0.99604, 0.99775, 0.99550, 0.99505, 0.99837],
+        [0.98943, 0.88609, 0.90485, 0.93357, 0.95674, 0.96780, 0.97667, 0.98236, 0.98822, 0.98904, 0.98702, 0.99329, 0.98995, 0.99218, 0.98940, 0.99162],
+        [0.98506, 0.89540, 0.87638, 0.89190, 0.91632, 0.93135, 0.94916, 0.95762, 0.96407, 0.97232, 0.97191, 0.97386, 0.97680, 0.97902, 0.97603, 0.98414],
+        [0.98044, 0.90213, 0.87034, 0.86611, 0.87780, 0.89075, 0.90579, 0.91936, 0.92969, 0.93866, 0.94198, 0.94475, 0.95508, 0.95248, 0.95725, 0.96724],
+        [0.97463, 0.90216, 0.86477, 0.84693, 0.84487, 0.85298, 0.86277, 0.87136, 0.88395, 0.89405, 0.89982, 0.90713, 0.91388, 0.91196, 0.92344, 0.93616],
+        [0.96677, 0.89857, 0.85467, 0.83265, 0.82117, 0.81934, 0.82124, 0.82042, 0.83243, 0.83613, 0.83999, 0.84667, 0.85725, 0.86189, 0.87298, 0.88905],
+        [0.95866, 0.88732, 0.84191, 0.81320, 0.79667, 0.77997, 0.77754, 0.77184, 0.77087, 0.77729, 0.78176, 0.78662, 0.78999, 0.80401, 0.80763, 0.82355],
+        [0.94965, 0.86715, 0.82174, 0.78905, 0.76575, 0.74932, 0.73342, 0.72377, 0.71729, 0.71141, 0.70754, 0.70970, 0.71480, 0.71966, 0.72860, 0.74111],
+        [0.93944, 0.84893, 0.79728, 0.75965, 0.73105, 0.70599, 0.68781, 0.67142, 0.65802, 0.64972, 0.63890, 0.62989, 0.63554, 0.63918, 0.64061, 0.64923],
+        [0.92978, 0.82715, 0.76677, 0.72646, 0.69395, 0.66800, 0.64068, 0.61717, 0.60104, 0.58679, 0.57649, 0.56374, 0.56039, 0.55925, 0.55170, 0.55294],
+        [0.91746, 0.80225, 0.73793, 0.69138, 0.65523, 0.62311, 0.59531, 0.56713, 0.54361, 0.52607, 0.51360, 0.49439, 0.47950, 0.47148, 0.46724, 0.45958],
+        [0.90639, 0.77634, 0.70385, 0.65175, 0.61174, 0.57749, 0.54706, 0.51380, 0.48557, 0.46643, 0.44577, 0.42964, 0.40713, 0.39917, 0.38731, 0.37652],
+        [0.89325, 0.74927, 0.67125, 0.61643, 0.57362, 0.53726, 0.50013, 0.46490, 0.43824, 0.40659, 0.39024, 0.36729, 0.35090, 0.33177, 0.31789, 0.30683],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95615, 0.99771, 0.99931, 0.99928, 0.99982, 0.99972, 0.99960, 0.99993, 0.99994, 1.00002, 1.00014, 1.00020, 0.99950, 0.99971, 1.00269, 1.00029],
+        [0.98704, 0.96001, 0.98962, 0.99508, 0.99667, 0.99820, 0.99832, 0.99899, 0.99870, 0.99919, 0.99941, 0.99901, 0.99854, 1.00045, 1.00028, 0.99947],
+        [0.99121, 0.89996, 0.95030, 0.97569, 0.98484, 0.99002, 0.99330, 0.99450, 0.99547, 0.99557, 0.99593, 0.99799, 0.99799, 0.99598, 0.99442, 0.99776],
+        [0.98937, 0.88559, 0.90349, 0.93371, 0.95537, 0.96799, 0.97625, 0.98231, 0.98649, 0.98835, 0.98912, 0.99144, 0.99296, 0.99174, 0.99293, 0.99374],
+        [0.98708, 0.89397, 0.87888, 0.89592, 0.91258, 0.93437, 0.94883, 0.95610, 0.96363, 0.96882, 0.97261, 0.97953, 0.97612, 0.98133, 0.97553, 0.98526],
+        [0.98299, 0.90438, 0.87039, 0.86930, 0.87896, 0.89091, 0.90558, 0.91804, 0.93015, 0.93658, 0.94586, 0.95135, 0.95031, 0.95419, 0.95179, 0.96887],
+        [0.97700, 0.90408, 0.86532, 0.85013, 0.84754, 0.85306, 0.86365, 0.87248, 0.88263, 0.89329, 0.90126, 0.90598, 0.91445, 0.92300, 0.92481, 0.93590],
+        [0.97142, 0.89933, 0.85841, 0.83464, 0.82150, 0.82084, 0.81693, 0.82243, 0.83063, 0.83609, 0.84549, 0.85196, 0.85553, 0.86525, 0.87451, 0.88778],
+        [0.96335, 0.88879, 0.84515, 0.81601, 0.79796, 0.78372, 0.77321, 0.77609, 0.77402, 0.77866, 0.78003, 0.78466, 0.79137, 0.80133, 0.80105, 0.82351],
+        [0.95415, 0.87206, 0.82537, 0.79104, 0.76603, 0.75190, 0.73288, 0.72248, 0.71541, 0.71419, 0.70763, 0.71553, 0.70865, 0.72117, 0.73022, 0.74001],
+        [0.94614, 0.85401, 0.80100, 0.76291, 0.73335, 0.70731, 0.69125, 0.67132, 0.65770, 0.64342, 0.63948, 0.63639, 0.63555, 0.63749, 0.63625, 0.64785],
+        [0.93656, 0.83221, 0.77368, 0.73141, 0.69601, 0.66603, 0.64215, 0.61996, 0.60130, 0.58652, 0.56976, 0.56419, 0.55488, 0.54992, 0.55360, 0.55239],
+        [0.92630, 0.80753, 0.74115, 0.69525, 0.65998, 0.62550, 0.59536, 0.56630, 0.54107, 0.52366, 0.50674, 0.49555, 0.48726, 0.47048, 0.46733, 0.46020],
+        [0.91584, 0.78436, 0.70922, 0.65760, 0.61555, 0.58055, 0.54486, 0.51022, 0.48577, 0.46336, 0.44734, 0.42305, 0.41006, 0.40401, 0.39437, 0.37568],
+        [0.90356, 0.75722, 0.67703, 0.62067, 0.57582, 0.53742, 0.49914, 0.46530, 0.43116, 0.41346, 0.38450, 0.35946, 0.35010, 0.33686, 0.31360, 0.30423],
+    ],
+    [
+        [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+        [0.95703, 0.99767, 0.99923, 1.00002, 0.99988, 0.99978, 0.99922, 1.00032, 1.00006, 0.99955, 0.99930, 1.00015, 0.99975, 1.00001, 0.99849, 1.00000],
+        [0.98715, 0.96020, 0.98888, 0.99519, 0.99731, 0.99866, 0.99920, 0.99894, 0.99949, 1.00011, 0.99939, 0.99953, 0.99937, 0.99833, 0.99970, 0.99955],
+        [0.99123, 0.89954, 0.95028, 0.97516, 0.98467, 0.99009, 0.99270, 0.99481, 0.99553, 0.99649, 0.99851, 0.99776, 0.99661, 0.99978, 0.99800, 0.99721],
+        [0.99106, 0.88578, 0.90365, 0.93490, 0.95614, 0.97036, 0.97582, 0.98202, 0.98641, 0.98759, 0.98943, 0.99255, 0.99068, 0.98853, 0.98977, 0.99427],
+        [0.98747, 0.89554, 0.87755, 0.89337, 0.91734, 0.93416, 0.94641, 0.95695, 0.96500, 0.97078, 0.97413, 0.97285, 0.97856, 0.97867, 0.97484, 0.98766],
+        [0.98420, 0.90416, 0.87057, 0.86615, 0.87583, 0.89187, 0.90702, 0.91823, 0.93109, 0.93821, 0.93934, 0.95022, 0.95370, 0.95763, 0.95473, 0.96777],
+        [0.97943, 0.90885, 0.86811, 0.84832, 0.84817, 0.85359, 0.86269, 0.87119, 0.88199, 0.89270, 0.90034, 0.90608, 0.91493, 0.92293, 0.91789, 0.93533],
+        [0.97339, 0.90171, 0.86104, 0.83481, 0.82352, 0.81935, 0.81988, 0.82512, 0.83232, 0.83831, 0.84367, 0.85496, 0.85767, 0.86430, 0.87075, 0.88776],
+        [0.96678, 0.89152, 0.84719, 0.81564, 0.79701, 0.78364, 0.77657, 0.77483, 0.77588, 0.77693, 0.78060, 0.78664, 0.79253, 0.79939, 0.81280, 0.82177],
+        [0.95900, 0.87662, 0.82770, 0.79370, 0.76904, 0.75033, 0.73194, 0.72403, 0.71788, 0.71532, 0.71565, 0.71188, 0.71421, 0.72108, 0.72801, 0.74032],
+        [0.95138, 0.85869, 0.80491, 0.76480, 0.73775, 0.70931, 0.69041, 0.67309, 0.66175, 0.64955, 0.64256, 0.64014, 0.63485, 0.63891, 0.63932, 0.64679],
+        [0.94249, 0.83799, 0.77674, 0.73261, 0.69965, 0.66680, 0.63940, 0.61881, 0.60106, 0.58773, 0.57112, 0.56097, 0.55746, 0.55088, 0.55010, 0.55219],
+        [0.93415, 0.81357, 0.74800, 0.69833, 0.65962, 0.62741, 0.59467, 0.56617, 0.54284, 0.52564, 0.50666, 0.49431, 0.48255, 0.47406, 0.46848, 0.45933],
+        [0.92433, 0.78978, 0.71435, 0.66030, 0.61930, 0.57702, 0.54338, 0.51349, 0.48790, 0.45910, 0.44596, 0.42061, 0.41635, 0.40004, 0.38276, 0.37434],
+        [0.91330, 0.76518, 0.68285, 0.62424, 0.57748, 0.53484, 0.49373, 0.46004, 0.42890, 0.40461, 0.38875, 0.36934, 0.33882, 0.33171, 0.31627, 0.30294],
+    ],
+];
+
+pub const DIELECTRIC_AVERAGE_INSIDE: [[f32; N_DIELECTRIC_ROUGH]; N_ETA] = [
+    [1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000, 1.00000],
+    [1.00000, 0.99981, 0.99829, 0.99229, 0.97932, 0.95945, 0.93316, 0.90110, 0.86803, 0.83316, 0.79970, 0.77046, 0.74219, 0.71463, 0.68992, 0.66736],
+    [1.00000, 0.99986, 0.99798, 0.99207, 0.98018, 0.95855, 0.92860, 0.88936, 0.84733, 0.80012, 0.75558, 0.71129, 0.67138, 0.63262, 0.60231, 0.56867],
+    [1.00000, 0.99982, 0.99863, 0.99273, 0.98108, 0.95917, 0.92916, 0.88988, 0.84080, 0.78942, 0.73798, 0.68302, 0.63422, 0.58929, 0.54730, 0.50855],
+    [1.00000, 0.99981, 0.99852, 0.99299, 0.98099, 0.96190, 0.92888, 0.88950, 0.83934, 0.78544, 0.72717, 0.66992, 0.61211, 0.56236, 0.51712, 0.47374],
+    [1.00000, 0.99977, 0.99909, 0.99241, 0.98165, 0.96083, 0.93150, 0.89007, 0.83763, 0.78302, 0.72316, 0.66149, 0.60276, 0.54674, 0.49475, 0.44880],
+    [1.00000, 1.00023, 0.99865, 0.99360, 0.98225, 0.96179, 0.93145, 0.89239, 0.84024, 0.78268, 0.72172, 0.65862, 0.59652, 0.53621, 0.48287, 0.43418],
+    [1.00000, 0.99994, 0.99812, 0.99323, 0.98194, 0.96243, 0.93184, 0.89190, 0.84203, 0.78330, 0.72046, 0.65695, 0.59285, 0.53048, 0.47462, 0.42491],
+    [1.00000, 1.00016, 0.99870, 0.99343, 0.98187, 0.96334, 0.93349, 0.89270, 0.84449, 0.78663, 0.72228, 0.65554, 0.59025, 0.52747, 0.46986, 0.41553],
+    [1.00000, 0.99974, 0.99877, 0.99431, 0.98205, 0.96285, 0.93383, 0.89291, 0.84470, 0.78664, 0.72394, 0.65528, 0.58902, 0.52652, 0.46664, 0.41231],
+    [1.00000, 0.99981, 0.99834, 0.99369, 0.98310, 0.96346, 0.93537, 0.89469, 0.84652, 0.78910, 0.72526, 0.65744, 0.58929, 0.52496, 0.46398, 0.40835],
+    [1.00000, 0.99960, 0.99835, 0.99416, 0.98283, 0.96368, 0.93547, 0.89640, 0.84754, 0.78825, 0.72565, 0.65784, 0.59105, 0.52473, 0.46370, 0.40658],
+    [1.00000, 0.99992, 0.99894, 0.99338, 0.98322, 0.96447, 0.93561, 0.89754, 0.84863, 0.79145, 0.72771, 0.65826, 0.59178, 0.52440, 0.46210, 0.40599],
+    [1.00000, 0.99993, 0.99847, 0.99304, 0.98284, 0.96385, 0.93576, 0.89741, 0.84833, 0.79301, 0.72773, 0.65961, 0.59326, 0.52587, 0.46169, 0.40498],
+    [1.00000, 1.00020, 0.99857, 0.99314, 0.98342, 0.96440, 0.93579, 0.89909, 0.84961, 0.79241, 0.72822, 0.65946, 0.59177, 0.52619, 0.46290, 0.40389],
+    [1.00000, 0.99964, 0.99860, 0.99409, 0.98265, 0.96416, 0.93629, 0.89803, 0.85009, 0.79401, 0.72940, 0.66175, 0.59168, 0.52663, 0.46134, 0.40241],
+];
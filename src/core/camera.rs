@@ -4,7 +4,6 @@ use crate::core::ray::Ray;
 
 pub struct PerspectiveCamera {
     camera_to_world: Transform,
-    raster_to_camera: Transform,
     dx_camera: Vector3,
     dy_camera: Vector3,
 }
@@ -35,7 +34,6 @@ impl PerspectiveCamera {
 
         PerspectiveCamera {
             camera_to_world,
-            raster_to_camera: Transform::new(crate::core::transform::Matrix4x4::identity()),
             dx_camera,
             dy_camera,
         }
@@ -1,11 +1,19 @@
-use crate::core::geometry::{Point2i, Vector3};
-use crate::core::spectrum::SampledSpectrum;
+use crate::core::geometry::{Point2, Point2i, Vector3};
 use std::fs::File;
 use std::io::Write;
 
 pub struct Film {
     pub resolution: Point2i,
     pixels: Vec<Vector3>, // Storing simplified RGB for now
+    light_groups: Vec<LightGroup>,
+}
+
+/// AOV layer holding the radiance contributed by a named set of lights
+/// (by scene light index), for relighting in compositing.
+pub struct LightGroup {
+    pub name: String,
+    pub lights: Vec<usize>,
+    pixels: Vec<Vector3>,
 }
 
 impl Film {
@@ -14,7 +22,49 @@ impl Film {
         Film {
             resolution,
             pixels: vec![Vector3 { x: 0.0, y: 0.0, z: 0.0 }; count],
+            light_groups: Vec::new(),
+        }
+    }
+
+    /// Adds a light-group layer; returns its index
+    pub fn add_light_group(&mut self, name: &str, lights: &[usize]) -> usize {
+        let count = (self.resolution.x * self.resolution.y) as usize;
+        self.light_groups.push(LightGroup {
+            name: name.to_string(),
+            lights: lights.to_vec(),
+            pixels: vec![Vector3 { x: 0.0, y: 0.0, z: 0.0 }; count],
+        });
+        self.light_groups.len() - 1
+    }
+
+    pub fn light_groups(&self) -> &[LightGroup] {
+        &self.light_groups
+    }
+
+    /// Light group of each of `n_lights` lights (the first group listing it wins)
+    pub fn light_group_map(&self, n_lights: usize) -> Vec<Option<usize>> {
+        let mut map = vec![None; n_lights];
+        for (g, group) in self.light_groups.iter().enumerate().rev() {
+            for &light in group.lights.iter().filter(|&&l| l < n_lights) {
+                map[light] = Some(g);
+            }
         }
+        map
+    }
+
+    #[cfg(test)]
+    pub fn light_group_pixel(&self, group: usize, p: Point2i) -> Vector3 {
+        self.light_groups[group].pixels[(p.y * self.resolution.x + p.x) as usize]
+    }
+
+    pub fn set_light_group_pixel(&mut self, group: usize, p: Point2i, color: Vector3) {
+        let idx = (p.y * self.resolution.x + p.x) as usize;
+        self.light_groups[group].pixels[idx] = color;
+    }
+
+    #[cfg(test)]
+    pub fn pixel(&self, p: Point2i) -> Vector3 {
+        self.pixels[(p.y * self.resolution.x + p.x) as usize]
     }
 
     pub fn set_pixel(&mut self, p: Point2i, color: Vector3) {
@@ -22,12 +72,36 @@ impl Film {
         self.pixels[idx] = color;
     }
 
+    // Adds a contribution at an arbitrary raster position (used by Metropolis
+    // light transport, whose samples are not tied to the pixel being rendered).
+    pub fn add_splat(&mut self, p: Point2, v: Vector3) {
+        let x = p.x.floor() as i32;
+        let y = p.y.floor() as i32;
+        if x < 0 || y < 0 || x >= self.resolution.x || y >= self.resolution.y {
+            return;
+        }
+        let idx = (y * self.resolution.x + x) as usize;
+        self.pixels[idx] = self.pixels[idx] + v;
+    }
+
     // Output to a simple PPM image format (readable by most viewers)
     pub fn write_image(&self, filename: &str) -> std::io::Result<()> {
+        self.write_ppm(filename, &self.pixels)
+    }
+
+    /// Writes each light group to `<prefix>_<name>.ppm`
+    pub fn write_light_groups(&self, prefix: &str) -> std::io::Result<()> {
+        for group in &self.light_groups {
+            self.write_ppm(&format!("{}_{}.ppm", prefix, group.name), &group.pixels)?;
+        }
+        Ok(())
+    }
+
+    fn write_ppm(&self, filename: &str, pixels: &[Vector3]) -> std::io::Result<()> {
         let mut file = File::create(filename)?;
         write!(file, "P3\n{} {}\n255\n", self.resolution.x, self.resolution.y)?;
 
-        for p in &self.pixels {
+        for p in pixels {
             let r = (p.x.sqrt().clamp(0.0, 1.0) * 255.99) as u8; // Gamma correction (sqrt)
             let g = (p.y.sqrt().clamp(0.0, 1.0) * 255.99) as u8;
             let b = (p.z.sqrt().clamp(0.0, 1.0) * 255.99) as u8;
@@ -35,4 +109,4 @@ impl Film {
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -1,3 +1,4 @@
+use std::f32::consts::PI;
 use std::ops::{Add, Sub, Mul, Neg};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -122,6 +123,114 @@ impl Bounds3 {
             max: Point3 { x: self.max.x.max(p.x), y: self.max.y.max(p.y), z: self.max.z.max(p.z) },
         }
     }
+
+    // Slab test: parametric range [t0, t1] of the ray inside the box, clipped to [0, t_max]
+    pub fn intersect_p(&self, o: Point3, d: Vector3, t_max: f32) -> Option<(f32, f32)> {
+        let mut t0 = 0.0f32;
+        let mut t1 = t_max;
+        let axes = [(o.x, d.x, self.min.x, self.max.x),
+                    (o.y, d.y, self.min.y, self.max.y),
+                    (o.z, d.z, self.min.z, self.max.z)];
+        for (o, d, lo, hi) in axes {
+            let inv_d = 1.0 / d;
+            let mut t_near = (lo - o) * inv_d;
+            let mut t_far = (hi - o) * inv_d;
+            if t_near > t_far { std::mem::swap(&mut t_near, &mut t_far); }
+            // NaN (ray parallel to and on the slab boundary) leaves the range unchanged
+            if t_near > t0 { t0 = t_near; }
+            if t_far < t1 { t1 = t_far; }
+            if t0 > t1 { return None; }
+        }
+        Some((t0, t1))
+    }
+
+    pub fn inside(&self, p: Point3) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x &&
+        p.y >= self.min.y && p.y <= self.max.y &&
+        p.z >= self.min.z && p.z <= self.max.z
+    }
+
+    pub fn union(self, b: Bounds3) -> Self {
+        self.union_point(b.min).union_point(b.max)
+    }
+
+    pub fn diagonal(&self) -> Vector3 {
+        self.max - self.min
+    }
+
+    pub fn centroid(&self) -> Point3 {
+        self.min + self.diagonal() * 0.5
+    }
+
+    pub fn surface_area(&self) -> f32 {
+        let d = self.diagonal();
+        2.0 * (d.x * d.y + d.x * d.z + d.y * d.z)
+    }
+
+    /// (center, radius) of a sphere enclosing the box
+    pub fn bounding_sphere(&self) -> (Point3, f32) {
+        (self.centroid(), self.diagonal().length() * 0.5)
+    }
+}
+
+// --- Direction Cone ---
+// Set of directions within angle acos(cos_theta) of the axis `w`.
+#[derive(Debug, Clone, Copy)]
+pub struct DirectionCone {
+    pub w: Vector3,
+    pub cos_theta: f32,
+}
+
+impl DirectionCone {
+    pub fn new(w: Vector3, cos_theta: f32) -> Self {
+        DirectionCone { w: w.normalize(), cos_theta }
+    }
+
+    pub fn entire_sphere() -> Self {
+        DirectionCone { w: Vector3::new(0.0, 0.0, 1.0), cos_theta: -1.0 }
+    }
+
+    /// Directions from `p` towards any point of `b`
+    pub fn bound_subtended_directions(b: &Bounds3, p: Point3) -> Self {
+        let (center, radius) = b.bounding_sphere();
+        let dist_sq = (p - center).length_squared();
+        if dist_sq < radius * radius {
+            return DirectionCone::entire_sphere();
+        }
+        let sin2_theta_max = radius * radius / dist_sq;
+        DirectionCone::new(center - p, (1.0 - sin2_theta_max).max(0.0).sqrt())
+    }
+
+    /// Smallest cone containing both cones
+    pub fn union(a: DirectionCone, b: DirectionCone) -> Self {
+        let theta_a = a.cos_theta.clamp(-1.0, 1.0).acos();
+        let theta_b = b.cos_theta.clamp(-1.0, 1.0).acos();
+        let theta_d = a.w.dot(b.w).clamp(-1.0, 1.0).acos();
+
+        // One cone inside the other
+        if (theta_d + theta_b).min(PI) <= theta_a {
+            return a;
+        }
+        if (theta_d + theta_a).min(PI) <= theta_b {
+            return b;
+        }
+
+        let theta_o = (theta_a + theta_d + theta_b) / 2.0;
+        if theta_o >= PI {
+            return DirectionCone::entire_sphere();
+        }
+
+        // Rotate a.w towards b.w by theta_o - theta_a (Rodrigues)
+        let theta_r = theta_o - theta_a;
+        let axis = a.w.cross(b.w);
+        if axis.length_squared() == 0.0 {
+            return DirectionCone::entire_sphere();
+        }
+        let k = axis.normalize();
+        let (sin_r, cos_r) = theta_r.sin_cos();
+        let w = a.w * cos_r + k.cross(a.w) * sin_r + k * (k.dot(a.w) * (1.0 - cos_r));
+        DirectionCone::new(w, theta_o.cos())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -0,0 +1,234 @@
+use std::fs;
+use std::io;
+
+// --- IES LM-63 Photometric Profiles ---
+//
+// Text format: header lines (keywords) up to a TILT= line, optional tilt
+// data, then whitespace-separated numbers:
+//   #lamps lumens/lamp candela-multiplier #vertical #horizontal
+//   photometric-type units width length height
+//   ballast-factor ballast-lamp-factor input-watts
+//   vertical angles, horizontal angles, candela[horizontal][vertical]
+
+/// Candela distribution of a luminaire (type C photometry).
+///
+/// Vertical angles are measured from the nadir (0 = straight down, 180 =
+/// straight up); horizontal angles go around the vertical axis. Profiles
+/// that only store part of the horizontal range are expanded by the
+/// symmetry implied by their last horizontal angle (0, 90, 180 or 360).
+#[derive(Debug, Clone)]
+pub struct IesProfile {
+    pub vertical_angles: Vec<f32>,   // Degrees, increasing
+    pub horizontal_angles: Vec<f32>, // Degrees, increasing
+    pub candela: Vec<f32>,           // [horizontal][vertical], multipliers applied
+    pub lumens: f32,                 // Rated lamp lumens (-1 = absolute photometry)
+}
+
+// Sanity bounds on header counts, so garbage files fail instead of allocating
+const MAX_ANGLES: usize = 1 << 16;
+const MAX_CANDELA: usize = 1 << 24;
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("IES: {}", msg))
+}
+
+// A count read from the header, as a non-negative integer below `MAX_ANGLES`
+fn count(value: f32, what: &str) -> io::Result<usize> {
+    if value >= 0.0 && value <= MAX_ANGLES as f32 && value.fract() == 0.0 {
+        Ok(value as usize)
+    } else {
+        Err(invalid(&format!("bad {} count {}", what, value)))
+    }
+}
+
+impl IesProfile {
+    pub fn read(filename: &str) -> io::Result<Self> {
+        let text = fs::read(filename)?;
+        // Files in the wild are often Latin-1 rather than UTF-8
+        Self::parse(&String::from_utf8_lossy(&text))
+    }
+
+    pub fn parse(text: &str) -> io::Result<Self> {
+        // Skip the keyword header
+        let mut lines = text.lines();
+        let tilt = loop {
+            let line = lines.next().ok_or_else(|| invalid("missing TILT line"))?;
+            if let Some(rest) = line.trim().strip_prefix("TILT=") {
+                break rest.trim().to_string();
+            }
+        };
+
+        let mut numbers = lines
+            .flat_map(|l| l.split(|c: char| c.is_whitespace() || c == ','))
+            .filter(|t| !t.is_empty())
+            .map(|t| t.parse::<f32>().map_err(|_| invalid(&format!("bad number '{}'", t))));
+        let mut next = || numbers.next().unwrap_or_else(|| Err(invalid("unexpected end of data")));
+
+        // Tilt data only matters for tilted lamps; skip it
+        if tilt == "INCLUDE" {
+            let _geometry = next()?;
+            let n_tilt = count(next()?, "tilt angle")?;
+            for _ in 0..2 * n_tilt {
+                next()?;
+            }
+        }
+
+        let _n_lamps = next()?;
+        let lumens = next()?;
+        let multiplier = next()?;
+        let n_vertical = count(next()?, "vertical angle")?;
+        let n_horizontal = count(next()?, "horizontal angle")?;
+        let photometric_type = next()? as i32;
+        let _units = next()?;
+        let (_width, _length, _height) = (next()?, next()?, next()?);
+        let ballast_factor = next()?;
+        let ballast_lamp_factor = next()?;
+        let _input_watts = next()?;
+
+        if photometric_type != 1 {
+            return Err(invalid("only type C photometry is supported"));
+        }
+        if n_vertical == 0 || n_horizontal == 0 {
+            return Err(invalid("empty angle table"));
+        }
+        let n_candela = n_vertical
+            .checked_mul(n_horizontal)
+            .filter(|&n| n <= MAX_CANDELA)
+            .ok_or_else(|| invalid("candela table too large"))?;
+
+        let vertical_angles = (0..n_vertical).map(|_| next()).collect::<io::Result<Vec<f32>>>()?;
+        let horizontal_angles = (0..n_horizontal).map(|_| next()).collect::<io::Result<Vec<f32>>>()?;
+        let scale = multiplier * ballast_factor * ballast_lamp_factor;
+        let candela = (0..n_candela)
+            .map(|_| next().map(|c| c * scale))
+            .collect::<io::Result<Vec<f32>>>()?;
+
+        Ok(IesProfile { vertical_angles, horizontal_angles, candela, lumens })
+    }
+
+    /// Luminous intensity (cd) at vertical angle `theta` from the nadir and
+    /// horizontal angle `phi`, both in degrees, bilinearly interpolated
+    pub fn intensity(&self, theta: f32, phi: f32) -> f32 {
+        let v = &self.vertical_angles;
+        if theta < v[0] || theta > v[v.len() - 1] {
+            return 0.0;
+        }
+        let (v0, v1, tv) = segment(v, theta);
+
+        let phi = self.fold_horizontal(phi);
+        let (h0, h1, th) = segment(&self.horizontal_angles, phi);
+
+        let nv = v.len();
+        let c = |h: usize, v: usize| self.candela[h * nv + v];
+        let lerp = |a: f32, b: f32, t: f32| a * (1.0 - t) + b * t;
+        lerp(lerp(c(h0, v0), c(h0, v1), tv), lerp(c(h1, v0), c(h1, v1), tv), th)
+    }
+
+    pub fn max_intensity(&self) -> f32 {
+        self.candela.iter().fold(0.0f32, |a, &b| a.max(b))
+    }
+
+    // Maps phi in degrees into the stored horizontal range using the profile's symmetry
+    fn fold_horizontal(&self, phi: f32) -> f32 {
+        let phi = phi.rem_euclid(360.0);
+        let last = self.horizontal_angles[self.horizontal_angles.len() - 1];
+        if last <= 0.0 {
+            0.0 // Rotationally symmetric
+        } else if last <= 90.0 {
+            // Quadrant symmetry
+            let p = phi % 180.0;
+            if p > 90.0 { 180.0 - p } else { p }
+        } else if last <= 180.0 {
+            // Bilateral symmetry about the 0-180 plane
+            if phi > 180.0 { 360.0 - phi } else { phi }
+        } else {
+            phi
+        }
+    }
+}
+
+// Indices bracketing x in the increasing table `a`, and the blend factor
+fn segment(a: &[f32], x: f32) -> (usize, usize, f32) {
+    if a.len() == 1 || x <= a[0] {
+        return (0, 0, 0.0);
+    }
+    let n = a.len();
+    if x >= a[n - 1] {
+        return (n - 1, n - 1, 0.0);
+    }
+    let i = a.partition_point(|&v| v <= x) - 1;
+    let width = a[i + 1] - a[i];
+    let t = if width > 0.0 { (x - a[i]) / width } else { 0.0 };
+    (i, i + 1, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Quadrant-symmetric profile with tilt data, a multiplier of 2 and comma separators
+    const QUADRANT: &str = "IESNA:LM-63-2002
+[TEST] fixture
+[MANUFAC] none
+TILT=INCLUDE
+1
+2
+0 90
+1 1
+1 1000 2 3 2
+1 2 0.1 0.1 0.1
+1.0 1.0 60
+0, 45, 90
+0 90
+100 80 20
+50 40 10
+";
+
+    #[test]
+    fn parses_header_tilt_and_table() {
+        let ies = IesProfile::parse(QUADRANT).unwrap();
+        assert_eq!(ies.vertical_angles, [0.0, 45.0, 90.0]);
+        assert_eq!(ies.horizontal_angles, [0.0, 90.0]);
+        assert_eq!(ies.candela, [200.0, 160.0, 40.0, 100.0, 80.0, 20.0]);
+        assert_eq!(ies.lumens, 1000.0);
+        assert_eq!(ies.max_intensity(), 200.0);
+
+        assert_eq!(ies.intensity(0.0, 0.0), 200.0);
+        assert_eq!(ies.intensity(22.5, 0.0), 180.0);
+        assert_eq!(ies.intensity(45.0, 45.0), 120.0);
+        assert_eq!(ies.intensity(120.0, 0.0), 0.0, "beyond the last vertical angle");
+    }
+
+    #[test]
+    fn fold_horizontal_follows_symmetry() {
+        let profile = |last: f32| IesProfile {
+            vertical_angles: vec![0.0],
+            horizontal_angles: vec![0.0, last],
+            candela: vec![1.0, 1.0],
+            lumens: -1.0,
+        };
+        let rotational = IesProfile { horizontal_angles: vec![0.0], candela: vec![1.0], ..profile(0.0) };
+        assert_eq!(rotational.fold_horizontal(123.0), 0.0);
+
+        let quadrant = profile(90.0);
+        for (phi, folded) in [(30.0, 30.0), (150.0, 30.0), (210.0, 30.0), (330.0, 30.0), (-30.0, 30.0)] {
+            assert!((quadrant.fold_horizontal(phi) - folded).abs() < 1e-4, "quadrant {}", phi);
+        }
+        let bilateral = profile(180.0);
+        for (phi, folded) in [(150.0, 150.0), (210.0, 150.0), (-30.0, 30.0), (400.0, 40.0)] {
+            assert!((bilateral.fold_horizontal(phi) - folded).abs() < 1e-4, "bilateral {}", phi);
+        }
+        let full = profile(360.0);
+        assert!((full.fold_horizontal(270.0) - 270.0).abs() < 1e-4);
+        assert!((full.fold_horizontal(-90.0) - 270.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(IesProfile::parse("IESNA:LM-63-2002\n1 1000 1 1 1\n").is_err(), "no TILT line");
+        assert!(IesProfile::parse("TILT=NONE\n1 1000 1 2 1 2 1 0 0 0 1 1 60\n0 90\n0\n1 1\n").is_err(), "type B");
+        assert!(IesProfile::parse("TILT=NONE\n1 1000 1 2 1 1 1 0 0 0 1 1 60\n0 90\n0\n1\n").is_err(), "truncated");
+        assert!(IesProfile::parse("TILT=NONE\n1 1000 1 1.5 1 1 1 0 0 0 1 1 60\n").is_err(), "fractional count");
+        assert!(IesProfile::parse("TILT=NONE\n1 1000 1 1 1 1 1 0 0 0 1 1 60\n0\n0\nlots\n").is_err(), "bad number");
+    }
+}
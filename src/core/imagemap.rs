@@ -2,31 +2,47 @@ use crate::core::texture::{Texture, TextureMapping2D}; // Import Trait
 use crate::core::mipmap::MIPMap;
 use crate::core::spectrum::SampledSpectrum;
 use crate::core::interaction::SurfaceInteraction;
+use std::fs::File;
+use std::io::{self, BufReader};
 use std::sync::Arc;
 
+use image::codecs::hdr::HdrDecoder;
+
+/// Reads an image as linear RGB texels (row-major, row 0 at the top).
+///
+/// Radiance `.hdr` files are decoded directly because `image::open()`
+/// converts them to 8-bit; everything else goes through `image::open()`.
+pub fn read_rgb_image(filename: &str) -> io::Result<(usize, usize, Vec<[f32; 3]>)> {
+    let invalid = |e: image::ImageError| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", filename, e));
+
+    let (width, height, texels) = if filename.to_lowercase().ends_with(".hdr") {
+        let decoder = HdrDecoder::new(BufReader::new(File::open(filename)?)).map_err(invalid)?;
+        let meta = decoder.metadata();
+        let pixels = decoder.read_image_hdr().map_err(invalid)?;
+        (meta.width, meta.height, pixels.iter().map(|p| p.0).collect())
+    } else {
+        let img = image::open(filename).map_err(invalid)?.to_rgb32f();
+        (img.width(), img.height(), img.pixels().map(|p| p.0).collect())
+    };
+    Ok((width as usize, height as usize, texels))
+}
+
 pub struct ImageTexture {
     mapping: Box<dyn TextureMapping2D>,
     mipmap: Arc<MIPMap>,
 }
 
 impl ImageTexture {
-    pub fn new(mapping: Box<dyn TextureMapping2D>, filename: &str) -> Self {
-        let img = image::open(filename).expect("Failed to load texture").to_rgb32f();
-        let (width, height) = img.dimensions();
-
-        let mut texels = Vec::with_capacity((width * height) as usize);
-        for y in 0..height {
-            for x in 0..width {
-                let pixel = img.get_pixel(x, y);
-                let rgb = [pixel[0], pixel[1], pixel[2]];
-                texels.push(SampledSpectrum::from_rgb(rgb, &crate::core::spectrum::SampledWavelengths::sample_uniform(0.5)));
-            }
-        }
+    pub fn new(mapping: Box<dyn TextureMapping2D>, filename: &str) -> io::Result<Self> {
+        let (width, height, rgb) = read_rgb_image(filename)?;
+
+        let lambda = crate::core::spectrum::SampledWavelengths::sample_uniform(0.5);
+        let texels = rgb.iter().map(|&c| SampledSpectrum::from_rgb(c, &lambda)).collect();
 
         let resolution = crate::core::geometry::Point2 { x: width as f32, y: height as f32 };
         let mipmap = Arc::new(MIPMap::new(resolution, texels));
 
-        ImageTexture { mapping, mipmap }
+        Ok(ImageTexture { mapping, mipmap })
     }
 }
 
@@ -1,10 +1,16 @@
-use crate::core::geometry::{Point2, Point2i, Vector3};
+use crate::core::geometry::{Point2, Point2i, Point3, Vector3};
+use std::sync::Arc;
+
+use crate::core::bsdf::BSDF;
+use crate::core::interaction::{Interaction, SurfaceInteraction};
 use crate::core::camera::PerspectiveCamera;
 use crate::core::primitive::Primitive;
-use crate::core::sampler::StratifiedSampler;
+use crate::core::ray::Ray;
+use crate::core::sampler::{Sampler, StratifiedSampler};
 use crate::core::film::Film;
 use crate::core::spectrum::{SampledSpectrum, SampledWavelengths};
-use crate::core::light::Light;
+use crate::core::light::{light_linked, Light, LightLinks};
+use crate::core::lightsampler::{BVHLightSampler, LightSampler};
 
 // Power heuristic for MIS weighting (p^2 / (p^2 + q^2))
 fn power_heuristic(nf: i32, f_pdf: f32, ng: i32, g_pdf: f32) -> f32 {
@@ -15,25 +21,67 @@ fn power_heuristic(nf: i32, f_pdf: f32, ng: i32, g_pdf: f32) -> f32 {
     if ff + gg == 0.0 { 0.0 } else { ff / (ff + gg) }
 }
 
-/// Full path tracer with NEE + MIS + robust delta light handling.
-/// Assumptions:
-/// - Light::sample_li returns a direction wi, radiance Li and pdf in *solid angle*.
-///   If some lights return area pdfs, uncomment the area→solid-angle conversion below.
-/// - bsdf.sample_f returns (f, wi, pdf, is_delta), pdf in solid angle.
-/// - Emission (Le) is added only for camera ray or after specular bounce.
-pub fn render(
+/// Shadow-ray visibility test between a surface point and a point on a light.
+/// `wi` must be the normalized direction towards `p_light`.
+pub fn unoccluded(
     scene: &dyn Primitive,
-    lights: &Vec<Box<dyn Light>>,
+    interaction: &Interaction,
+    wi: Vector3,
+    p_light: Point3,
+) -> bool {
+    let shadow_ray = interaction.spawn_ray(wi);
+    // Measured from the offset origin, so the light's own surface is not an occluder
+    let light_dist = (p_light - shadow_ray.o).length();
+    match scene.intersect(&shadow_ray) {
+        Some((t_occ, _, _)) => t_occ >= light_dist - 1e-3,
+        None => true,
+    }
+}
+
+/// Converts a spectral sample to linear sRGB (stored in a Vector3 like the Film).
+pub fn to_rgb(s: SampledSpectrum, wavelengths: &SampledWavelengths) -> Vector3 {
+    let rgb = SampledSpectrum::xyz_to_rgb(s.to_xyz(wavelengths));
+    Vector3 { x: rgb[0], y: rgb[1], z: rgb[2] }
+}
+
+/// Shared camera/film/sampler loop for integrators that estimate one camera
+/// ray at a time. `estimate` receives the camera ray, the sampled wavelengths
+/// and the sampler, and returns the sample's RGB value.
+pub fn render_pixels<F>(
     camera: &PerspectiveCamera,
     film: &mut Film,
-) {
+    description: &str,
+    mut estimate: F,
+) where
+    F: FnMut(Ray, &SampledWavelengths, &mut dyn Sampler) -> Vector3,
+{
+    render_pixels_with_groups(camera, film, description, |ray, wavelengths, sampler, _| {
+        estimate(ray, wavelengths, sampler)
+    });
+}
+
+/// `render_pixels` for integrators that also fill the film's light groups:
+/// `estimate` writes each group's share of the sample (RGB) into the slice
+/// it is given, which has one entry per group and is zeroed for every sample.
+pub fn render_pixels_with_groups<F>(
+    camera: &PerspectiveCamera,
+    film: &mut Film,
+    description: &str,
+    mut estimate: F,
+) where
+    F: FnMut(Ray, &SampledWavelengths, &mut dyn Sampler, &mut [Vector3]) -> Vector3,
+{
+    let zero = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
+    let n_groups = film.light_groups().len();
+    let mut group_sample = vec![zero; n_groups];
+    let mut group_sum = vec![zero; n_groups];
+
     let mut sampler = StratifiedSampler::new(8, 8);
     let spp = sampler.samples_per_pixel() as f32;
-    let max_depth = 5;
 
     println!(
-        "Rendering {}x{} image (Full Path Tracing with MIS)...",
-        film.resolution.x, film.resolution.y
+        "Rendering {}x{} image ({})...",
+        film.resolution.x, film.resolution.y, description
     );
 
     for y in 0..film.resolution.y {
@@ -42,15 +90,17 @@ pub fn render(
             sampler.start_pixel(pixel);
 
             let mut pixel_color = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
+            group_sum.fill(zero);
 
-            for _s in 0..sampler.samples_per_pixel() {
+            for s in 0..sampler.samples_per_pixel() {
+                sampler.start_sample(s);
                 let offset = sampler.get_2d();
                 let raster_sample = Point2 {
                     x: x as f32 + offset.x,
                     y: y as f32 + offset.y,
                 };
 
-                let mut ray = camera.generate_ray(
+                let ray = camera.generate_ray(
                     raster_sample,
                     Point2 {
                         x: film.resolution.x as f32,
@@ -60,207 +110,458 @@ pub fn render(
                 );
 
                 let wavelengths = SampledWavelengths::sample_uniform(sampler.get_2d().x);
-                let mut l = SampledSpectrum::new(0.0);
-                let mut beta = SampledSpectrum::new(1.0);
-                let mut specular_bounce = false;
+                group_sample.fill(zero);
+                pixel_color = pixel_color + estimate(ray, &wavelengths, &mut sampler, &mut group_sample);
+                for (sum, &c) in group_sum.iter_mut().zip(&group_sample) {
+                    *sum = *sum + c;
+                }
+            }
 
-                for bounces in 0..max_depth {
-                    let hit = scene.intersect(&ray);
+            film.set_pixel(pixel, pixel_color * (1.0 / spp));
+            for (g, &sum) in group_sum.iter().enumerate() {
+                film.set_light_group_pixel(g, pixel, sum * (1.0 / spp));
+            }
+        }
 
-                    // Escaped scene -> environment contribution would go here if you have one
-                    let Some((_, interaction, material_opt)) = hit else {
-                        // e.g. l += beta * env_le(ray.d, wavelengths);
-                        break;
-                    };
-
-                    // Surface emission (Le)
-                    if let Some(mat) = &material_opt {
-                        let le = mat.emitted(&interaction);
-                        if le.values.iter().any(|&v| v > 0.0) {
-                            // Only for primary rays or specular paths (avoid double counting with NEE)
-                            if bounces == 0 || specular_bounce {
-                                l = l + beta * le;
-                            }
-                        }
-                    }
+        if y % 10 == 0 {
+            print!(".");
+            use std::io::Write;
+            std::io::stdout().flush().unwrap();
+        }
+    }
 
-                    // No material: terminate
-                    let Some(mat) = material_opt else { break; };
-
-                    // Build BSDF
-                    let Some(bsdf) = mat.compute_scattering(&interaction) else {
-                        break; // absorbed / invalid
-                    };
-
-                    // === Next Event Estimation: sample one light with MIS (robust) ===
-                    if !lights.is_empty() {
-                        let n_lights = lights.len();
-                        let light_choice_f = sampler.get_2d().x * n_lights as f32;
-                        let light_idx = light_choice_f.floor() as usize;
-                        let light_idx = light_idx.min(n_lights - 1);
-                        let light = &lights[light_idx];
-                        let pdf_light_choice = 1.0 / (n_lights as f32);
-
-                        let u_light = sampler.get_2d();
-                        if let Some(ls) = light.sample_li(&interaction, u_light) {
-                            // If Light::sample_li returns area pdf, convert here.
-                            // For now we assume ls.pdf is already in solid angle:
-                            let mut ls_pdf_solid = ls.pdf;
-
-                            // Uncomment and adapt if some lights use area measure:
-                            // let light_dist = (ls.p_light - interaction.core.p).length();
-                            // let cos_at_light = ls.n_light.dot(-ls.wi).max(0.0);
-                            // if cos_at_light > 1e-7 {
-                            //     ls_pdf_solid = ls.pdf * (light_dist * light_dist) / cos_at_light;
-                            // } else {
-                            //     ls_pdf_solid = 0.0;
-                            // }
-
-                            let li_nonzero =
-                                !ls.l.values.iter().all(|&v| v == 0.0);
-
-                            // Delta lights: pdf may be zero but they must still contribute
-                            if light.is_delta() {
-                                if li_nonzero {
-                                    let shadow_ray = interaction.core.spawn_ray(ls.wi);
-                                    let light_dist =
-                                        (ls.p_light - interaction.core.p).length();
-                                    let occluded =
-                                        if let Some((t_occ, _, _)) =
-                                            scene.intersect(&shadow_ray)
-                                        {
-                                            t_occ < light_dist - 1e-3
-                                        } else {
-                                            false
-                                        };
-
-                                    if !occluded {
-                                        let wo = -ray.d;
-                                        let f = bsdf.f(wo, ls.wi);
-                                        if !f.values.iter().all(|&v| v == 0.0) {
-                                            let n_vec =
-                                                Vector3::from(interaction.shading.n);
-                                            let cos_theta =
-                                                n_vec.dot(ls.wi).max(0.0);
-                                            if cos_theta > 0.0 {
-                                                // No MIS competition for delta lights
-                                                l = l + beta * f * ls.l * cos_theta;
-                                            }
-                                        }
-                                    }
-                                }
-                            } else {
-                                // Non-delta lights: standard MIS
-                                if ls_pdf_solid > 0.0 && li_nonzero {
-                                    let shadow_ray =
-                                        interaction.core.spawn_ray(ls.wi);
-                                    let light_dist =
-                                        (ls.p_light - interaction.core.p).length();
-                                    let occluded =
-                                        if let Some((t_occ, _, _)) =
-                                            scene.intersect(&shadow_ray)
-                                        {
-                                            t_occ < light_dist - 1e-3
-                                        } else {
-                                            false
-                                        };
-
-                                    if !occluded {
-                                        let wo = -ray.d;
-                                        let f = bsdf.f(wo, ls.wi);
-                                        if !f.values.iter().all(|&v| v == 0.0) {
-                                            let n_vec =
-                                                Vector3::from(interaction.shading.n);
-                                            let cos_theta =
-                                                n_vec.dot(ls.wi).max(0.0);
-                                            if cos_theta > 0.0 {
-                                                let pdf_light =
-                                                    ls_pdf_solid * pdf_light_choice;
-                                                let pdf_bsdf = bsdf.pdf(wo, ls.wi);
-
-                                                let weight_light =
-                                                    power_heuristic(
-                                                        1,
-                                                        pdf_light,
-                                                        1,
-                                                        pdf_bsdf,
-                                                    );
-
-                                                if pdf_light > 0.0 {
-                                                    l = l
-                                                        + beta
-                                                            * f
-                                                            * ls.l
-                                                            * (cos_theta / pdf_light)
-                                                            * weight_light;
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
+    println!("\nDone!");
+}
+
+/// Full path tracer with NEE + MIS + robust delta light handling.
+/// Assumptions:
+/// - Light::sample_li returns a direction wi, radiance Li and pdf in *solid angle*.
+///   If some lights return area pdfs, uncomment the area→solid-angle conversion below.
+/// - bsdf.sample_f returns (f, wi, pdf, is_delta), pdf in solid angle.
+/// - Emission (Le) is added only for camera ray or after specular bounce.
+pub fn render(
+    scene: &dyn Primitive,
+    lights: &Vec<Box<dyn Light>>,
+    camera: &PerspectiveCamera,
+    film: &mut Film,
+) {
+    let max_depth = 5;
+    let light_sampler = BVHLightSampler::new(lights);
+    let group_map = film.light_group_map(lights.len());
+    let n_groups = film.light_groups().len();
+    render_pixels_with_groups(camera, film, "Full Path Tracing with MIS", |ray, wavelengths, sampler, group_rgb| {
+        let mut groups = LightGroupRadiance::new(&group_map, n_groups);
+        let l = li_with_groups(ray, wavelengths, scene, lights, &light_sampler, sampler, max_depth, &mut groups);
+        for (rgb, &lg) in group_rgb.iter_mut().zip(&groups.l) {
+            *rgb = to_rgb(lg, wavelengths);
+        }
+        to_rgb(l, wavelengths)
+    });
+}
+
+/// Radiance of one camera path split by film light group: contributions
+/// from light `i` are also added to `l[map[i]]`.
+pub struct LightGroupRadiance<'a> {
+    map: &'a [Option<usize>],
+    pub l: Vec<SampledSpectrum>,
+}
+
+impl<'a> LightGroupRadiance<'a> {
+    pub fn new(map: &'a [Option<usize>], n_groups: usize) -> Self {
+        LightGroupRadiance { map, l: vec![SampledSpectrum::new(0.0); n_groups] }
+    }
+
+    /// Records `c` as coming from light `light`
+    pub fn add(&mut self, light: usize, c: SampledSpectrum) {
+        if let Some(&Some(g)) = self.map.get(light) {
+            self.l[g] = self.l[g] + c;
+        }
+    }
+}
+
+/// Radiance arriving along a camera ray (the path tracer's estimator).
+///
+/// All random numbers come from `sampler`, so any `Sampler` can drive it:
+/// the stratified per-pixel sampler in `render()` or the primary-sample-space
+/// sampler used by Metropolis light transport.
+pub fn li(
+    ray: Ray,
+    wavelengths: &SampledWavelengths,
+    scene: &dyn Primitive,
+    lights: &[Box<dyn Light>],
+    light_sampler: &dyn LightSampler,
+    sampler: &mut dyn Sampler,
+    max_depth: usize,
+) -> SampledSpectrum {
+    let mut groups = LightGroupRadiance::new(&[], 0);
+    li_with_groups(ray, wavelengths, scene, lights, light_sampler, sampler, max_depth, &mut groups)
+}
+
+/// `li` that also records each light's contribution in `groups`.
+///
+/// Surface light links are honoured for NEE and for MIS-weighted emitter
+/// hits (using the links of the surface the path last scattered from).
+/// Light refracted into a material with a BSSRDF continues from an exit
+/// point found by probe rays (see `TabulatedBSSRDF::sample_exit`).
+#[allow(clippy::too_many_arguments)]
+pub fn li_with_groups(
+    mut ray: Ray,
+    wavelengths: &SampledWavelengths,
+    scene: &dyn Primitive,
+    lights: &[Box<dyn Light>],
+    light_sampler: &dyn LightSampler,
+    sampler: &mut dyn Sampler,
+    max_depth: usize,
+    groups: &mut LightGroupRadiance,
+) -> SampledSpectrum {
+    let mut l = SampledSpectrum::new(0.0);
+    let mut beta = SampledSpectrum::new(1.0);
+    let mut specular_bounce = false;
+    // Previous scattering vertex and its BSDF pdf, for MIS on emitter hits
+    let mut prev_ctx: Option<Interaction> = None;
+    let mut prev_bsdf_pdf = 0.0;
+    let mut prev_links: Option<Arc<LightLinks>> = None;
+
+    for bounces in 0..max_depth {
+        let hit = scene.intersect(&ray);
+
+        // Escaped scene -> environment contribution from infinite lights
+        let Some((_, interaction, material_opt)) = hit else {
+            for (light_index, light) in lights.iter().enumerate().filter(|(_, l)| l.is_infinite()) {
+                if prev_ctx.is_some() && !light_linked(prev_links.as_deref(), light_index) {
+                    continue;
+                }
+                let le = light.le(&ray, wavelengths);
+                if le.is_black() {
+                    continue;
+                }
+                let c = match &prev_ctx {
+                    Some(ctx) if !specular_bounce => {
+                        let pdf_light = light_sampler.pmf(ctx, light_index) * light.pdf_li(ctx, ray.d);
+                        let weight_bsdf = power_heuristic(1, prev_bsdf_pdf, 1, pdf_light);
+                        beta * le * weight_bsdf
                     }
+                    _ => beta * le,
+                };
+                l = l + c;
+                groups.add(light_index, c);
+            }
+            break;
+        };
+
+        // Surface emission (Le)
+        if let Some(light_index) = interaction.area_light {
+            // Emitter linked to a scene light: weight against light sampling,
+            // unless the surface the path came from is not lit by it
+            let le = if prev_ctx.is_none() || light_linked(prev_links.as_deref(), light_index) {
+                lights[light_index].l(&interaction, -ray.d, wavelengths)
+            } else {
+                SampledSpectrum::new(0.0)
+            };
+            if le.values.iter().any(|&v| v > 0.0) {
+                let c = match &prev_ctx {
+                    Some(ctx) if !specular_bounce => {
+                        let pdf_light = light_sampler.pmf(ctx, light_index)
+                            * lights[light_index].pdf_li(ctx, ray.d);
+                        let weight_bsdf = power_heuristic(1, prev_bsdf_pdf, 1, pdf_light);
+                        beta * le * weight_bsdf
+                    }
+                    _ => beta * le,
+                };
+                l = l + c;
+                groups.add(light_index, c);
+            }
+        } else if let Some(mat) = &material_opt {
+            let le = mat.emitted(&interaction);
+            if le.values.iter().any(|&v| v > 0.0) {
+                // Only for primary rays or specular paths (avoid double counting with NEE)
+                if bounces == 0 || specular_bounce {
+                    l = l + beta * le;
+                }
+            }
+        }
 
-                    // === BSDF sampling for indirect lighting ===
-                    let u_bsdf = sampler.get_2d();
-                    let wo = -ray.d;
+        // No material: terminate
+        let Some(mat) = material_opt else { break; };
 
-                    // bsdf.sample_f: (f, wi, pdf, is_delta)
-                    if let Some((f, wi, pdf, is_delta)) = bsdf.sample_f(wo, u_bsdf) {
-                        if pdf == 0.0
-                            || f.values.iter().all(|&v| v == 0.0)
-                        {
-                            break;
-                        }
+        // Build BSDF
+        let Some(bsdf) = mat.compute_scattering(&interaction, wavelengths) else {
+            break; // absorbed / invalid
+        };
 
-                        let n_vec = Vector3::from(interaction.shading.n);
-                        let cos_theta = wi.dot(n_vec).max(0.0);
-                        if cos_theta == 0.0 {
-                            break;
-                        }
+        // === Next Event Estimation: sample one light with MIS (robust) ===
+        l = l + sample_direct(&interaction, &bsdf, -ray.d, beta, wavelengths, scene, lights, light_sampler, sampler, groups);
 
-                        // Throughput update
-                        beta = beta * f * (cos_theta / pdf);
-
-                        // Russian roulette
-                        if bounces > 3 {
-                            let max_component =
-                                beta.values.iter().fold(0.0f32, |a, &b| a.max(b));
-                            let q = (1.0 - max_component)
-                                .max(0.05)
-                                .min(0.95);
-                            if sampler.get_2d().x < q {
-                                break;
-                            }
-                            beta = beta * (1.0 / (1.0 - q));
-                        }
+        // === BSDF sampling for indirect lighting ===
+        let u_bsdf = sampler.get_2d();
+        let wo = -ray.d;
+
+        // bsdf.sample_f: (f, wi, pdf, is_delta)
+        if let Some((f, wi, pdf, is_delta)) = bsdf.sample_f(wo, u_bsdf) {
+            if pdf == 0.0
+                || f.values.iter().all(|&v| v == 0.0)
+            {
+                break;
+            }
+
+            let n_vec = Vector3::from(interaction.shading.n);
+            let cos_theta = wi.dot(n_vec).abs();
+            if cos_theta == 0.0 {
+                break;
+            }
 
-                        // Next ray
-                        ray = interaction.core.spawn_ray(wi);
-                        specular_bounce = is_delta;
-                    } else {
+            // Throughput update
+            beta = beta * f * (cos_theta / pdf);
+            let mut bsdf_pdf = if bsdf.pdf_is_proportional() { bsdf.pdf(wo, wi) } else { pdf };
+            let (mut wi, mut is_delta, mut interaction) = (wi, is_delta, interaction);
+
+            // === Subsurface scattering: transmitted light exits elsewhere ===
+            // The path continues from an exit point found by a probe ray,
+            // with NEE and sampling of the exit's Sw lobe
+            if wo.dot(n_vec) * wi.dot(n_vec) < 0.0 {
+                if let Some(bssrdf) = mat.bssrdf(&interaction, wavelengths) {
+                    let u = sampler.get_2d();
+                    let Some(segment) = bssrdf.sample(u.x, sampler.get_2d()) else { break; };
+                    let Some((exit, weight, sw)) = bssrdf.sample_exit(scene, &mat, &segment, u.y) else { break; };
+                    beta = beta * weight;
+                    let wo_exit = exit.core.wo;
+                    l = l + sample_direct(&exit, &sw, wo_exit, beta, wavelengths, scene, lights, light_sampler, sampler, groups);
+
+                    let Some((f, wi_exit, pdf, _)) = sw.sample_f(wo_exit, sampler.get_2d()) else { break; };
+                    if pdf == 0.0 || f.is_black() {
                         break;
                     }
+                    beta = beta * f * (wi_exit.dot(Vector3::from(exit.shading.n)).abs() / pdf);
+                    (wi, is_delta, bsdf_pdf, interaction) = (wi_exit, false, pdf, exit);
                 }
+            }
 
-                let rgb = SampledSpectrum::xyz_to_rgb(l.to_xyz(&wavelengths));
-                pixel_color = pixel_color + Vector3 {
-                    x: rgb[0],
-                    y: rgb[1],
-                    z: rgb[2],
-                };
+            // Russian roulette
+            if bounces > 3 {
+                let max_component =
+                    beta.values.iter().fold(0.0f32, |a, &b| a.max(b));
+                let q = (1.0 - max_component).clamp(0.05, 0.95);
+                if sampler.get_2d().x < q {
+                    break;
+                }
+                beta = beta * (1.0 / (1.0 - q));
             }
 
-            film.set_pixel(pixel, pixel_color * (1.0 / spp));
+            // Next ray
+            ray = interaction.core.spawn_ray(wi);
+            specular_bounce = is_delta;
+            prev_bsdf_pdf = bsdf_pdf;
+            prev_ctx = Some(interaction.core.clone());
+            prev_links = interaction.light_links.clone();
+        } else {
+            break;
         }
+    }
 
-        if y % 10 == 0 {
-            print!(".");
-            use std::io::Write;
-            std::io::stdout().flush().unwrap();
+    l
+}
+
+/// Next event estimation at a surface vertex: one light sample, MIS-weighted
+/// against `bsdf` (delta lights unweighted). Returns the contribution,
+/// already multiplied by the path throughput `beta`.
+#[allow(clippy::too_many_arguments)]
+fn sample_direct(
+    interaction: &SurfaceInteraction,
+    bsdf: &BSDF,
+    wo: Vector3,
+    beta: SampledSpectrum,
+    wavelengths: &SampledWavelengths,
+    scene: &dyn Primitive,
+    lights: &[Box<dyn Light>],
+    light_sampler: &dyn LightSampler,
+    sampler: &mut dyn Sampler,
+    groups: &mut LightGroupRadiance,
+) -> SampledSpectrum {
+    let mut l = SampledSpectrum::new(0.0);
+    let u_choice = sampler.get_2d().x;
+    let linked_sample = light_sampler
+        .sample(&interaction.core, u_choice)
+        .filter(|s| light_linked(interaction.light_links.as_deref(), s.light));
+    if let Some(sampled) = linked_sample {
+        let light = &lights[sampled.light];
+        let pdf_light_choice = sampled.p;
+
+        let u_light = sampler.get_2d();
+        if let Some(ls) = light.sample_li(&interaction.core, u_light, wavelengths) {
+            // If Light::sample_li returns area pdf, convert here.
+            // For now we assume ls.pdf is already in solid angle:
+            let ls_pdf_solid = ls.pdf;
+
+            // Uncomment and adapt if some lights use area measure:
+            // let light_dist = (ls.p_light - interaction.core.p).length();
+            // let cos_at_light = ls.n_light.dot(-ls.wi).max(0.0);
+            // if cos_at_light > 1e-7 {
+            //     ls_pdf_solid = ls.pdf * (light_dist * light_dist) / cos_at_light;
+            // } else {
+            //     ls_pdf_solid = 0.0;
+            // }
+
+            let li_nonzero =
+                !ls.l.values.iter().all(|&v| v == 0.0);
+
+            // Delta lights: pdf may be zero but they must still contribute
+            if light.is_delta() {
+                if li_nonzero {
+                    let shadow_ray = interaction.core.spawn_ray(ls.wi);
+                    let light_dist =
+                        (ls.p_light - shadow_ray.o).length();
+                    let occluded =
+                        if let Some((t_occ, _, _)) =
+                            scene.intersect(&shadow_ray)
+                        {
+                            t_occ < light_dist - 1e-3
+                        } else {
+                            false
+                        };
+
+                    if !occluded {
+                        let f = bsdf.f(wo, ls.wi);
+                        if !f.values.iter().all(|&v| v == 0.0) {
+                            let n_vec =
+                                Vector3::from(interaction.shading.n);
+                            let cos_theta =
+                                n_vec.dot(ls.wi).abs();
+                            if cos_theta > 0.0 {
+                                // No MIS competition for delta lights
+                                let c = beta * f * ls.l * (cos_theta / pdf_light_choice);
+                                l = l + c;
+                                groups.add(sampled.light, c);
+                            }
+                        }
+                    }
+                }
+            } else {
+                // Non-delta lights: standard MIS
+                if ls_pdf_solid > 0.0 && li_nonzero {
+                    let shadow_ray =
+                        interaction.core.spawn_ray(ls.wi);
+                    let light_dist =
+                        (ls.p_light - shadow_ray.o).length();
+                    let occluded =
+                        if let Some((t_occ, _, _)) =
+                            scene.intersect(&shadow_ray)
+                        {
+                            t_occ < light_dist - 1e-3
+                        } else {
+                            false
+                        };
+
+                    if !occluded {
+                        let f = bsdf.f(wo, ls.wi);
+                        if !f.values.iter().all(|&v| v == 0.0) {
+                            let n_vec =
+                                Vector3::from(interaction.shading.n);
+                            let cos_theta =
+                                n_vec.dot(ls.wi).abs();
+                            if cos_theta > 0.0 {
+                                let pdf_light =
+                                    ls_pdf_solid * pdf_light_choice;
+                                let pdf_bsdf = bsdf.pdf(wo, ls.wi);
+
+                                let weight_light =
+                                    power_heuristic(
+                                        1,
+                                        pdf_light,
+                                        1,
+                                        pdf_bsdf,
+                                    );
+
+                                if pdf_light > 0.0 {
+                                    let c = beta
+                                        * f
+                                        * ls.l
+                                        * (cos_theta / pdf_light)
+                                        * weight_light;
+                                    l = l + c;
+                                    groups.add(sampled.light, c);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
         }
     }
+    l
+}
 
-    println!("\nDone!");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scene::SceneBuilder;
+    use crate::integrators::tests::{add_backlight, add_wall, film_mean, wall_camera, RESOLUTION};
+    use crate::integrators::volpath::VolPathIntegrator;
+
+    fn key() -> Point3 {
+        Point3::new(-2.0, 0.0, -4.0)
+    }
+
+    fn fill() -> Point3 {
+        Point3::new(2.0, 0.0, -4.0)
+    }
+
+    // Mean of the path-traced and volpath images of the scene `build` makes
+    fn render_means(build: impl Fn(&mut SceneBuilder)) -> [f32; 2] {
+        let mut builder = SceneBuilder::new();
+        build(&mut builder);
+        let (scene, lights) = builder.build();
+        let camera = wall_camera();
+        let mut path = Film::new(RESOLUTION);
+        render(&scene, &lights, &camera, &mut path);
+        let mut volpath = Film::new(RESOLUTION);
+        VolPathIntegrator::new(5, None).render(&scene, &lights, &camera, &mut volpath);
+        [film_mean(&path), film_mean(&volpath)]
+    }
+
+    #[test]
+    fn linked_surfaces_only_see_their_lights() {
+        let linked = render_means(|builder| {
+            let key = add_backlight(builder, key(), 4.0);
+            add_backlight(builder, fill(), 16.0);
+            builder.set_light_links(LightLinks::Include(key));
+            add_wall(builder);
+        });
+        let excluded = render_means(|builder| {
+            add_backlight(builder, key(), 4.0);
+            let fill = add_backlight(builder, fill(), 16.0);
+            builder.set_light_links(LightLinks::Exclude(fill));
+            add_wall(builder);
+        });
+        let key_only = render_means(|builder| {
+            add_backlight(builder, key(), 4.0);
+            add_wall(builder);
+        });
+        for (i, name) in ["path", "volpath"].into_iter().enumerate() {
+            for means in [linked, excluded] {
+                assert!((means[i] - key_only[i]).abs() < 0.03 * key_only[i], "{}: {} vs {}", name, means[i], key_only[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn light_groups_add_up_to_the_image() {
+        let mut builder = SceneBuilder::new();
+        let key = add_backlight(&mut builder, key(), 4.0);
+        let fill = add_backlight(&mut builder, fill(), 16.0);
+        add_wall(&mut builder);
+        let (scene, lights) = builder.build();
+
+        let mut film = Film::new(RESOLUTION);
+        film.add_light_group("key", &key);
+        film.add_light_group("fill", &fill);
+        render(&scene, &lights, &wall_camera(), &mut film);
+        for y in 0..RESOLUTION.y {
+            for x in 0..RESOLUTION.x {
+                let p = Point2i { x, y };
+                let (total, sum) = (film.pixel(p), film.light_group_pixel(0, p) + film.light_group_pixel(1, p));
+                assert!((total - sum).length() <= 1e-3 * total.length(), "{:?}: {:?} vs {:?}", p, total, sum);
+                assert!(film.light_group_pixel(1, p).length() > film.light_group_pixel(0, p).length() * 0.1);
+            }
+        }
+    }
 }
@@ -1,5 +1,9 @@
 use crate::core::geometry::{Point3, Vector3, Normal3, Point2};
 use crate::core::ray::Ray; // Add this import at top
+use std::sync::Arc;
+
+use crate::core::light::LightLinks;
+use crate::core::medium::MediumInterface;
 
 /// Base struct for any interaction (Surface, Volume, Light)
 #[derive(Debug, Clone)]
@@ -20,11 +24,21 @@ pub struct SurfaceInteraction {
     // Differential Geometry (for texture mapping)
     pub dpdu: Vector3,
     pub dpdv: Vector3,
-    pub dndu: Normal3,
-    pub dndv: Normal3,
 
     // Shading Geometry (Bump mapping, normal mapping results)
     pub shading: ShadingData,
+
+    // Media on either side of the surface (None = surface does not change media)
+    pub medium_interface: Option<MediumInterface>,
+
+    // Barycentric coordinates of the hit (triangles only)
+    pub barycentrics: Option<[f32; 3]>,
+
+    // Scene light index if the surface is an area light
+    pub area_light: Option<usize>,
+
+    // Lights allowed to illuminate this surface (None = all)
+    pub light_links: Option<Arc<LightLinks>>,
 }
 
 #[derive(Debug, Clone)]
@@ -32,8 +46,6 @@ pub struct ShadingData {
     pub n: Normal3,
     pub dpdu: Vector3,
     pub dpdv: Vector3,
-    pub dndu: Normal3,
-    pub dndv: Normal3,
 }
 
 impl SurfaceInteraction {
@@ -43,15 +55,17 @@ impl SurfaceInteraction {
         n: Normal3, time: f32
     ) -> Self {
         let core = Interaction { p, time, p_error, wo, n, uv };
-        let shading = ShadingData { n, dpdu: Vector3{x:0.0,y:0.0,z:0.0}, dpdv: Vector3{x:0.0,y:0.0,z:0.0}, dndu: n, dndv: n }; // Defaults
+        let shading = ShadingData { n, dpdu: Vector3{x:0.0,y:0.0,z:0.0}, dpdv: Vector3{x:0.0,y:0.0,z:0.0} }; // Defaults
         
         SurfaceInteraction {
             core,
             dpdu: Vector3{x:0.0,y:0.0,z:0.0}, 
             dpdv: Vector3{x:0.0,y:0.0,z:0.0},
-            dndu: Normal3{x:0.0,y:0.0,z:0.0}, 
-            dndv: Normal3{x:0.0,y:0.0,z:0.0},
             shading,
+            medium_interface: None,
+            barycentrics: None,
+            area_light: None,
+            light_links: None,
         }
     }
 }
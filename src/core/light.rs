@@ -1,9 +1,26 @@
+use std::io;
 use std::sync::Arc;
 
-use crate::core::geometry::{Point2, Point3, Vector3};
-use crate::core::interaction::SurfaceInteraction;
+use std::f32::consts::PI;
+
+use crate::core::bsdf::Frame;
+use crate::core::geometry::{Bounds3, DirectionCone, Normal3, Point2, Point3, Vector3};
+use crate::core::ies::IesProfile;
+use crate::core::imagemap::read_rgb_image;
+use crate::core::interaction::{Interaction, SurfaceInteraction};
+use crate::core::math::{
+    sample_cosine_hemisphere, sample_uniform_cone, sample_uniform_disk_polar, sample_uniform_sphere,
+    smooth_step, uniform_cone_pdf, uniform_sphere_pdf, Distribution2D,
+};
 use crate::core::primitive::Shape;
-use crate::core::spectrum::SampledSpectrum;
+use crate::core::ray::Ray;
+use crate::core::sky::{sun_direction, sun_solid_angle, PreethamSky, SunSpectrum, SUN_ANGULAR_RADIUS};
+use crate::core::spectrum::{
+    spectrum_to_luminous, spectrum_to_photometric, spectrum_to_radiometric, SampledSpectrum, SampledWavelengths,
+    Spectrum,
+};
+use crate::core::texture::Texture;
+use crate::core::transform::Transform;
 
 /// Result of sampling a light source (incident radiance at a point)
 pub struct LightLiSample {
@@ -20,38 +37,267 @@ pub struct LightLiSample {
     pub p_light: Point3,
 }
 
+/// Result of sampling a ray leaving a light source (photon emission)
+pub struct LightLeSample {
+    /// Emitted radiance carried along `ray`
+    pub l: SampledSpectrum,
+
+    /// Ray leaving the light (world space, normalized direction)
+    pub ray: Ray,
+
+    /// Surface normal at the emission point (None for delta lights)
+    pub n: Option<Normal3>,
+
+    /// PDF of the emission point (area measure)
+    pub pdf_pos: f32,
+
+    /// PDF of the emission direction (solid angle measure)
+    pub pdf_dir: f32,
+}
+
 /// Light interface used by the integrator for Next Event Estimation
 pub trait Light: Send + Sync {
-    /// Sample incident radiance from this light at a surface point
+    /// Sample incident radiance from this light at a surface or medium point
     fn sample_li(
         &self,
-        ctx: &SurfaceInteraction,
+        ctx: &Interaction,
         u: Point2,
+        lambda: &SampledWavelengths,
     ) -> Option<LightLiSample>;
 
     /// PDF of sampling direction `wi` from `ctx` (solid angle measure)
-    fn pdf_li(&self, ctx: &SurfaceInteraction, wi: Vector3) -> f32;
+    fn pdf_li(&self, ctx: &Interaction, wi: Vector3) -> f32;
 
     /// Is this a delta light? (point / directional)
     fn is_delta(&self) -> bool;
+
+    /// Sample a ray leaving the light (used by photon-tracing integrators)
+    fn sample_le(&self, u1: Point2, u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample>;
+
+    /// Total emitted power
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum;
+
+    /// Spatial and directional bounds of the emission (None for infinite lights)
+    fn bounds(&self) -> Option<LightBounds>;
+
+    /// Radiance emitted from a point `si` on an area light in direction `w`
+    /// (for rays that hit the light's primitive)
+    fn l(&self, _si: &SurfaceInteraction, _w: Vector3, _lambda: &SampledWavelengths) -> SampledSpectrum {
+        SampledSpectrum::new(0.0)
+    }
+
+    /// Radiance arriving along a ray that escapes the scene (infinite lights only)
+    fn le(&self, _ray: &Ray, _lambda: &SampledWavelengths) -> SampledSpectrum {
+        SampledSpectrum::new(0.0)
+    }
+
+    /// Does this light surround the scene and contribute to escaped rays?
+    fn is_infinite(&self) -> bool {
+        false
+    }
+
+    /// Called once the scene geometry is known (e.g. distant lights need its extent)
+    fn preprocess(&mut self, _scene_bounds: &Bounds3) {}
+}
+
+/// Light linking: which scene lights (by index) may illuminate a surface.
+///
+/// Links restrict NEE and MIS-weighted emitter hits from the surface; they
+/// do not hide emitters from camera rays.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum LightLinks {
+    #[default]
+    All,
+    /// Only these lights
+    Include(Vec<usize>),
+    /// Every light except these
+    Exclude(Vec<usize>),
+}
+
+impl LightLinks {
+    pub fn allows(&self, light: usize) -> bool {
+        match self {
+            LightLinks::All => true,
+            LightLinks::Include(lights) => lights.contains(&light),
+            LightLinks::Exclude(lights) => !lights.contains(&light),
+        }
+    }
+}
+
+/// Does a surface with `links` (None = unlinked) receive light from `light`?
+pub fn light_linked(links: Option<&LightLinks>, light: usize) -> bool {
+    links.is_none_or(|l| l.allows(light))
+}
+
+/// Conservative bounds on where and in which directions a light emits,
+/// used by the light BVH to estimate a light's importance at a point.
+///
+/// Emission leaves points in `bounds` in directions within `acos(cos_theta_o)`
+/// of `w` (the normal cone), spreading up to `acos(cos_theta_e)` further.
+#[derive(Debug, Clone, Copy)]
+pub struct LightBounds {
+    pub bounds: Bounds3,
+    pub w: Vector3,
+    pub phi: f32,
+    pub cos_theta_o: f32,
+    pub cos_theta_e: f32,
+    pub two_sided: bool,
 }
 
-/// Diffuse area light backed by a geometric shape
+impl LightBounds {
+    pub fn union(a: &LightBounds, b: &LightBounds) -> LightBounds {
+        if a.phi == 0.0 {
+            return *b;
+        }
+        if b.phi == 0.0 {
+            return *a;
+        }
+        let cone = DirectionCone::union(
+            DirectionCone::new(a.w, a.cos_theta_o),
+            DirectionCone::new(b.w, b.cos_theta_o),
+        );
+        LightBounds {
+            bounds: a.bounds.union(b.bounds),
+            w: cone.w,
+            phi: a.phi + b.phi,
+            cos_theta_o: cone.cos_theta,
+            cos_theta_e: a.cos_theta_e.min(b.cos_theta_e),
+            two_sided: a.two_sided || b.two_sided,
+        }
+    }
+
+    /// Estimated contribution of the bounded lights at point `p` with normal
+    /// `n` (zero normal for points in media). Conservative: never zero if
+    /// some light in the bounds can illuminate `p`.
+    pub fn importance(&self, p: Point3, n: Normal3) -> f32 {
+        // cos(a - b) and sin(a - b), clamped to zero angle when b covers a
+        let cos_sub_clamped = |sin_a: f32, cos_a: f32, sin_b: f32, cos_b: f32| {
+            if cos_a > cos_b { 1.0 } else { cos_a * cos_b + sin_a * sin_b }
+        };
+        let sin_sub_clamped = |sin_a: f32, cos_a: f32, sin_b: f32, cos_b: f32| {
+            if cos_a > cos_b { 0.0 } else { sin_a * cos_b - cos_a * sin_b }
+        };
+        let safe_sqrt = |x: f32| x.max(0.0).sqrt();
+
+        // Clamped squared distance to the bounds' center
+        let pc = self.bounds.centroid();
+        let d2 = (p - pc).length_squared().max(self.bounds.diagonal().length() / 2.0);
+
+        // Angle between the cone axis and the direction to p
+        let to_p = p - pc;
+        let wi = if to_p.length_squared() > 0.0 { to_p.normalize() } else { self.w };
+        let mut cos_theta_w = self.w.dot(wi);
+        if self.two_sided {
+            cos_theta_w = cos_theta_w.abs();
+        }
+        let sin_theta_w = safe_sqrt(1.0 - cos_theta_w * cos_theta_w);
+
+        // Angle subtended by the bounds as seen from p
+        let cos_theta_b = DirectionCone::bound_subtended_directions(&self.bounds, p).cos_theta;
+        let sin_theta_b = safe_sqrt(1.0 - cos_theta_b * cos_theta_b);
+
+        // Minimum angle between the emission cone and the direction to p
+        let sin_theta_o = safe_sqrt(1.0 - self.cos_theta_o * self.cos_theta_o);
+        let cos_theta_x = cos_sub_clamped(sin_theta_w, cos_theta_w, sin_theta_o, self.cos_theta_o);
+        let sin_theta_x = sin_sub_clamped(sin_theta_w, cos_theta_w, sin_theta_o, self.cos_theta_o);
+        let cos_theta_p = cos_sub_clamped(sin_theta_x, cos_theta_x, sin_theta_b, cos_theta_b);
+        if cos_theta_p <= self.cos_theta_e {
+            return 0.0;
+        }
+
+        let mut importance = self.phi * cos_theta_p / d2;
+
+        // Incident cosine bound at surfaces
+        let n = Vector3::from(n);
+        if n.length_squared() > 0.0 {
+            let cos_theta_i = wi.dot(n).abs();
+            let sin_theta_i = safe_sqrt(1.0 - cos_theta_i * cos_theta_i);
+            importance *= cos_sub_clamped(sin_theta_i, cos_theta_i, sin_theta_b, cos_theta_b);
+        }
+        importance.max(0.0)
+    }
+}
+
+/// Diffuse area light backed by a geometric shape.
+///
+/// Emission comes from a texture evaluated at the light's surface point, so
+/// textured emitters work the same way as constant ones. One-sided lights
+/// emit on the side the shape's normal points to. The texture is multiplied
+/// by `scale * spectrum(λ)`, which `with_spectrum` / `with_power` set.
 pub struct DiffuseAreaLight {
     pub shape: Arc<dyn Shape>,
-    pub l_emit: SampledSpectrum, // Emitted radiance (Le)
-    pub area: f32,               // Cached surface area
+    pub emit: Arc<dyn Texture>, // Emitted radiance (Le)
+    pub spectrum: Spectrum,
+    pub scale: f32,
+    pub two_sided: bool,
+    pub area: f32,              // Cached surface area
+    l_average: SampledSpectrum, // Estimated over the surface, for phi()
+    l_max: f32,
 }
 
 impl DiffuseAreaLight {
-    pub fn new(shape: Arc<dyn Shape>, l_emit: SampledSpectrum) -> Self {
+    pub fn from_texture(shape: Arc<dyn Shape>, emit: Arc<dyn Texture>, two_sided: bool) -> Self {
         let area = shape.area();
+
+        // Estimate average / maximum emission from stratified surface samples
+        const N: usize = 8;
+        let mut l_sum = SampledSpectrum::new(0.0);
+        let mut l_max = 0.0f32;
+        for i in 0..N {
+            for j in 0..N {
+                let u = Point2 { x: (i as f32 + 0.5) / N as f32, y: (j as f32 + 0.5) / N as f32 };
+                let it = shape.sample(u);
+                let l = emit.evaluate(&surface_at(&it));
+                l_sum = l_sum + l;
+                l_max = l_max.max(l.max_value());
+            }
+        }
+
         DiffuseAreaLight {
             shape,
-            l_emit,
+            emit,
+            spectrum: Spectrum::Constant(1.0),
+            scale: 1.0,
+            two_sided,
             area,
+            l_average: l_sum * (1.0 / (N * N) as f32),
+            l_max,
         }
     }
+
+    /// Emission spectrum, normalized to unit luminance so the texture still sets brightness
+    pub fn with_spectrum(mut self, spectrum: Spectrum) -> Self {
+        self.scale = photometric_scale(&spectrum, 1.0);
+        self.spectrum = spectrum;
+        self
+    }
+
+    /// Sets the scale from a physical power: W or lm emitted by the whole
+    /// surface, or the average luminance in nits
+    pub fn with_power(mut self, power: AreaPower) -> Self {
+        let sides = if self.two_sided { 2.0 } else { 1.0 };
+        let l = self.l_average.average();
+        let flux = l * sides * self.area * PI;
+        self.scale = match power {
+            AreaPower::Watts(w) => radiometric_scale(&self.spectrum, w, flux),
+            AreaPower::Lumens(lm) => luminous_scale(&self.spectrum, lm, flux),
+            AreaPower::Nits(nits) => luminous_scale(&self.spectrum, nits, l),
+        };
+        self
+    }
+
+    // Emitted radiance leaving the surface point `it` in direction `w`
+    fn radiance(&self, it: &Interaction, w: Vector3, lambda: &SampledWavelengths) -> SampledSpectrum {
+        if !self.two_sided && Vector3::from(it.n).dot(w) < 0.0 {
+            return SampledSpectrum::new(0.0);
+        }
+        self.emit.evaluate(&surface_at(it)) * self.spectrum.sample(lambda) * self.scale
+    }
+}
+
+// SurfaceInteraction for evaluating the emission texture at a sampled point
+fn surface_at(it: &Interaction) -> SurfaceInteraction {
+    SurfaceInteraction::new(it.p, it.p_error, it.uv, it.wo, it.n, it.time)
 }
 
 impl Light for DiffuseAreaLight {
@@ -61,49 +307,1500 @@ impl Light for DiffuseAreaLight {
 
     fn sample_li(
         &self,
-        ctx: &SurfaceInteraction,
+        ctx: &Interaction,
         u: Point2,
+        lambda: &SampledWavelengths,
     ) -> Option<LightLiSample> {
-        // 1. Sample a point uniformly on the light (area measure)
-        let (p_light, n_light) = self.shape.sample(u);
+        // 1. Sample a point on the light; the shape picks the strategy and
+        //    returns the density in solid angle
+        let (it, pdf) = self.shape.sample_solid_angle(ctx, u)?;
+        let p_light = it.p;
 
         // 2. Direction to light
-        let wi_vec = p_light - ctx.core.p;
+        let wi_vec = p_light - ctx.p;
         let dist_sq = wi_vec.length_squared();
         if dist_sq == 0.0 {
             return None;
         }
+        let wi = wi_vec * (1.0 / dist_sq.sqrt());
 
-        let dist = dist_sq.sqrt();
-        // FIX: Vector3 does not implement Div<f32>, use multiplication by reciprocal
-        let wi = wi_vec * (1.0 / dist);
-
-        // 3. Backface culling (light must face the shading point)
-        // FIX: Convert Normal3 to Vector3 for dot product
-        let cos_theta_light = Vector3::from(n_light).dot(-wi);
-        if cos_theta_light <= 0.0 {
+        // 3. Backface culling (light must face the shading point, unless two-sided)
+        if !self.two_sided && Vector3::from(it.n).dot(-wi) <= 0.0 {
             return None;
         }
 
-        // 4. Convert area PDF to solid angle PDF
-        //
-        // pdf_omega = (dist^2) / (area * cos_theta_light)
-        let pdf = dist_sq / (self.area * cos_theta_light);
-        if !pdf.is_finite() || pdf <= 0.0 {
+        let l = self.radiance(&it, -wi, lambda);
+        if l.is_black() {
             return None;
         }
 
         Some(LightLiSample {
-            l: self.l_emit,
+            l,
             wi,
             pdf,
             p_light,
         })
     }
 
-    fn pdf_li(&self, _ctx: &SurfaceInteraction, _wi: Vector3) -> f32 {
-        // Proper implementation requires ray–light intersection testing.
-        // This is intentionally left as 0 until MIS is wired correctly.
+    fn sample_le(&self, u1: Point2, u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        // 1. Sample a point uniformly on the light (area measure)
+        let it = self.shape.sample(u1);
+        let (p_light, mut n_light) = (it.p, it.n);
+
+        // 2. Cosine-weighted direction in the hemisphere around the normal;
+        //    two-sided lights pick a side with the first sample dimension
+        let mut u2 = u2;
+        let mut side_pdf = 1.0;
+        if self.two_sided {
+            side_pdf = 0.5;
+            if u2.x < 0.5 {
+                u2.x = (u2.x * 2.0).min(1.0 - f32::EPSILON);
+                n_light = Normal3 { x: -n_light.x, y: -n_light.y, z: -n_light.z };
+            } else {
+                u2.x = ((u2.x - 0.5) * 2.0).min(1.0 - f32::EPSILON);
+            }
+        }
+        let w_local = sample_cosine_hemisphere(u2);
+        if w_local.z <= 0.0 {
+            return None;
+        }
+        let frame = Frame::from_z(Vector3::from(n_light));
+        let w = frame.from_local(w_local);
+
+        // Offset the origin to avoid re-hitting the emitter itself
+        let ray = Ray::new(p_light + w * 0.001, w, 0.0);
+
+        Some(LightLeSample {
+            l: self.radiance(&it, w, lambda),
+            ray,
+            n: Some(n_light),
+            pdf_pos: 1.0 / self.area,
+            pdf_dir: side_pdf * w_local.z / PI,
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        // Lambertian emitter: L * A * pi per emitting side
+        let sides = if self.two_sided { 2.0 } else { 1.0 };
+        self.l_average * self.spectrum.sample(lambda) * (self.scale * sides * self.area * PI)
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        let cone = self.shape.normal_bounds();
+        let sides = if self.two_sided { 2.0 } else { 1.0 };
+        Some(LightBounds {
+            bounds: self.shape.bounds(),
+            w: cone.w,
+            phi: self.l_max * self.scale * self.spectrum.max_value() * sides * self.area * PI,
+            cos_theta_o: cone.cos_theta,
+            cos_theta_e: 0.0, // cos(pi / 2): emission over the whole hemisphere
+            two_sided: self.two_sided,
+        })
+    }
+
+    fn pdf_li(&self, ctx: &Interaction, wi: Vector3) -> f32 {
+        self.shape.pdf_solid_angle(ctx, wi)
+    }
+
+    fn l(&self, si: &SurfaceInteraction, w: Vector3, lambda: &SampledWavelengths) -> SampledSpectrum {
+        self.radiance(&si.core, w, lambda)
+    }
+}
+
+// Divides out the spectrum's luminance so `scale` alone sets the brightness
+fn photometric_scale(spectrum: &Spectrum, scale: f32) -> f32 {
+    let y = spectrum_to_photometric(spectrum);
+    if y > 0.0 { scale / y } else { 0.0 }
+}
+
+/// Physical strength of an area emitter (`EmissiveMaterial`,
+/// `DiffuseAreaLight`), which `with_power` turns into its scale.
+///
+/// Spectra are taken as spectral power per nm, so watts are matched against
+/// `∫ s(λ) dλ` and the photometric units against `K_m ∫ s(λ) ȳ(λ) dλ`: a
+/// light of N lumens is N lumens whatever the colour of its spectrum. Each
+/// kind of light has its own unit enum, holding only the units it supports.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AreaPower {
+    Watts(f32),  // Radiant flux over 360..830nm
+    Lumens(f32), // Luminous flux
+    Nits(f32),   // Average luminance (cd/m^2)
+}
+
+impl AreaPower {
+    /// The share of this power carried by a fraction `f` of an emitter:
+    /// fluxes are split, luminance is unchanged
+    pub fn fraction(self, f: f32) -> Self {
+        match self {
+            AreaPower::Watts(w) => AreaPower::Watts(w * f),
+            AreaPower::Lumens(lm) => AreaPower::Lumens(lm * f),
+            other => other,
+        }
+    }
+}
+
+/// Physical strength of a light emitting from a point (point, spot,
+/// goniometric and projection lights)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PointPower {
+    Watts(f32),   // Radiant flux over 360..830nm
+    Lumens(f32),  // Luminous flux
+    Candela(f32), // Luminous intensity in the brightest direction
+}
+
+/// Physical strength of an infinite light (uniform or image-based)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SkyPower {
+    Nits(f32), // Average luminance (cd/m^2)
+    Lux(f32),  // Illuminance on an upward-facing surface
+}
+
+// Scale that makes a light emitting `per_unit` W at scale 1 (per unit of
+// spectral content) emit `target` W
+fn radiometric_scale(spectrum: &Spectrum, target: f32, per_unit: f32) -> f32 {
+    let denom = spectrum_to_radiometric(spectrum) * per_unit;
+    if denom > 0.0 { target / denom } else { 0.0 }
+}
+
+// Same for the photometric units (lm, cd, nits, lux)
+fn luminous_scale(spectrum: &Spectrum, target: f32, per_unit: f32) -> f32 {
+    let denom = spectrum_to_luminous(spectrum) * per_unit;
+    if denom > 0.0 { target / denom } else { 0.0 }
+}
+
+// Scale for a point-like light emitting `flux` in total and `intensity` in
+// its brightest direction at scale 1
+fn point_power_scale(spectrum: &Spectrum, power: PointPower, flux: f32, intensity: f32) -> f32 {
+    match power {
+        PointPower::Watts(w) => radiometric_scale(spectrum, w, flux),
+        PointPower::Lumens(lm) => luminous_scale(spectrum, lm, flux),
+        PointPower::Candela(cd) => luminous_scale(spectrum, cd, intensity),
+    }
+}
+
+// Scale for an infinite light of average radiance `luminance` giving
+// `illuminance` at scale 1
+fn sky_power_scale(spectrum: &Spectrum, power: SkyPower, luminance: f32, illuminance: f32) -> f32 {
+    match power {
+        SkyPower::Nits(nits) => luminous_scale(spectrum, nits, luminance),
+        SkyPower::Lux(lux) => luminous_scale(spectrum, lux, illuminance),
+    }
+}
+
+/// Isotropic point light with radiant intensity `scale * I(λ)`.
+///
+/// The spectrum is normalized to unit luminance, so a blackbody and a
+/// constant spectrum with the same scale appear equally bright.
+pub struct PointLight {
+    pub p: Point3,
+    pub i: Spectrum,
+    pub scale: f32,
+}
+
+impl PointLight {
+    pub fn new(p: Point3, i: Spectrum, scale: f32) -> Self {
+        let scale = photometric_scale(&i, scale);
+        PointLight { p, i, scale }
+    }
+
+    /// Sets the scale from a physical power (W, lm or cd)
+    pub fn with_power(mut self, power: PointPower) -> Self {
+        self.scale = point_power_scale(&self.i, power, 4.0 * PI, 1.0);
+        self
+    }
+}
+
+impl Light for PointLight {
+    fn is_delta(&self) -> bool {
+        true
+    }
+
+    fn sample_li(&self, ctx: &Interaction, _u: Point2, lambda: &SampledWavelengths) -> Option<LightLiSample> {
+        let d = self.p - ctx.p;
+        let dist_sq = d.length_squared();
+        if dist_sq == 0.0 {
+            return None;
+        }
+        Some(LightLiSample {
+            l: self.i.sample(lambda) * (self.scale / dist_sq),
+            wi: d * (1.0 / dist_sq.sqrt()),
+            pdf: 1.0,
+            p_light: self.p,
+        })
+    }
+
+    fn pdf_li(&self, _ctx: &Interaction, _wi: Vector3) -> f32 {
         0.0
     }
-}
\ No newline at end of file
+
+    fn sample_le(&self, u1: Point2, _u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        let w = sample_uniform_sphere(u1);
+        Some(LightLeSample {
+            l: self.i.sample(lambda) * self.scale,
+            ray: Ray::new(self.p, w, 0.0),
+            n: None,
+            pdf_pos: 1.0,
+            pdf_dir: uniform_sphere_pdf(),
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        self.i.sample(lambda) * (4.0 * PI * self.scale)
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        Some(LightBounds {
+            bounds: Bounds3::new(self.p, self.p),
+            w: Vector3::new(0.0, 0.0, 1.0),
+            phi: 4.0 * PI * self.scale * self.i.max_value(),
+            cos_theta_o: -1.0, // cos(pi): emits in every direction
+            cos_theta_e: 0.0,
+            two_sided: false,
+        })
+    }
+}
+
+/// Point light restricted to a cone, with a smoothstep falloff between the
+/// inner (`cone_angle - cone_delta`) and outer (`cone_angle`) half-angles.
+pub struct SpotLight {
+    pub p: Point3,
+    pub frame: Frame, // z = spot axis
+    pub i: Spectrum,
+    pub scale: f32,
+    pub cos_falloff_start: f32,
+    pub cos_falloff_end: f32,
+}
+
+impl SpotLight {
+    /// Spot at `from` pointing at `to`; angles in degrees
+    pub fn new(from: Point3, to: Point3, i: Spectrum, scale: f32, cone_angle: f32, cone_delta: f32) -> Self {
+        let scale = photometric_scale(&i, scale);
+        SpotLight {
+            p: from,
+            frame: Frame::from_z((to - from).normalize()),
+            i,
+            scale,
+            cos_falloff_start: (cone_angle - cone_delta).to_radians().cos(),
+            cos_falloff_end: cone_angle.to_radians().cos(),
+        }
+    }
+
+    /// Sets the scale from a physical power: W or lm emitted into the cone,
+    /// or cd along the axis
+    pub fn with_power(mut self, power: PointPower) -> Self {
+        self.scale = point_power_scale(&self.i, power, self.solid_angle(), 1.0);
+        self
+    }
+
+    // Effective solid angle: full intensity inside the start cone, smoothstep
+    // average (1/2) in the falloff band
+    fn solid_angle(&self) -> f32 {
+        2.0 * PI * ((1.0 - self.cos_falloff_start) + (self.cos_falloff_start - self.cos_falloff_end) / 2.0)
+    }
+
+    // Intensity leaving the light in world direction `w`
+    fn intensity(&self, w: Vector3, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let cos_theta = self.frame.to_local(w).z;
+        let falloff = smooth_step(cos_theta, self.cos_falloff_end, self.cos_falloff_start);
+        self.i.sample(lambda) * (falloff * self.scale)
+    }
+}
+
+impl Light for SpotLight {
+    fn is_delta(&self) -> bool {
+        true
+    }
+
+    fn sample_li(&self, ctx: &Interaction, _u: Point2, lambda: &SampledWavelengths) -> Option<LightLiSample> {
+        let d = self.p - ctx.p;
+        let dist_sq = d.length_squared();
+        if dist_sq == 0.0 {
+            return None;
+        }
+        let wi = d * (1.0 / dist_sq.sqrt());
+        let l = self.intensity(-wi, lambda) * (1.0 / dist_sq);
+        if l.is_black() {
+            return None;
+        }
+        Some(LightLiSample { l, wi, pdf: 1.0, p_light: self.p })
+    }
+
+    fn pdf_li(&self, _ctx: &Interaction, _wi: Vector3) -> f32 {
+        0.0
+    }
+
+    fn sample_le(&self, u1: Point2, _u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        let w = self.frame.from_local(sample_uniform_cone(u1, self.cos_falloff_end));
+        Some(LightLeSample {
+            l: self.intensity(w, lambda),
+            ray: Ray::new(self.p, w, 0.0),
+            n: None,
+            pdf_pos: 1.0,
+            pdf_dir: uniform_cone_pdf(self.cos_falloff_end),
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        self.i.sample(lambda) * (self.scale * self.solid_angle())
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        // phi as for a point light, so importance falls off with the cone
+        // angles rather than being diluted by the cone's solid angle
+        let cos_theta_e = (self.cos_falloff_end.acos() - self.cos_falloff_start.acos()).cos();
+        Some(LightBounds {
+            bounds: Bounds3::new(self.p, self.p),
+            w: self.frame.from_local(Vector3::new(0.0, 0.0, 1.0)),
+            phi: 4.0 * PI * self.scale * self.i.max_value(),
+            cos_theta_o: self.cos_falloff_start,
+            cos_theta_e,
+            two_sided: false,
+        })
+    }
+}
+
+/// Point light whose intensity distribution comes from an IES profile.
+///
+/// The profile's vertical 0° (nadir) points along `to - from`; horizontal
+/// angles go around that axis. Candela values are taken as the luminous
+/// intensity of the light, so with the spectrum normalized to unit
+/// luminance `scale = 1` reproduces the file's photometry.
+pub struct GoniometricLight {
+    pub p: Point3,
+    pub frame: Frame, // z = nadir of the profile
+    pub profile: IesProfile,
+    pub i: Spectrum,
+    pub scale: f32,
+    total_intensity: f32, // ∫ profile dω (lumens for absolute photometry)
+}
+
+impl GoniometricLight {
+    pub fn new(from: Point3, to: Point3, profile: IesProfile, i: Spectrum, scale: f32) -> Self {
+        let scale = photometric_scale(&i, scale);
+
+        // Midpoint rule over (theta, phi); profiles are smooth enough for a fixed grid
+        let (nt, np) = (180, 360);
+        let (dt, dp) = (PI / nt as f32, 2.0 * PI / np as f32);
+        let mut total_intensity = 0.0;
+        for t in 0..nt {
+            let theta = (t as f32 + 0.5) * dt;
+            for p in 0..np {
+                let phi = (p as f32 + 0.5) * dp;
+                total_intensity += profile.intensity(theta.to_degrees(), phi.to_degrees()) * theta.sin();
+            }
+        }
+        total_intensity *= dt * dp;
+
+        GoniometricLight { p: from, frame: Frame::from_z((to - from).normalize()), profile, i, scale, total_intensity }
+    }
+
+    /// Sets the scale from a physical power: W or lm for the whole profile,
+    /// or cd at its brightest direction
+    pub fn with_power(mut self, power: PointPower) -> Self {
+        self.scale = point_power_scale(&self.i, power, self.total_intensity, self.profile.max_intensity());
+        self
+    }
+
+    // Intensity leaving the light in world direction `w`
+    fn intensity(&self, w: Vector3, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let wl = self.frame.to_local(w);
+        let theta = wl.z.clamp(-1.0, 1.0).acos().to_degrees();
+        let phi = wl.y.atan2(wl.x).to_degrees();
+        self.i.sample(lambda) * (self.scale * self.profile.intensity(theta, phi))
+    }
+}
+
+impl Light for GoniometricLight {
+    fn is_delta(&self) -> bool {
+        true
+    }
+
+    fn sample_li(&self, ctx: &Interaction, _u: Point2, lambda: &SampledWavelengths) -> Option<LightLiSample> {
+        let d = self.p - ctx.p;
+        let dist_sq = d.length_squared();
+        if dist_sq == 0.0 {
+            return None;
+        }
+        let wi = d * (1.0 / dist_sq.sqrt());
+        let l = self.intensity(-wi, lambda) * (1.0 / dist_sq);
+        if l.is_black() {
+            return None;
+        }
+        Some(LightLiSample { l, wi, pdf: 1.0, p_light: self.p })
+    }
+
+    fn pdf_li(&self, _ctx: &Interaction, _wi: Vector3) -> f32 {
+        0.0
+    }
+
+    fn sample_le(&self, u1: Point2, _u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        let w = sample_uniform_sphere(u1);
+        Some(LightLeSample {
+            l: self.intensity(w, lambda),
+            ray: Ray::new(self.p, w, 0.0),
+            n: None,
+            pdf_pos: 1.0,
+            pdf_dir: uniform_sphere_pdf(),
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        self.i.sample(lambda) * (self.scale * self.total_intensity)
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        Some(LightBounds {
+            bounds: Bounds3::new(self.p, self.p),
+            w: self.frame.from_local(Vector3::new(0.0, 0.0, 1.0)),
+            phi: 4.0 * PI * self.scale * self.i.max_value() * self.profile.max_intensity(),
+            cos_theta_o: -1.0,
+            cos_theta_e: 0.0,
+            two_sided: false,
+        })
+    }
+}
+
+/// Slide projector: a point light whose intensity is an image projected
+/// through a frustum.
+///
+/// Light space matches `PerspectiveCamera`: the light looks down +z, `fov`
+/// is the vertical field of view and the image's aspect ratio sets the
+/// horizontal extent, so a projector with the camera's transform lands the
+/// image exactly on what the camera sees. Radiant intensity is
+/// `scale * image(u, v)`; nothing is emitted outside the frustum.
+pub struct ProjectionLight {
+    render_from_light: Transform,
+    light_from_render: Transform,
+    width: usize,
+    height: usize,
+    texels: Vec<[f32; 3]>, // Linear RGB, row-major
+    pub scale: f32,
+    screen_max: Point2,    // Half extents of the image on the z = 1 plane
+    cos_total_width: f32,  // cos of the angle to the frustum's corners
+    distribution: Distribution2D,
+}
+
+impl ProjectionLight {
+    /// Loads the projected image with the same loader as `ImageTexture`; `fov` in degrees
+    pub fn new(render_from_light: Transform, filename: &str, fov: f32, scale: f32) -> io::Result<Self> {
+        let (width, height, texels) = read_rgb_image(filename)?;
+        Ok(Self::from_texels(width, height, texels, render_from_light, fov, scale))
+    }
+
+    pub fn from_texels(
+        width: usize,
+        height: usize,
+        texels: Vec<[f32; 3]>,
+        render_from_light: Transform,
+        fov: f32,
+        scale: f32,
+    ) -> Self {
+        assert_eq!(texels.len(), width * height);
+
+        let tan_half = (fov.to_radians() / 2.0).tan();
+        let aspect = width as f32 / height as f32;
+        let screen_max = Point2 { x: aspect * tan_half, y: tan_half };
+        let corner = Vector3::new(screen_max.x, screen_max.y, 1.0);
+
+        // Sample directions by pixel brightness times the solid angle it subtends
+        let mut f = Vec::with_capacity(width * height);
+        for v in 0..height {
+            for u in 0..width {
+                let uv = Point2 { x: (u as f32 + 0.5) / width as f32, y: (v as f32 + 0.5) / height as f32 };
+                let cos_theta = Self::uv_to_direction(screen_max, uv).z;
+                f.push(rgb_average(texels[v * width + u]) * cos_theta * cos_theta * cos_theta);
+            }
+        }
+
+        ProjectionLight {
+            render_from_light,
+            light_from_render: render_from_light.inverse(),
+            width,
+            height,
+            texels,
+            scale,
+            screen_max,
+            cos_total_width: corner.normalize().z,
+            distribution: Distribution2D::new(&f, width, height),
+        }
+    }
+
+    /// Sets the scale from a physical power: W or lm through the frustum, or
+    /// cd at the brightest texel. Texels carry no spectrum, so they count as
+    /// an equal-energy spectrum of their average value.
+    pub fn with_power(mut self, power: PointPower) -> Self {
+        let max = self.texels.iter().fold(0.0f32, |m, &t| m.max(rgb_average(t)));
+        self.scale = point_power_scale(&Spectrum::Constant(1.0), power, self.image_integral(), max);
+        self
+    }
+
+    // ∫ image dω over the frustum, per RGB channel
+    fn image_integral_rgb(&self) -> [f32; 3] {
+        // Sum of texel intensities times the solid angle each subtends
+        let mut sum = [0.0f32; 3];
+        for v in 0..self.height {
+            for u in 0..self.width {
+                let uv = Point2 { x: (u as f32 + 0.5) / self.width as f32, y: (v as f32 + 0.5) / self.height as f32 };
+                let dw = self.uv_jacobian(Self::uv_to_direction(self.screen_max, uv));
+                let t = self.texels[v * self.width + u];
+                for c in 0..3 {
+                    sum[c] += t[c] * dw;
+                }
+            }
+        }
+        let n = (self.width * self.height) as f32;
+        [sum[0] / n, sum[1] / n, sum[2] / n]
+    }
+
+    fn image_integral(&self) -> f32 {
+        rgb_average(self.image_integral_rgb())
+    }
+
+    // Light-space direction -> image uv, None outside the frustum
+    fn direction_to_uv(&self, w: Vector3) -> Option<Point2> {
+        if w.z <= 0.0 {
+            return None;
+        }
+        let (x, y) = (w.x / w.z, w.y / w.z);
+        let uv = Point2 { x: 0.5 * (x / self.screen_max.x + 1.0), y: 0.5 * (1.0 - y / self.screen_max.y) };
+        if !(0.0..=1.0).contains(&uv.x) || !(0.0..=1.0).contains(&uv.y) {
+            return None;
+        }
+        Some(uv)
+    }
+
+    // Image uv -> normalized light-space direction
+    fn uv_to_direction(screen_max: Point2, uv: Point2) -> Vector3 {
+        let x = (2.0 * uv.x - 1.0) * screen_max.x;
+        let y = (1.0 - 2.0 * uv.y) * screen_max.y;
+        Vector3::new(x, y, 1.0).normalize()
+    }
+
+    // Nearest-texel lookup, matching the piecewise-constant sampling density
+    fn lookup(&self, uv: Point2, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let x = ((uv.x * self.width as f32) as usize).min(self.width - 1);
+        let y = ((uv.y * self.height as f32) as usize).min(self.height - 1);
+        SampledSpectrum::from_rgb(self.texels[y * self.width + x], lambda) * self.scale
+    }
+
+    // Solid angle covered by one unit of uv area in direction w: dA on the
+    // z = 1 plane is 4 * screen_max.x * screen_max.y du dv, and dw = cos^3 dA
+    fn uv_jacobian(&self, w: Vector3) -> f32 {
+        4.0 * self.screen_max.x * self.screen_max.y * w.z * w.z * w.z
+    }
+
+    fn p(&self) -> Point3 {
+        self.render_from_light.transform_point(Point3::new(0.0, 0.0, 0.0))
+    }
+}
+
+impl Light for ProjectionLight {
+    fn is_delta(&self) -> bool {
+        true
+    }
+
+    fn sample_li(&self, ctx: &Interaction, _u: Point2, lambda: &SampledWavelengths) -> Option<LightLiSample> {
+        let p = self.p();
+        let d = p - ctx.p;
+        let dist_sq = d.length_squared();
+        if dist_sq == 0.0 {
+            return None;
+        }
+        let wi = d * (1.0 / dist_sq.sqrt());
+        let uv = self.direction_to_uv(self.light_from_render.transform_vector(-wi).normalize())?;
+        let l = self.lookup(uv, lambda) * (1.0 / dist_sq);
+        if l.is_black() {
+            return None;
+        }
+        Some(LightLiSample { l, wi, pdf: 1.0, p_light: p })
+    }
+
+    fn pdf_li(&self, _ctx: &Interaction, _wi: Vector3) -> f32 {
+        0.0
+    }
+
+    fn sample_le(&self, u1: Point2, _u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        let (uv, map_pdf) = self.distribution.sample_continuous(u1);
+        if map_pdf == 0.0 {
+            return None;
+        }
+        let w = Self::uv_to_direction(self.screen_max, uv);
+        Some(LightLeSample {
+            l: self.lookup(uv, lambda),
+            ray: Ray::new(self.p(), self.render_from_light.transform_vector(w).normalize(), 0.0),
+            n: None,
+            pdf_pos: 1.0,
+            pdf_dir: map_pdf / self.uv_jacobian(w),
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        SampledSpectrum::from_rgb(self.image_integral_rgb(), lambda) * self.scale
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        let max = self.texels.iter().fold(0.0f32, |m, t| m.max(t[0]).max(t[1]).max(t[2]));
+        Some(LightBounds {
+            bounds: Bounds3::new(self.p(), self.p()),
+            w: self.render_from_light.transform_vector(Vector3::new(0.0, 0.0, 1.0)).normalize(),
+            phi: 4.0 * PI * self.scale * max,
+            cos_theta_o: self.cos_total_width,
+            cos_theta_e: 0.0,
+            two_sided: false,
+        })
+    }
+}
+
+/// Directional light arriving from direction `w` with radiance-like scale
+/// `scale * L(λ)` (irradiance on a surface facing the light).
+///
+/// Emission sampling needs the scene's bounding sphere, set by `preprocess`.
+pub struct DistantLight {
+    pub w: Vector3, // Direction towards the light
+    pub l_emit: Spectrum,
+    pub scale: f32,
+    scene_center: Point3,
+    scene_radius: f32,
+}
+
+impl DistantLight {
+    /// Light travelling from `from` towards `to`
+    pub fn new(from: Point3, to: Point3, l_emit: Spectrum, scale: f32) -> Self {
+        let scale = photometric_scale(&l_emit, scale);
+        DistantLight {
+            w: (from - to).normalize(),
+            l_emit,
+            scale,
+            scene_center: Point3::new(0.0, 0.0, 0.0),
+            scene_radius: 1.0e4, // Until preprocess() sees the scene
+        }
+    }
+
+    /// Sets the scale from the illuminance (lux) on a surface facing the light
+    pub fn with_illuminance(mut self, lux: f32) -> Self {
+        self.scale = luminous_scale(&self.l_emit, lux, 1.0);
+        self
+    }
+}
+
+impl Light for DistantLight {
+    fn is_delta(&self) -> bool {
+        true
+    }
+
+    fn sample_li(&self, ctx: &Interaction, _u: Point2, lambda: &SampledWavelengths) -> Option<LightLiSample> {
+        // A point outside the scene along w bounds the shadow ray
+        let p_light = ctx.p + self.w * (2.0 * self.scene_radius);
+        Some(LightLiSample {
+            l: self.l_emit.sample(lambda) * self.scale,
+            wi: self.w,
+            pdf: 1.0,
+            p_light,
+        })
+    }
+
+    fn pdf_li(&self, _ctx: &Interaction, _wi: Vector3) -> f32 {
+        0.0
+    }
+
+    fn sample_le(&self, u1: Point2, _u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        Some(LightLeSample {
+            l: self.l_emit.sample(lambda) * self.scale,
+            ray: scene_disk_ray(self.w, u1, self.scene_center, self.scene_radius),
+            n: None,
+            pdf_pos: 1.0 / (PI * self.scene_radius * self.scene_radius),
+            pdf_dir: 1.0,
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        self.l_emit.sample(lambda) * (self.scale * PI * self.scene_radius * self.scene_radius)
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        None // Infinitely far away
+    }
+
+    fn preprocess(&mut self, scene_bounds: &Bounds3) {
+        let (center, radius) = scene_bounds.bounding_sphere();
+        self.scene_center = center;
+        self.scene_radius = radius.max(1e-3);
+    }
+}
+
+/// Constant radiance arriving from every direction.
+pub struct UniformInfiniteLight {
+    pub l_emit: Spectrum,
+    pub scale: f32,
+    scene_center: Point3,
+    scene_radius: f32,
+}
+
+impl UniformInfiniteLight {
+    pub fn new(l_emit: Spectrum, scale: f32) -> Self {
+        let scale = photometric_scale(&l_emit, scale);
+        UniformInfiniteLight {
+            l_emit,
+            scale,
+            scene_center: Point3::new(0.0, 0.0, 0.0),
+            scene_radius: 1.0e4, // Until preprocess() sees the scene
+        }
+    }
+
+    /// Sets the scale from the sky's luminance (nits) or the illuminance
+    /// (lux) it gives a horizontal surface
+    pub fn with_power(mut self, power: SkyPower) -> Self {
+        self.scale = sky_power_scale(&self.l_emit, power, 1.0, PI);
+        self
+    }
+}
+
+impl Light for UniformInfiniteLight {
+    fn is_delta(&self) -> bool {
+        false
+    }
+
+    fn is_infinite(&self) -> bool {
+        true
+    }
+
+    fn sample_li(&self, ctx: &Interaction, u: Point2, lambda: &SampledWavelengths) -> Option<LightLiSample> {
+        let wi = sample_uniform_sphere(u);
+        Some(LightLiSample {
+            l: self.l_emit.sample(lambda) * self.scale,
+            wi,
+            pdf: uniform_sphere_pdf(),
+            p_light: ctx.p + wi * (2.0 * self.scene_radius),
+        })
+    }
+
+    fn pdf_li(&self, _ctx: &Interaction, _wi: Vector3) -> f32 {
+        uniform_sphere_pdf()
+    }
+
+    fn sample_le(&self, u1: Point2, u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        let wi = sample_uniform_sphere(u1);
+        Some(LightLeSample {
+            l: self.l_emit.sample(lambda) * self.scale,
+            ray: scene_disk_ray(wi, u2, self.scene_center, self.scene_radius),
+            n: None,
+            pdf_pos: 1.0 / (PI * self.scene_radius * self.scene_radius),
+            pdf_dir: uniform_sphere_pdf(),
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        // Radiance over the full sphere of directions through the scene's disk
+        let r = self.scene_radius;
+        self.l_emit.sample(lambda) * (self.scale * 4.0 * PI * PI * r * r)
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        None
+    }
+
+    fn le(&self, _ray: &Ray, lambda: &SampledWavelengths) -> SampledSpectrum {
+        self.l_emit.sample(lambda) * self.scale
+    }
+
+    fn preprocess(&mut self, scene_bounds: &Bounds3) {
+        let (center, radius) = scene_bounds.bounding_sphere();
+        self.scene_center = center;
+        self.scene_radius = radius.max(1e-3);
+    }
+}
+
+// Ray entering the scene's bounding sphere against direction `wi`, starting
+// on the disk that covers the sphere perpendicular to `wi`
+fn scene_disk_ray(wi: Vector3, u: Point2, center: Point3, radius: f32) -> Ray {
+    let frame = Frame::from_z(wi);
+    let pd = sample_uniform_disk_polar(u);
+    let p_disk = center + frame.from_local(Vector3::new(pd.x, pd.y, 0.0)) * radius;
+    Ray::new(p_disk + wi * radius, -wi, 0.0)
+}
+
+/// Environment map light from an equirectangular (latitude-longitude) image.
+///
+/// In light space +y is up: image row 0 is the zenith and u = phi / 2pi with
+/// phi = atan2(z, x). `render_from_light` orients the map in the scene.
+/// Directions are importance sampled from a piecewise-constant distribution
+/// over the pixels, weighted by sin(theta) for the equirect area distortion.
+pub struct ImageInfiniteLight {
+    render_from_light: Transform,
+    light_from_render: Transform,
+    width: usize,
+    height: usize,
+    texels: Vec<[f32; 3]>, // Linear RGB, row-major
+    pub scale: f32,
+    distribution: Distribution2D,
+    scene_center: Point3,
+    scene_radius: f32,
+}
+
+impl ImageInfiniteLight {
+    /// Loads an environment map (Radiance .hdr, OpenEXR or any format `image` reads)
+    pub fn new(filename: &str, render_from_light: Transform, scale: f32) -> io::Result<Self> {
+        let (width, height, texels) = read_rgb_image(filename)?;
+        Ok(Self::from_texels(width, height, texels, render_from_light, scale))
+    }
+
+    pub fn from_texels(
+        width: usize,
+        height: usize,
+        texels: Vec<[f32; 3]>,
+        render_from_light: Transform,
+        scale: f32,
+    ) -> Self {
+        assert_eq!(texels.len(), width * height);
+
+        // Sampling density: pixel brightness times the solid angle it covers
+        let mut f = Vec::with_capacity(width * height);
+        for v in 0..height {
+            let sin_theta = (PI * (v as f32 + 0.5) / height as f32).sin();
+            for u in 0..width {
+                f.push(rgb_average(texels[v * width + u]) * sin_theta);
+            }
+        }
+
+        ImageInfiniteLight {
+            render_from_light,
+            light_from_render: render_from_light.inverse(),
+            width,
+            height,
+            texels,
+            scale,
+            distribution: Distribution2D::new(&f, width, height),
+            scene_center: Point3::new(0.0, 0.0, 0.0),
+            scene_radius: 1.0e4, // Until preprocess() sees the scene
+        }
+    }
+
+    /// Sets the scale from the map's average luminance (nits) or the
+    /// illuminance (lux) its upper hemisphere gives a surface facing the
+    /// map's zenith. Texels count as an equal-energy spectrum of their average.
+    pub fn with_power(mut self, power: SkyPower) -> Self {
+        let texel_solid_angle = 2.0 * PI * PI / (self.width * self.height) as f32;
+        let (mut integral, mut upper) = (0.0, 0.0);
+        for v in 0..self.height {
+            let theta = PI * (v as f32 + 0.5) / self.height as f32;
+            for u in 0..self.width {
+                let l = rgb_average(self.texels[v * self.width + u]) * theta.sin() * texel_solid_angle;
+                integral += l;
+                if theta < PI / 2.0 {
+                    upper += l * theta.cos();
+                }
+            }
+        }
+        self.scale = sky_power_scale(&Spectrum::Constant(1.0), power, integral / (4.0 * PI), upper);
+        self
+    }
+
+    // Nearest-texel lookup, matching the piecewise-constant sampling density
+    fn lookup(&self, uv: Point2, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let x = ((uv.x * self.width as f32) as usize).min(self.width - 1);
+        let y = ((uv.y * self.height as f32) as usize).min(self.height - 1);
+        SampledSpectrum::from_rgb(self.texels[y * self.width + x], lambda) * self.scale
+    }
+
+    // Render-space direction -> (image uv, sin theta)
+    fn direction_to_uv(&self, w: Vector3) -> (Point2, f32) {
+        equirect_from_direction(self.light_from_render.transform_vector(w))
+    }
+}
+
+fn rgb_average(rgb: [f32; 3]) -> f32 {
+    (rgb[0] + rgb[1] + rgb[2]) / 3.0
+}
+
+// Equirectangular mapping (+y up): direction -> (uv, sin theta)
+fn equirect_from_direction(w: Vector3) -> (Point2, f32) {
+    let w = w.normalize();
+    // atan2 rather than acos(y), which loses precision near the poles
+    let sin_theta = (w.x * w.x + w.z * w.z).sqrt();
+    let theta = sin_theta.atan2(w.y);
+    let mut phi = w.z.atan2(w.x);
+    if phi < 0.0 {
+        phi += 2.0 * PI;
+    }
+    (Point2 { x: phi / (2.0 * PI), y: theta / PI }, sin_theta)
+}
+
+// Equirectangular mapping (+y up): uv -> (direction, sin theta)
+fn equirect_to_direction(uv: Point2) -> (Vector3, f32) {
+    let (sin_theta, cos_theta) = (uv.y * PI).sin_cos();
+    let (sin_phi, cos_phi) = (uv.x * 2.0 * PI).sin_cos();
+    (Vector3::new(sin_theta * cos_phi, cos_theta, sin_theta * sin_phi), sin_theta)
+}
+
+// Samples an equirect distribution: (light-space direction, uv, solid angle pdf)
+fn sample_equirect(distribution: &Distribution2D, u: Point2) -> Option<(Vector3, Point2, f32)> {
+    let (uv, map_pdf) = distribution.sample_continuous(u);
+    let (w, sin_theta) = equirect_to_direction(uv);
+    if map_pdf == 0.0 || sin_theta == 0.0 {
+        return None;
+    }
+    // Jacobian of the (u, v) -> direction mapping: 2 pi^2 sin(theta)
+    Some((w, uv, map_pdf / (2.0 * PI * PI * sin_theta)))
+}
+
+// Solid angle density of `sample_equirect` for a light-space direction
+fn equirect_pdf(distribution: &Distribution2D, w: Vector3) -> f32 {
+    let (uv, sin_theta) = equirect_from_direction(w);
+    if sin_theta == 0.0 {
+        return 0.0;
+    }
+    distribution.pdf(uv) / (2.0 * PI * PI * sin_theta)
+}
+
+impl Light for ImageInfiniteLight {
+    fn is_delta(&self) -> bool {
+        false
+    }
+
+    fn is_infinite(&self) -> bool {
+        true
+    }
+
+    fn sample_li(&self, ctx: &Interaction, u: Point2, lambda: &SampledWavelengths) -> Option<LightLiSample> {
+        let (w, uv, pdf) = sample_equirect(&self.distribution, u)?;
+        let wi = self.render_from_light.transform_vector(w).normalize();
+        Some(LightLiSample {
+            l: self.lookup(uv, lambda),
+            wi,
+            pdf,
+            p_light: ctx.p + wi * (2.0 * self.scene_radius),
+        })
+    }
+
+    fn pdf_li(&self, _ctx: &Interaction, wi: Vector3) -> f32 {
+        equirect_pdf(&self.distribution, self.light_from_render.transform_vector(wi))
+    }
+
+    fn sample_le(&self, u1: Point2, u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        let (w, uv, pdf_dir) = sample_equirect(&self.distribution, u1)?;
+        let wi = self.render_from_light.transform_vector(w).normalize();
+        Some(LightLeSample {
+            l: self.lookup(uv, lambda),
+            ray: scene_disk_ray(wi, u2, self.scene_center, self.scene_radius),
+            n: None,
+            pdf_pos: 1.0 / (PI * self.scene_radius * self.scene_radius),
+            pdf_dir,
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        // Integral of L over the sphere (texel solid angles), through the scene's disk
+        let mut sum = [0.0f32; 3];
+        for v in 0..self.height {
+            let sin_theta = (PI * (v as f32 + 0.5) / self.height as f32).sin();
+            for u in 0..self.width {
+                let t = self.texels[v * self.width + u];
+                for c in 0..3 {
+                    sum[c] += t[c] * sin_theta;
+                }
+            }
+        }
+        let texel_solid_angle = 2.0 * PI * PI / (self.width * self.height) as f32;
+        let r = self.scene_radius;
+        SampledSpectrum::from_rgb(sum, lambda) * (self.scale * texel_solid_angle * PI * r * r)
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        None
+    }
+
+    fn le(&self, ray: &Ray, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let (uv, _) = self.direction_to_uv(ray.d);
+        self.lookup(uv, lambda)
+    }
+
+    fn preprocess(&mut self, scene_bounds: &Bounds3) {
+        let (center, radius) = scene_bounds.bounding_sphere();
+        self.scene_center = center;
+        self.scene_radius = radius.max(1e-3);
+    }
+}
+
+/// Procedural daylight sky (Preetham), evaluated spectrally per wavelength.
+///
+/// Importance sampling uses a piecewise-constant equirect table of the sky
+/// luminance; radiance itself is always evaluated analytically.
+pub struct SkyLight {
+    pub sky: PreethamSky,
+    pub scale: f32, // Multiplies the model's kcd/m^2 radiance
+    distribution: Distribution2D,
+    scene_center: Point3,
+    scene_radius: f32,
+}
+
+impl SkyLight {
+    /// Sun position in degrees (+y up, azimuth from +x towards +z)
+    pub fn new(sun_elevation: f32, sun_azimuth: f32, turbidity: f32, ground_albedo: f32, scale: f32) -> Self {
+        let sky = PreethamSky::new(sun_direction(sun_elevation, sun_azimuth), turbidity, ground_albedo);
+
+        const NU: usize = 128;
+        const NV: usize = 64;
+        let mut f = Vec::with_capacity(NU * NV);
+        for v in 0..NV {
+            for u in 0..NU {
+                let uv = Point2 { x: (u as f32 + 0.5) / NU as f32, y: (v as f32 + 0.5) / NV as f32 };
+                let (w, sin_theta) = equirect_to_direction(uv);
+                f.push(sky.xyy(w)[0] * sin_theta);
+            }
+        }
+
+        SkyLight {
+            sky,
+            scale,
+            distribution: Distribution2D::new(&f, NU, NV),
+            scene_center: Point3::new(0.0, 0.0, 0.0),
+            scene_radius: 1.0e4, // Until preprocess() sees the scene
+        }
+    }
+}
+
+impl Light for SkyLight {
+    fn is_delta(&self) -> bool {
+        false
+    }
+
+    fn is_infinite(&self) -> bool {
+        true
+    }
+
+    fn sample_li(&self, ctx: &Interaction, u: Point2, lambda: &SampledWavelengths) -> Option<LightLiSample> {
+        let (wi, _, pdf) = sample_equirect(&self.distribution, u)?;
+        Some(LightLiSample {
+            l: self.sky.radiance(wi, lambda) * self.scale,
+            wi,
+            pdf,
+            p_light: ctx.p + wi * (2.0 * self.scene_radius),
+        })
+    }
+
+    fn pdf_li(&self, _ctx: &Interaction, wi: Vector3) -> f32 {
+        equirect_pdf(&self.distribution, wi)
+    }
+
+    fn sample_le(&self, u1: Point2, u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        let (wi, _, pdf_dir) = sample_equirect(&self.distribution, u1)?;
+        Some(LightLeSample {
+            l: self.sky.radiance(wi, lambda) * self.scale,
+            ray: scene_disk_ray(wi, u2, self.scene_center, self.scene_radius),
+            n: None,
+            pdf_pos: 1.0 / (PI * self.scene_radius * self.scene_radius),
+            pdf_dir,
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        // Integrate over the equirect table's cells
+        const NU: usize = 32;
+        const NV: usize = 16;
+        let mut sum = SampledSpectrum::new(0.0);
+        for v in 0..NV {
+            for u in 0..NU {
+                let uv = Point2 { x: (u as f32 + 0.5) / NU as f32, y: (v as f32 + 0.5) / NV as f32 };
+                let (w, sin_theta) = equirect_to_direction(uv);
+                sum = sum + self.sky.radiance(w, lambda) * sin_theta;
+            }
+        }
+        let cell_solid_angle = 2.0 * PI * PI / (NU * NV) as f32;
+        let r = self.scene_radius;
+        sum * (self.scale * cell_solid_angle * PI * r * r)
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        None
+    }
+
+    fn le(&self, ray: &Ray, lambda: &SampledWavelengths) -> SampledSpectrum {
+        self.sky.radiance(ray.d, lambda) * self.scale
+    }
+
+    fn preprocess(&mut self, scene_bounds: &Bounds3) {
+        let (center, radius) = scene_bounds.bounding_sphere();
+        self.scene_center = center;
+        self.scene_radius = radius.max(1e-3);
+    }
+}
+
+/// The solar disk as seen through the atmosphere: constant radiance over a
+/// cone of `SUN_ANGULAR_RADIUS`, sampled uniformly within the cone.
+/// Pair with a `SkyLight` using the same sun position and turbidity.
+pub struct SunLight {
+    pub w: Vector3, // Towards the sun
+    pub l_emit: Spectrum,
+    pub scale: f32, // Multiplies the model's kcd/m^2 radiance
+    cos_theta_max: f32,
+    scene_center: Point3,
+    scene_radius: f32,
+}
+
+impl SunLight {
+    /// Sun position in degrees (+y up, azimuth from +x towards +z)
+    pub fn new(elevation: f32, azimuth: f32, turbidity: f32, scale: f32) -> Self {
+        let w = sun_direction(elevation, azimuth);
+        SunLight {
+            w,
+            l_emit: SunSpectrum::new(w, turbidity).spectrum,
+            scale,
+            cos_theta_max: SUN_ANGULAR_RADIUS.cos(),
+            scene_center: Point3::new(0.0, 0.0, 0.0),
+            scene_radius: 1.0e4, // Until preprocess() sees the scene
+        }
+    }
+
+    fn in_disk(&self, w: Vector3) -> bool {
+        w.normalize().dot(self.w) >= self.cos_theta_max
+    }
+}
+
+impl Light for SunLight {
+    fn is_delta(&self) -> bool {
+        false
+    }
+
+    fn is_infinite(&self) -> bool {
+        true
+    }
+
+    fn sample_li(&self, ctx: &Interaction, u: Point2, lambda: &SampledWavelengths) -> Option<LightLiSample> {
+        let wi = Frame::from_z(self.w).from_local(sample_uniform_cone(u, self.cos_theta_max));
+        Some(LightLiSample {
+            l: self.l_emit.sample(lambda) * self.scale,
+            wi,
+            pdf: uniform_cone_pdf(self.cos_theta_max),
+            p_light: ctx.p + wi * (2.0 * self.scene_radius),
+        })
+    }
+
+    fn pdf_li(&self, _ctx: &Interaction, wi: Vector3) -> f32 {
+        if self.in_disk(wi) { uniform_cone_pdf(self.cos_theta_max) } else { 0.0 }
+    }
+
+    fn sample_le(&self, u1: Point2, u2: Point2, lambda: &SampledWavelengths) -> Option<LightLeSample> {
+        let wi = Frame::from_z(self.w).from_local(sample_uniform_cone(u1, self.cos_theta_max));
+        Some(LightLeSample {
+            l: self.l_emit.sample(lambda) * self.scale,
+            ray: scene_disk_ray(wi, u2, self.scene_center, self.scene_radius),
+            n: None,
+            pdf_pos: 1.0 / (PI * self.scene_radius * self.scene_radius),
+            pdf_dir: uniform_cone_pdf(self.cos_theta_max),
+        })
+    }
+
+    fn phi(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        // Irradiance (L * disk solid angle) through the scene's cross-section
+        let r = self.scene_radius;
+        self.l_emit.sample(lambda) * (self.scale * sun_solid_angle() * PI * r * r)
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        None
+    }
+
+    fn le(&self, ray: &Ray, lambda: &SampledWavelengths) -> SampledSpectrum {
+        if self.in_disk(ray.d) {
+            self.l_emit.sample(lambda) * self.scale
+        } else {
+            SampledSpectrum::new(0.0)
+        }
+    }
+
+    fn preprocess(&mut self, scene_bounds: &Bounds3) {
+        let (center, radius) = scene_bounds.bounding_sphere();
+        self.scene_center = center;
+        self.scene_radius = radius.max(1e-3);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bsdf::tests::{sphere_integral, stratified};
+    use crate::core::spectrum::PiecewiseLinearSpectrum;
+    use crate::core::texture::ConstantTexture;
+    use crate::core::transform::Matrix4x4;
+    use crate::shapes::cylinder::Cylinder;
+    use crate::shapes::disk::Disk;
+    use crate::shapes::sphere::Sphere;
+    use crate::shapes::triangle::{Triangle, TriangleMesh};
+
+    fn reference(p: Point3) -> Interaction {
+        let zero = Vector3::new(0.0, 0.0, 0.0);
+        Interaction { p, time: 0.0, p_error: zero, wo: zero, n: Normal3 { x: 0.0, y: 0.0, z: 0.0 }, uv: Point2 { x: 0.0, y: 0.0 } }
+    }
+
+    /// Checks that `sample_li` reports the density `pdf_li` gives its
+    /// directions, and that `pdf_li` integrates to one over the sphere
+    fn check_pdf_li(what: &str, light: &dyn Light, ctx: &Interaction) {
+        let lambda = SampledWavelengths::sample_uniform(0.5);
+        let mut produced = 0;
+        for u in stratified(64, 3) {
+            let Some(ls) = light.sample_li(ctx, u, &lambda) else { continue };
+            produced += 1;
+            let pdf = light.pdf_li(ctx, ls.wi);
+            assert!((ls.pdf - pdf).abs() <= 1e-3 * ls.pdf, "{}: sampled pdf {} vs pdf_li {} for {:?}", what, ls.pdf, pdf, ls.wi);
+        }
+        assert!(produced > 0, "{}: no samples", what);
+        let integral = sphere_integral(512, |w| light.pdf_li(ctx, w));
+        assert!((integral - 1.0).abs() < 0.02, "{}: pdf_li integrates to {}", what, integral);
+    }
+
+    /// Checks that light sampling estimates the radiance `le` gives escaping rays
+    fn check_infinite_estimate(what: &str, light: &dyn Light, ctx: &Interaction) {
+        let lambda = SampledWavelengths::sample_uniform(0.5);
+        let points = stratified(128, 5);
+        let sampled = points
+            .iter()
+            .filter_map(|&u| light.sample_li(ctx, u, &lambda))
+            .map(|ls| (ls.l.values[0] / ls.pdf) as f64)
+            .sum::<f64>() as f32
+            / points.len() as f32;
+        let expected = sphere_integral(512, |w| light.le(&Ray::new(ctx.p, w, 0.0), &lambda).values[0]);
+        assert!((sampled - expected).abs() < 0.02 * expected, "{}: sampled {} vs {}", what, sampled, expected);
+    }
+
+    /// Checks a delta light: `sample_li` always returns `wi` with pdf 1 (and
+    /// `pdf_li` is 0), and returns the radiance at `ctx` (None when black)
+    fn delta_li(what: &str, light: &dyn Light, ctx: &Interaction, wi: Vector3) -> Option<f32> {
+        let lambda = SampledWavelengths::sample_uniform(0.5);
+        assert!(light.is_delta(), "{}: not a delta light", what);
+        let samples: Vec<_> = stratified(4, 3).into_iter().map(|u| light.sample_li(ctx, u, &lambda)).collect();
+        for ls in samples.iter().flatten() {
+            assert_eq!(ls.pdf, 1.0, "{}", what);
+            assert!((ls.wi - wi).length() < 1e-5, "{}: wi {:?} vs {:?}", what, ls.wi, wi);
+            assert_eq!(light.pdf_li(ctx, ls.wi), 0.0, "{}", what);
+        }
+        samples[0].as_ref().map(|ls| ls.l.values[0])
+    }
+
+    fn triangle(p: [Point3; 3]) -> Arc<dyn Shape> {
+        Arc::new(Triangle::new(Arc::new(TriangleMesh::new(vec![0, 1, 2], p.to_vec(), None)), 0))
+    }
+
+    #[test]
+    fn triangle_light_pdf_matches_sampling() {
+        let shape = triangle([Point3::new(-1.0, 0.0, 2.0), Point3::new(1.0, 0.0, 2.0), Point3::new(0.0, 1.5, 2.5)]);
+        let light = DiffuseAreaLight::from_texture(shape, Arc::new(ConstantTexture::new(SampledSpectrum::new(1.0))), true);
+        check_pdf_li("triangle", &light, &reference(Point3::new(0.2, 0.3, 0.0)));
+    }
+
+    #[test]
+    fn point_spot_and_distant_lights_are_delta() {
+        let up = Vector3::new(0.0, 1.0, 0.0);
+        let point = PointLight::new(Point3::new(0.0, 2.0, 0.0), Spectrum::Constant(1.0), 3.0);
+        let near = delta_li("point", &point, &reference(Point3::new(0.0, 1.0, 0.0)), up).unwrap();
+        let far = delta_li("point", &point, &reference(Point3::new(0.0, 0.0, 0.0)), up).unwrap();
+        assert!((near - 4.0 * far).abs() < 1e-4 * near, "inverse square: {} vs {}", near, far);
+
+        // 30 degree cone, full intensity within 20 degrees
+        let spot = SpotLight::new(Point3::new(0.0, 2.0, 0.0), Point3::new(0.0, 0.0, 0.0), Spectrum::Constant(1.0), 3.0, 30.0, 10.0);
+        let axis = delta_li("spot", &spot, &reference(Point3::new(0.0, 0.0, 0.0)), up).unwrap();
+        assert!((axis - far).abs() < 1e-4 * far, "spot axis {} vs point {}", axis, far);
+        let off = Point3::new(2.0 * 25f32.to_radians().tan(), 0.0, 0.0);
+        let band = delta_li("spot", &spot, &reference(off), (Point3::new(0.0, 2.0, 0.0) - off).normalize()).unwrap();
+        assert!(band > 0.0 && band < axis, "falloff band {}", band);
+        let outside = Point3::new(2.0, 0.0, 0.0);
+        assert!(delta_li("spot", &spot, &reference(outside), (Point3::new(0.0, 2.0, 0.0) - outside).normalize()).is_none());
+
+        let distant = DistantLight::new(Point3::new(1.0, 1.0, 0.0), Point3::new(0.0, 0.0, 0.0), Spectrum::Constant(1.0), 2.0);
+        let w = Vector3::new(1.0, 1.0, 0.0).normalize();
+        let a = delta_li("distant", &distant, &reference(Point3::new(0.0, 0.0, 0.0)), w).unwrap();
+        let b = delta_li("distant", &distant, &reference(Point3::new(5.0, -3.0, 1.0)), w).unwrap();
+        assert_eq!(a, b);
+    }
+
+    // Equirect map with a bright spot and a dark band, rotated off its axes
+    fn environment() -> ImageInfiniteLight {
+        let (width, height) = (32, 16);
+        let texels = (0..width * height)
+            .map(|i| {
+                let (u, v) = (i % width, i / width);
+                let l = if (10..13).contains(&u) && (4..6).contains(&v) { 50.0 } else if v == 9 { 0.0 } else { 0.5 + 0.03 * u as f32 };
+                [l, 0.8 * l, 0.6 * l]
+            })
+            .collect();
+        let render_from_light = Transform::rotate(30.0, Vector3::new(1.0, 0.0, 1.0).normalize());
+        ImageInfiniteLight::from_texels(width, height, texels, render_from_light, 1.0)
+    }
+
+    #[test]
+    fn infinite_lights_pdf_matches_sampling() {
+        let ctx = reference(Point3::new(0.3, -0.2, 0.1));
+        let uniform = UniformInfiniteLight::new(Spectrum::Constant(1.0), 2.0);
+        check_pdf_li("uniform", &uniform, &ctx);
+        check_infinite_estimate("uniform", &uniform, &ctx);
+
+        let image = environment();
+        check_pdf_li("image", &image, &ctx);
+        check_infinite_estimate("image", &image, &ctx);
+    }
+
+    #[test]
+    fn sky_and_sun_pdf_matches_sampling() {
+        let ctx = reference(Point3::new(0.0, 0.0, 0.0));
+        let sky = SkyLight::new(35.0, 120.0, 3.0, 0.2, 1.0);
+        check_pdf_li("sky", &sky, &ctx);
+        check_infinite_estimate("sky", &sky, &ctx);
+
+        // The disk is too small to integrate over the sphere: check it against its solid angle
+        let sun = SunLight::new(35.0, 120.0, 3.0, 1.0);
+        let lambda = SampledWavelengths::sample_uniform(0.5);
+        let l = sun.l_emit.sample(&lambda).values[0];
+        let points = stratified(16, 3);
+        let mut estimate = 0.0;
+        for &u in &points {
+            let ls = sun.sample_li(&ctx, u, &lambda).unwrap();
+            assert!(sun.in_disk(ls.wi));
+            assert!((sun.pdf_li(&ctx, ls.wi) - ls.pdf).abs() <= 1e-3 * ls.pdf);
+            estimate += ls.l.values[0] / ls.pdf / points.len() as f32;
+        }
+        assert!((estimate - l * sun_solid_angle()).abs() < 1e-3 * estimate, "{} vs {}", estimate, l * sun_solid_angle());
+        assert_eq!(sun.pdf_li(&ctx, Vector3::new(0.0, 1.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn goniometric_light_follows_its_profile() {
+        let profile = IesProfile {
+            vertical_angles: vec![0.0, 90.0, 180.0],
+            horizontal_angles: vec![0.0],
+            candela: vec![100.0, 50.0, 0.0],
+            lumens: -1.0,
+        };
+        let from = Point3::new(0.0, 2.0, 0.0);
+        let light = GoniometricLight::new(from, Point3::new(0.0, 0.0, 0.0), profile, Spectrum::Constant(1.0), 1.0);
+        let towards = |p: Point3| (from - p).normalize();
+
+        let nadir = delta_li("goniometric", &light, &reference(Point3::new(0.0, 0.0, 0.0)), towards(Point3::new(0.0, 0.0, 0.0))).unwrap();
+        let side = Point3::new(0.0, 2.0, 2.0);
+        let horizontal = delta_li("goniometric", &light, &reference(side), towards(side)).unwrap();
+        assert!((nadir - 2.0 * horizontal).abs() < 1e-4 * nadir, "nadir {} vs horizontal {}", nadir, horizontal);
+        let above = Point3::new(0.0, 4.0, 0.0);
+        assert!(delta_li("goniometric", &light, &reference(above), towards(above)).is_none());
+    }
+
+    #[test]
+    fn projection_light_projects_its_image() {
+        // 32x16 image whose top-right quadrant is bright, looking down +z
+        let texels = (0..32 * 16).map(|i| if i % 32 >= 16 && i / 32 < 8 { [9.0; 3] } else { [1.0; 3] }).collect();
+        let light = ProjectionLight::from_texels(32, 16, texels, Transform::new(Matrix4x4::identity()), 60.0, 1.0);
+        let origin = Point3::new(0.0, 0.0, 0.0);
+        let at = |p: Point3| delta_li("projection", &light, &reference(p), (origin - p).normalize());
+
+        let dim = at(Point3::new(-0.5, -0.2, 2.0)).unwrap();
+        let bright = at(Point3::new(1.5, 0.5, 2.0)).unwrap();
+        let d_sq = |p: Point3| (p - origin).length_squared();
+        let ratio = bright * d_sq(Point3::new(1.5, 0.5, 2.0)) / (dim * d_sq(Point3::new(-0.5, -0.2, 2.0)));
+        assert!((ratio - 9.0).abs() < 1e-3, "{}", ratio);
+        assert!(at(Point3::new(0.0, 3.0, 2.0)).is_none(), "outside the frustum");
+        assert!(at(Point3::new(0.0, 0.0, -2.0)).is_none(), "behind the projector");
+
+        // Emission sampling estimates the power through the frustum
+        let lambda = SampledWavelengths::sample_uniform(0.5);
+        let points = stratified(64, 3);
+        let estimate: f32 = points
+            .iter()
+            .map(|&u| {
+                let le = light.sample_le(u, u, &lambda).unwrap();
+                le.l.values[0] / le.pdf_dir
+            })
+            .sum::<f32>()
+            / points.len() as f32;
+        let phi = light.phi(&lambda).values[0];
+        assert!((estimate - phi).abs() < 0.01 * phi, "sampled {} vs phi {}", estimate, phi);
+    }
+
+    #[test]
+    fn shape_lights_pdf_matches_sampling() {
+        let emit = || Arc::new(ConstantTexture::new(SampledSpectrum::new(1.0)));
+        let ctx = reference(Point3::new(0.2, -0.1, 0.3));
+        let shapes: [(&str, Arc<dyn Shape>); 3] = [
+            ("sphere", Arc::new(Sphere::new(Point3::new(0.5, 1.0, 3.0), 1.0))),
+            ("disk", Arc::new(Disk::new(Point3::new(0.0, 2.0, 1.0), Vector3::new(0.3, -1.0, 0.2), 1.5))),
+            ("spherical triangle", {
+                let p = vec![Point3::new(-1.0, 0.0, 1.0), Point3::new(1.0, 0.0, 1.0), Point3::new(0.0, 1.5, 1.5)];
+                let mesh = TriangleMesh::new(vec![0, 1, 2], p, None).with_solid_angle_sampling(true);
+                Arc::new(Triangle::new(Arc::new(mesh), 0))
+            }),
+        ];
+        for (what, shape) in shapes {
+            check_pdf_li(what, &DiffuseAreaLight::from_texture(shape, emit(), true), &ctx);
+        }
+
+        // Area sampling may pick a point behind the tube's near side, where
+        // pdf_li sees the first hit; from the axis every ray hits it once
+        let cylinder = Arc::new(Cylinder::new(Point3::new(0.2, -0.1, 1.0), Point3::new(0.2, -0.1, 2.0), 0.5));
+        check_pdf_li("cylinder", &DiffuseAreaLight::from_texture(cylinder, emit(), true), &ctx);
+
+        // Inside the sphere, sampling falls back to uniform area sampling
+        let sphere = Arc::new(Sphere::new(Point3::new(0.0, 0.0, 0.0), 2.0));
+        check_pdf_li("inside sphere", &DiffuseAreaLight::from_texture(sphere, emit(), true), &ctx);
+    }
+
+    // Tabulates a per-wavelength quantity of a light over the visible range
+    fn tabulate(f: impl Fn(&SampledWavelengths) -> SampledSpectrum) -> Spectrum {
+        let lambda: Vec<f32> = (360..=830).map(|l| l as f32).collect();
+        let values = lambda
+            .iter()
+            .map(|&l| f(&SampledWavelengths { lambda: [l; 4], pdf: [1.0; 4] }).values[0])
+            .collect();
+        Spectrum::PiecewiseLinear(PiecewiseLinearSpectrum::new(lambda, values))
+    }
+
+    fn assert_close(what: &str, value: f32, expected: f32, tolerance: f32) {
+        assert!((value - expected).abs() <= tolerance * expected, "{}: {} vs {}", what, value, expected);
+    }
+
+    #[test]
+    fn with_power_round_trips() {
+        let warm = || Spectrum::blackbody(3000.0);
+        let ctx = reference(Point3::new(0.0, 0.0, 0.0));
+        let u = Point2 { x: 0.3, y: 0.6 };
+        let li = |light: &dyn Light, ctx: &Interaction| tabulate(|lambda| light.sample_li(ctx, u, lambda).unwrap().l);
+
+        let shape = || triangle([Point3::new(-1.0, 0.0, 2.0), Point3::new(1.0, 0.0, 2.0), Point3::new(0.0, 1.5, 2.5)]);
+        let area = |power| {
+            let emit = Arc::new(ConstantTexture::new(SampledSpectrum::new(1.0)));
+            DiffuseAreaLight::from_texture(shape(), emit, true).with_spectrum(warm()).with_power(power)
+        };
+        let light = area(AreaPower::Watts(50.0));
+        assert_close("area W", spectrum_to_radiometric(&tabulate(|l| light.phi(l))), 50.0, 1e-3);
+        let light = area(AreaPower::Lumens(800.0));
+        assert_close("area lm", spectrum_to_luminous(&tabulate(|l| light.phi(l))), 800.0, 1e-3);
+        let light = area(AreaPower::Nits(100.0));
+        assert_close("area nits", spectrum_to_luminous(&li(&light, &ctx)), 100.0, 1e-3);
+
+        let p = Point3::new(0.0, 2.0, 0.0);
+        let point = |power| PointLight::new(p, warm(), 1.0).with_power(power);
+        let light = point(PointPower::Watts(60.0));
+        assert_close("point W", spectrum_to_radiometric(&tabulate(|l| light.phi(l))), 60.0, 1e-3);
+        let light = point(PointPower::Lumens(800.0));
+        assert_close("point lm", spectrum_to_luminous(&tabulate(|l| light.phi(l))), 800.0, 1e-3);
+        let light = point(PointPower::Candela(50.0));
+        assert_close("point cd", 4.0 * spectrum_to_luminous(&li(&light, &ctx)), 50.0, 1e-3);
+
+        let spot = |power| SpotLight::new(p, Point3::new(0.0, 0.0, 0.0), warm(), 1.0, 30.0, 10.0).with_power(power);
+        let light = spot(PointPower::Lumens(400.0));
+        assert_close("spot lm", spectrum_to_luminous(&tabulate(|l| light.phi(l))), 400.0, 1e-3);
+        let light = spot(PointPower::Candela(50.0));
+        assert_close("spot cd", 4.0 * spectrum_to_luminous(&li(&light, &ctx)), 50.0, 1e-3);
+
+        let distant = DistantLight::new(p, Point3::new(0.0, 0.0, 0.0), warm(), 1.0).with_illuminance(1000.0);
+        assert_close("distant lux", spectrum_to_luminous(&li(&distant, &ctx)), 1000.0, 1e-3);
+
+        let sky = UniformInfiniteLight::new(warm(), 1.0).with_power(SkyPower::Nits(300.0));
+        assert_close("uniform nits", spectrum_to_luminous(&li(&sky, &ctx)), 300.0, 1e-3);
+        let sky = UniformInfiniteLight::new(warm(), 1.0).with_power(SkyPower::Lux(1000.0));
+        assert_close("uniform lux", PI * spectrum_to_luminous(&li(&sky, &ctx)), 1000.0, 1e-3);
+
+        // Average luminance of the map over the sphere
+        let env = environment().with_power(SkyPower::Nits(300.0));
+        let radiance = tabulate(|lambda| {
+            let l = sphere_integral(64, |w| env.le(&Ray::new(ctx.p, w, 0.0), lambda).values[0]);
+            SampledSpectrum::new(l / (4.0 * PI))
+        });
+        assert_close("image nits", spectrum_to_luminous(&radiance), 300.0, 0.02);
+    }
+}
@@ -0,0 +1,384 @@
+use std::f32::consts::PI;
+
+use crate::core::geometry::{Bounds3, Normal3, Point3};
+use crate::core::interaction::Interaction;
+use crate::core::light::{Light, LightBounds};
+use crate::core::math::AliasTable;
+use crate::core::spectrum::SampledWavelengths;
+
+const ONE_MINUS_EPSILON: f32 = 1.0 - f32::EPSILON;
+
+/// A light chosen by a `LightSampler`: index into the scene's light list and
+/// the discrete probability of having chosen it.
+#[derive(Debug, Clone, Copy)]
+pub struct SampledLight {
+    pub light: usize,
+    pub p: f32,
+}
+
+/// Chooses one light for next event estimation.
+///
+/// `pmf` must return the probability `sample` would pick `light` for the same
+/// reference point; integrators need it to MIS-weight BSDF-sampled hits.
+pub trait LightSampler: Send + Sync {
+    fn sample(&self, ctx: &Interaction, u: f32) -> Option<SampledLight>;
+    fn pmf(&self, ctx: &Interaction, light: usize) -> f32;
+}
+
+// --- 1. Power (alias table over emitted power) ---
+//
+// Ignores the reference point; also the natural choice for photon emission.
+pub struct PowerLightSampler {
+    alias_table: Option<AliasTable>,
+}
+
+impl PowerLightSampler {
+    pub fn new(lights: &[Box<dyn Light>]) -> Self {
+        if lights.is_empty() {
+            return PowerLightSampler { alias_table: None };
+        }
+        let lambda = SampledWavelengths::sample_uniform(0.5);
+        let power: Vec<f32> = lights.iter().map(|l| l.phi(&lambda).average().max(0.0)).collect();
+        PowerLightSampler { alias_table: Some(AliasTable::new(&power)) }
+    }
+
+    /// Context-free sampling (e.g. for photon emission)
+    pub fn sample_light(&self, u: f32) -> Option<SampledLight> {
+        let table = self.alias_table.as_ref()?;
+        let (light, p) = table.sample(u);
+        if p == 0.0 {
+            return None;
+        }
+        Some(SampledLight { light, p })
+    }
+}
+
+impl LightSampler for PowerLightSampler {
+    fn sample(&self, _ctx: &Interaction, u: f32) -> Option<SampledLight> {
+        self.sample_light(u)
+    }
+
+    fn pmf(&self, _ctx: &Interaction, light: usize) -> f32 {
+        self.alias_table.as_ref().map_or(0.0, |t| t.pmf(light))
+    }
+}
+
+// --- 2. Light BVH ---
+//
+// Bounded lights are organised in a binary tree whose nodes store the union
+// of their lights' `LightBounds`. Sampling walks from the root, choosing each
+// child with probability proportional to its importance at the reference
+// point. Lights without bounds (infinite lights) are sampled separately.
+#[derive(Debug, Clone, Copy)]
+struct LightBVHNode {
+    light_bounds: LightBounds,
+    // Leaf: index of the light. Interior: index of the second child
+    // (the first child always follows its parent).
+    index: usize,
+    is_leaf: bool,
+}
+
+pub struct BVHLightSampler {
+    nodes: Vec<LightBVHNode>,
+    infinite_lights: Vec<usize>,
+    // Per light: path from the root (bit i set = took the second child at depth i)
+    bit_trails: Vec<Option<u64>>,
+}
+
+impl BVHLightSampler {
+    pub fn new(lights: &[Box<dyn Light>]) -> Self {
+        let mut infinite_lights = Vec::new();
+        let mut bvh_lights = Vec::new();
+        for (i, light) in lights.iter().enumerate() {
+            match light.bounds() {
+                None => infinite_lights.push(i),
+                Some(lb) if lb.phi > 0.0 => bvh_lights.push((i, lb)),
+                Some(_) => {} // Emits nothing: never sampled
+            }
+        }
+
+        let mut sampler = BVHLightSampler {
+            nodes: Vec::new(),
+            infinite_lights,
+            bit_trails: vec![None; lights.len()],
+        };
+        if !bvh_lights.is_empty() {
+            sampler.build(&mut bvh_lights, 0, 0);
+        }
+        sampler
+    }
+
+    // Recursively builds the subtree for `lights`, returning its bounds
+    fn build(&mut self, lights: &mut [(usize, LightBounds)], bit_trail: u64, depth: u32) -> LightBounds {
+        if lights.len() == 1 {
+            let (light, lb) = lights[0];
+            self.nodes.push(LightBVHNode { light_bounds: lb, index: light, is_leaf: true });
+            self.bit_trails[light] = Some(bit_trail);
+            return lb;
+        }
+
+        // Bounds of all lights and of their centroids
+        let mut bounds = lights[0].1.bounds;
+        let mut centroid_bounds = Bounds3::new(bounds.centroid(), bounds.centroid());
+        for (_, lb) in lights.iter() {
+            bounds = bounds.union(lb.bounds);
+            centroid_bounds = centroid_bounds.union_point(lb.bounds.centroid());
+        }
+
+        // Choose the split with the lowest cost over 12 buckets per axis
+        const N_BUCKETS: usize = 12;
+        let centroid_diag = centroid_bounds.diagonal();
+        let bucket_of = |c: Point3, dim: usize, extent: f32| {
+            let offset = axis(c - centroid_bounds.min, dim) / extent;
+            ((offset * N_BUCKETS as f32) as usize).min(N_BUCKETS - 1)
+        };
+        let mut best: Option<(f32, usize, usize)> = None; // (cost, dim, bucket)
+        for dim in 0..3 {
+            let extent = axis(centroid_diag, dim);
+            if extent <= 0.0 {
+                continue;
+            }
+            let mut buckets: [Option<LightBounds>; N_BUCKETS] = [None; N_BUCKETS];
+            for (_, lb) in lights.iter() {
+                let b = bucket_of(lb.bounds.centroid(), dim, extent);
+                buckets[b] = Some(match &buckets[b] {
+                    Some(acc) => LightBounds::union(acc, lb),
+                    None => *lb,
+                });
+            }
+
+            for split in 0..N_BUCKETS - 1 {
+                let merge = |range: &[Option<LightBounds>]| {
+                    range.iter().flatten().fold(None, |acc: Option<LightBounds>, b| {
+                        Some(acc.map_or(*b, |a| LightBounds::union(&a, b)))
+                    })
+                };
+                let (Some(b0), Some(b1)) = (merge(&buckets[..=split]), merge(&buckets[split + 1..])) else {
+                    continue;
+                };
+                let cost = evaluate_cost(&b0, &bounds, dim) + evaluate_cost(&b1, &bounds, dim);
+                if cost > 0.0 && best.is_none_or(|(c, _, _)| cost < c) {
+                    best = Some((cost, dim, split));
+                }
+            }
+        }
+
+        // Partition. Bucket splits can be lopsided, so once they have used
+        // half of the 64-bit trail, or when none works, split at the median
+        // centroid along the widest axis: that needs at most log2(n) more levels
+        let mid = match best {
+            Some((_, dim, split)) if depth < 32 => {
+                let extent = axis(centroid_diag, dim);
+                lights.sort_by_key(|(_, lb)| bucket_of(lb.bounds.centroid(), dim, extent) > split);
+                lights.partition_point(|(_, lb)| bucket_of(lb.bounds.centroid(), dim, extent) <= split)
+            }
+            _ => {
+                let dim = (0..3).fold(0, |d, i| if axis(centroid_diag, i) > axis(centroid_diag, d) { i } else { d });
+                let key = |lb: &LightBounds| axis(lb.bounds.centroid() - centroid_bounds.min, dim);
+                lights.sort_by(|a, b| key(&a.1).total_cmp(&key(&b.1)));
+                lights.len() / 2
+            }
+        };
+        let mid = mid.clamp(1, lights.len() - 1);
+
+        // Interior node: first child follows directly, second child index patched in
+        let node_index = self.nodes.len();
+        self.nodes.push(LightBVHNode { light_bounds: lights[0].1, index: 0, is_leaf: false });
+        let (left, right) = lights.split_at_mut(mid);
+        let lb0 = self.build(left, bit_trail, depth + 1);
+        self.nodes[node_index].index = self.nodes.len();
+        let lb1 = self.build(right, bit_trail | (1u64 << depth), depth + 1);
+
+        let lb = LightBounds::union(&lb0, &lb1);
+        self.nodes[node_index].light_bounds = lb;
+        lb
+    }
+
+    // Probability of sampling the infinite lights as a group
+    fn p_infinite(&self) -> f32 {
+        let n_inf = self.infinite_lights.len() as f32;
+        let n_bvh = if self.nodes.is_empty() { 0.0 } else { 1.0 };
+        if n_inf + n_bvh == 0.0 { 0.0 } else { n_inf / (n_inf + n_bvh) }
+    }
+
+    // Importances of an interior node's two children
+    fn child_importance(&self, node_index: usize, p: Point3, n: Normal3) -> (f32, f32) {
+        let second = self.nodes[node_index].index;
+        (
+            self.nodes[node_index + 1].light_bounds.importance(p, n),
+            self.nodes[second].light_bounds.importance(p, n),
+        )
+    }
+}
+
+impl LightSampler for BVHLightSampler {
+    fn sample(&self, ctx: &Interaction, mut u: f32) -> Option<SampledLight> {
+        let p_inf = self.p_infinite();
+        if u < p_inf {
+            let n = self.infinite_lights.len();
+            let i = ((u / p_inf * n as f32) as usize).min(n - 1);
+            return Some(SampledLight { light: self.infinite_lights[i], p: p_inf / n as f32 });
+        }
+        if self.nodes.is_empty() {
+            return None;
+        }
+
+        u = ((u - p_inf) / (1.0 - p_inf)).min(ONE_MINUS_EPSILON);
+        let mut node_index = 0;
+        let mut pmf = 1.0 - p_inf;
+        loop {
+            let node = &self.nodes[node_index];
+            if node.is_leaf {
+                // A single-light tree still has to check that the light can contribute
+                if node_index > 0 || node.light_bounds.importance(ctx.p, ctx.n) > 0.0 {
+                    return Some(SampledLight { light: node.index, p: pmf });
+                }
+                return None;
+            }
+
+            let (c0, c1) = self.child_importance(node_index, ctx.p, ctx.n);
+            if c0 == 0.0 && c1 == 0.0 {
+                return None;
+            }
+            let p0 = c0 / (c0 + c1);
+            if u < p0 {
+                node_index += 1;
+                u = (u / p0).min(ONE_MINUS_EPSILON);
+                pmf *= p0;
+            } else {
+                node_index = node.index;
+                u = ((u - p0) / (1.0 - p0)).min(ONE_MINUS_EPSILON);
+                pmf *= 1.0 - p0;
+            }
+        }
+    }
+
+    fn pmf(&self, ctx: &Interaction, light: usize) -> f32 {
+        let Some(mut bit_trail) = self.bit_trails.get(light).copied().flatten() else {
+            // Infinite light (or one that is never sampled)
+            return if self.infinite_lights.contains(&light) {
+                self.p_infinite() / self.infinite_lights.len() as f32
+            } else {
+                0.0
+            };
+        };
+
+        let mut pmf = 1.0 - self.p_infinite();
+        let mut node_index = 0;
+        loop {
+            let node = &self.nodes[node_index];
+            if node.is_leaf {
+                if node_index == 0 && node.light_bounds.importance(ctx.p, ctx.n) == 0.0 {
+                    return 0.0;
+                }
+                return pmf;
+            }
+            let (c0, c1) = self.child_importance(node_index, ctx.p, ctx.n);
+            if c0 + c1 == 0.0 {
+                return 0.0;
+            }
+            if bit_trail & 1 == 0 {
+                pmf *= c0 / (c0 + c1);
+                node_index += 1;
+            } else {
+                pmf *= c1 / (c0 + c1);
+                node_index = node.index;
+            }
+            bit_trail >>= 1;
+        }
+    }
+}
+
+fn axis(v: crate::core::geometry::Vector3, dim: usize) -> f32 {
+    match dim {
+        0 => v.x,
+        1 => v.y,
+        _ => v.z,
+    }
+}
+
+// Surface-area-orientation heuristic cost of a light cluster (pbrt-v4):
+// power * solid angle measure of the emission cone * area, with a penalty
+// for thin boxes split along their short axis.
+fn evaluate_cost(b: &LightBounds, bounds: &Bounds3, dim: usize) -> f32 {
+    let theta_o = b.cos_theta_o.clamp(-1.0, 1.0).acos();
+    let theta_e = b.cos_theta_e.clamp(-1.0, 1.0).acos();
+    let theta_w = (theta_o + theta_e).min(PI);
+    let sin_theta_o = (1.0 - b.cos_theta_o * b.cos_theta_o).max(0.0).sqrt();
+    let m_omega = 2.0 * PI * (1.0 - b.cos_theta_o)
+        + PI / 2.0
+            * (2.0 * theta_w * sin_theta_o - (theta_o - 2.0 * theta_w).cos()
+                - 2.0 * theta_o * sin_theta_o
+                + b.cos_theta_o);
+
+    let d = bounds.diagonal();
+    let kr = d.x.max(d.y).max(d.z) / axis(d, dim);
+    b.phi * m_omega * kr * b.bounds.surface_area()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::core::geometry::{Point2, Vector3};
+    use crate::core::light::DiffuseAreaLight;
+    use crate::core::math::RNG;
+    use crate::core::spectrum::SampledSpectrum;
+    use crate::core::texture::ConstantTexture;
+    use crate::shapes::sphere::Sphere;
+
+    fn sphere_lights(spheres: impl Iterator<Item = (Point3, f32, f32)>) -> Vec<Box<dyn Light>> {
+        spheres
+            .map(|(center, radius, l)| {
+                let shape = Arc::new(Sphere::new(center, radius));
+                let emit = Arc::new(ConstantTexture::new(SampledSpectrum::new(l)));
+                Box::new(DiffuseAreaLight::from_texture(shape, emit, false)) as Box<dyn Light>
+            })
+            .collect()
+    }
+
+    fn reference(p: Point3) -> Interaction {
+        let zero = Vector3::new(0.0, 0.0, 0.0);
+        Interaction { p, time: 0.0, p_error: zero, wo: zero, n: Normal3 { x: 0.0, y: 0.0, z: 0.0 }, uv: Point2 { x: 0.0, y: 0.0 } }
+    }
+
+    // Relative frequency with which `sample` picks each light, against `pmf`
+    fn check_pmf_matches_sampling(sampler: &dyn LightSampler, n_lights: usize, ctx: &Interaction) {
+        const N: usize = 200_000;
+        let mut counts = vec![0usize; n_lights];
+        for i in 0..N {
+            let u = (i as f32 + 0.5) / N as f32;
+            let sampled = sampler.sample(ctx, u).unwrap();
+            let pmf = sampler.pmf(ctx, sampled.light);
+            assert!((sampled.p - pmf).abs() <= 1e-3 * pmf, "light {}: sampled p {} vs pmf {}", sampled.light, sampled.p, pmf);
+            counts[sampled.light] += 1;
+        }
+        let total: f32 = (0..n_lights).map(|l| sampler.pmf(ctx, l)).sum();
+        assert!((total - 1.0).abs() < 1e-4, "pmf sums to {}", total);
+        for (light, &count) in counts.iter().enumerate() {
+            let (frequency, pmf) = (count as f32 / N as f32, sampler.pmf(ctx, light));
+            assert!((frequency - pmf).abs() < 1e-3 + 0.01 * pmf, "light {}: frequency {} vs pmf {}", light, frequency, pmf);
+        }
+    }
+
+    #[test]
+    fn bvh_pmf_matches_sampling_frequency() {
+        let mut rng = RNG::new(3, 0);
+        let mut next = || rng.next_f32();
+        let lights = sphere_lights((0..40).map(|_| (Point3::new(next() * 10.0, next() * 10.0, next() * 10.0), 0.1 + next(), next())));
+        let sampler = BVHLightSampler::new(&lights);
+        for p in [Point3::new(5.0, 5.0, 5.0), Point3::new(-3.0, 12.0, 1.0)] {
+            check_pmf_matches_sampling(&sampler, lights.len(), &reference(p));
+        }
+    }
+
+    // Spheres growing geometrically in size and distance make the bucket
+    // splits peel off one or two lights at a time, 66 levels deep
+    #[test]
+    fn lopsided_bvh_stays_within_the_bit_trail() {
+        let lights = sphere_lights((0..130).map(|i| (Point3::new(1.4f32.powi(i), 0.0, 0.0), 0.25 * 1.4f32.powi(i), 1.0)));
+        let sampler = BVHLightSampler::new(&lights);
+        check_pmf_matches_sampling(&sampler, lights.len(), &reference(Point3::new(0.0, 1.0, 0.0)));
+    }
+}
@@ -1,27 +1,53 @@
+use std::io;
 use std::sync::Arc;
 use crate::core::interaction::SurfaceInteraction;
-use crate::core::bsdf::{BSDF, BxDF, DiffuseBxDF, MicrofacetReflection, FresnelBlend, FresnelDielectric};
-use crate::core::texture::Texture;
-use crate::core::spectrum::SampledSpectrum;
+use crate::core::bsdf::{BSDF, BxDF, DiffuseBxDF, DielectricBxDF, MicrofacetReflection, FresnelConductor, LayeredBxDF, OrenNayarBxDF, PrincipledBxDF, HairBxDF};
+use crate::core::texture::{ConstantTexture, Texture};
+use crate::core::spectrum::{SampledSpectrum, SampledWavelengths, Spectrum};
+use crate::core::light::AreaPower;
+use crate::core::metals::metal_ior;
+use crate::core::reflection::conductor_ior_from_edge_tint;
 use crate::core::geometry::Vector3;
 use crate::core::microfacet::TrowbridgeReitzDistribution;
-use crate::core::bsdf::Fresnel; // For trait types
+use crate::core::measured::{MeasuredBRDF, MeasuredBxDF};
+use crate::core::bssrdf::{BSSRDFTable, TabulatedBSSRDF};
+use crate::core::math::hash_floats;
 
 // The Material Trait: Determines how light interacts with the surface
 pub trait Material: Send + Sync {
-    // 1. Scattering: Creates the BSDF (BRDF/BTDF) for the hit point
-    fn compute_scattering(&self, si: &SurfaceInteraction) -> Option<BSDF>;
+    // 1. Scattering: Creates the BSDF (BRDF/BTDF) for the hit point at the
+    //    path's sampled wavelengths
+    fn compute_scattering(&self, si: &SurfaceInteraction, lambda: &SampledWavelengths) -> Option<BSDF>;
     
     // 2. Emission: Does this material emit light? (Le)
     fn emitted(&self, _si: &SurfaceInteraction) -> SampledSpectrum {
         SampledSpectrum::new(0.0)
     }
+
+    // 3. Area light description, used by the scene builder to turn emissive meshes into lights
+    fn emission(&self) -> Option<Emission> {
+        None
+    }
+
+    // 4. Subsurface scattering: light the BSDF transmits re-emerges elsewhere
+    //    on surfaces of this material (None = the BSDF is the whole story)
+    fn bssrdf(&self, _si: &SurfaceInteraction, _lambda: &SampledWavelengths) -> Option<TabulatedBSSRDF> {
+        None
+    }
+}
+
+// Emission parameters of a light-emitting material
+pub struct Emission {
+    pub le: Arc<dyn Texture>,
+    pub two_sided: bool,
+    pub spectrum: Option<Spectrum>,  // Emission spectrum (texture only when None)
+    pub power: Option<AreaPower>,   // Physical power of the whole emitter
 }
 
-// --- Matte Material (Lambertian) ---
+// --- Matte Material (Lambertian / Oren-Nayar) ---
 pub struct MatteMaterial {
     pub kd: Arc<dyn Texture>, // Diffuse Reflectance (Texture)
-    pub sigma: Arc<dyn Texture>, // Roughness (unused in basic matte)
+    pub sigma: Arc<dyn Texture>, // Roughness: facet slope std. deviation in degrees (0 = Lambertian)
 }
 
 impl MatteMaterial {
@@ -31,43 +57,281 @@ impl MatteMaterial {
 }
 
 impl Material for MatteMaterial {
-    fn compute_scattering(&self, si: &SurfaceInteraction) -> Option<BSDF> {
+    fn compute_scattering(&self, si: &SurfaceInteraction, _lambda: &SampledWavelengths) -> Option<BSDF> {
         // Evaluate textures at the hit point
         let r = self.kd.evaluate(si);
         
+        let sigma = self.sigma.evaluate(si).values[0];
+
         // Create the BSDF
-        let bxdf = BxDF::Diffuse(DiffuseBxDF::new(r));
+        let bxdf = if sigma > 0.0 {
+            BxDF::OrenNayar(OrenNayarBxDF::new(r, sigma))
+        } else {
+            BxDF::Diffuse(DiffuseBxDF::new(r))
+        };
         Some(BSDF::new(Vector3::from(si.shading.n), bxdf))
     }
 }
 
 // --- Emissive Material (Light Source) ---
+// Without a spectrum or power, the texture is the emitted radiance as-is.
 pub struct EmissiveMaterial {
     pub emit: Arc<dyn Texture>,
+    pub two_sided: bool, // Emit from both faces (default: front face only)
+    pub spectrum: Option<Spectrum>,
+    pub power: Option<AreaPower>,
 }
 
 impl EmissiveMaterial {
     pub fn new(emit: Arc<dyn Texture>) -> Self {
-        EmissiveMaterial { emit }
+        EmissiveMaterial { emit, two_sided: false, spectrum: None, power: None }
+    }
+
+    pub fn with_two_sided(mut self, two_sided: bool) -> Self {
+        self.two_sided = two_sided;
+        self
+    }
+
+    /// Emission spectrum (e.g. a blackbody or `Spectrum::named`), tinted by the texture
+    pub fn with_spectrum(mut self, spectrum: Spectrum) -> Self {
+        self.spectrum = Some(spectrum);
+        self
+    }
+
+    /// Physical power of the emitter; fluxes are shared across a mesh by area
+    pub fn with_power(mut self, power: AreaPower) -> Self {
+        self.power = Some(power);
+        self
     }
 }
 
 impl Material for EmissiveMaterial {
-    fn compute_scattering(&self, _si: &SurfaceInteraction) -> Option<BSDF> {
+    fn compute_scattering(&self, _si: &SurfaceInteraction, _lambda: &SampledWavelengths) -> Option<BSDF> {
         // Lights don't scatter, they just emit.
         None 
     }
 
     fn emitted(&self, si: &SurfaceInteraction) -> SampledSpectrum {
+        // One-sided emitters are dark when seen from behind
+        if !self.two_sided && Vector3::from(si.core.n).dot(si.core.wo) < 0.0 {
+            return SampledSpectrum::new(0.0);
+        }
         self.emit.evaluate(si)
     }
+
+    fn emission(&self) -> Option<Emission> {
+        Some(Emission {
+            le: self.emit.clone(),
+            two_sided: self.two_sided,
+            spectrum: self.spectrum.clone(),
+            power: self.power,
+        })
+    }
+}
+
+// --- Dielectric Material (Glass / Water / Gems) ---
+pub struct DielectricMaterial {
+    pub eta: Arc<dyn Texture>,       // Index of refraction (inside / outside)
+    pub roughness: Arc<dyn Texture>, // 0.0 = Perfectly smooth (delta lobes)
+}
+
+impl DielectricMaterial {
+    pub fn new(eta: Arc<dyn Texture>, roughness: Arc<dyn Texture>) -> Self {
+        Self { eta, roughness }
+    }
+}
+
+impl Material for DielectricMaterial {
+    fn compute_scattering(&self, si: &SurfaceInteraction, _lambda: &SampledWavelengths) -> Option<BSDF> {
+        let eta = self.eta.evaluate(si).values[0]; // Assume grayscale (no dispersion)
+        let roughness = self.roughness.evaluate(si).values[0];
+        let distribution = TrowbridgeReitzDistribution::new(roughness, roughness);
+
+        let bxdf = BxDF::Dielectric(DielectricBxDF::new(eta, distribution).with_energy_compensation());
+        Some(BSDF::new(Vector3::from(si.shading.n), bxdf))
+    }
+}
+
+// --- Conductor Material (Metals) ---
+// Complex IOR (eta + i k) evaluated at the path's wavelengths
+pub enum ConductorIor {
+    Spectral { eta: Spectrum, k: Spectrum },
+    // Artist-friendly: normal-incidence color and grazing-angle tint
+    EdgeTint { reflectance: Arc<dyn Texture>, edge_tint: Arc<dyn Texture> },
+}
+
+pub struct ConductorMaterial {
+    pub ior: ConductorIor,
+    pub roughness_u: Arc<dyn Texture>, // Roughness along dpdu
+    pub roughness_v: Arc<dyn Texture>, // Roughness along dpdv
+}
+
+impl ConductorMaterial {
+    pub fn new(eta: Spectrum, k: Spectrum, roughness: Arc<dyn Texture>) -> Self {
+        Self { ior: ConductorIor::Spectral { eta, k }, roughness_u: roughness.clone(), roughness_v: roughness }
+    }
+
+    // Measured metal from the built-in library ("Au", "Ag", "Cu", "Al", "Cr", "Ti", "Fe")
+    pub fn named(metal: &str, roughness: Arc<dyn Texture>) -> Option<Self> {
+        let (eta, k) = metal_ior(metal)?;
+        Some(Self::new(eta, k, roughness))
+    }
+
+    pub fn from_edge_tint(reflectance: Arc<dyn Texture>, edge_tint: Arc<dyn Texture>, roughness: Arc<dyn Texture>) -> Self {
+        Self {
+            ior: ConductorIor::EdgeTint { reflectance, edge_tint },
+            roughness_u: roughness.clone(),
+            roughness_v: roughness,
+        }
+    }
+
+    pub fn with_anisotropic_roughness(mut self, roughness_u: Arc<dyn Texture>, roughness_v: Arc<dyn Texture>) -> Self {
+        self.roughness_u = roughness_u;
+        self.roughness_v = roughness_v;
+        self
+    }
+}
+
+impl ConductorMaterial {
+    fn bxdf(&self, si: &SurfaceInteraction, lambda: &SampledWavelengths) -> BxDF {
+        let (eta, k) = match &self.ior {
+            ConductorIor::Spectral { eta, k } => (eta.sample(lambda), k.sample(lambda)),
+            ConductorIor::EdgeTint { reflectance, edge_tint } => {
+                conductor_ior_from_edge_tint(reflectance.evaluate(si), edge_tint.evaluate(si))
+            }
+        };
+        let rough_u = self.roughness_u.evaluate(si).values[0];
+        let rough_v = self.roughness_v.evaluate(si).values[0];
+        let distribution = TrowbridgeReitzDistribution::new(rough_u, rough_v);
+
+        let fresnel = Box::new(FresnelConductor { eta, k });
+        let bxdf = MicrofacetReflection::new(SampledSpectrum::splat(1.0), distribution, fresnel);
+        BxDF::Microfacet(bxdf.with_energy_compensation())
+    }
+}
+
+impl Material for ConductorMaterial {
+    fn compute_scattering(&self, si: &SurfaceInteraction, lambda: &SampledWavelengths) -> Option<BSDF> {
+        let bxdf = self.bxdf(si, lambda);
+        Some(BSDF::with_tangent(Vector3::from(si.shading.n), si.shading.dpdu, bxdf))
+    }
+}
+
+// --- Coated Materials (Dielectric coat over a diffuse or metal base) ---
+// Parameters of the coat; the medium between coat and base is measured in
+// mean free paths, and a black albedo makes it a pure absorber.
+pub struct Coating {
+    pub eta: Arc<dyn Texture>,
+    pub roughness: Arc<dyn Texture>,
+    pub thickness: Arc<dyn Texture>,
+    pub albedo: Arc<dyn Texture>,
+    pub g: f32, // Henyey-Greenstein asymmetry of the medium
+    pub max_depth: usize,
+    pub n_samples: usize,
+}
+
+impl Coating {
+    pub fn new(roughness: Arc<dyn Texture>) -> Self {
+        Self {
+            eta: constant(1.5),
+            roughness,
+            thickness: constant(0.01),
+            albedo: constant(0.0),
+            g: 0.0,
+            max_depth: 10,
+            n_samples: 1,
+        }
+    }
+
+    pub fn with_eta(mut self, eta: Arc<dyn Texture>) -> Self {
+        self.eta = eta;
+        self
+    }
+
+    pub fn with_medium(mut self, thickness: Arc<dyn Texture>, albedo: Arc<dyn Texture>, g: f32) -> Self {
+        self.thickness = thickness;
+        self.albedo = albedo;
+        self.g = g;
+        self
+    }
+
+    /// Random-walk depth and number of walks per f / pdf estimate
+    pub fn with_walk(mut self, max_depth: usize, n_samples: usize) -> Self {
+        self.max_depth = max_depth;
+        self.n_samples = n_samples;
+        self
+    }
+
+    fn layered(&self, si: &SurfaceInteraction, bottom: BxDF) -> BxDF {
+        let roughness = self.roughness.evaluate(si).values[0];
+        let top = DielectricBxDF::new(self.eta.evaluate(si).values[0], TrowbridgeReitzDistribution::new(roughness, roughness));
+        let thickness = self.thickness.evaluate(si).values[0];
+        let layered = LayeredBxDF::new(top, bottom, thickness, self.albedo.evaluate(si), self.g);
+        BxDF::Layered(layered.with_walk(self.max_depth, self.n_samples))
+    }
+}
+
+pub struct CoatedDiffuseMaterial {
+    pub reflectance: Arc<dyn Texture>,
+    pub coating: Coating,
+}
+
+impl CoatedDiffuseMaterial {
+    pub fn new(reflectance: Arc<dyn Texture>, coating: Coating) -> Self {
+        Self { reflectance, coating }
+    }
+}
+
+impl Material for CoatedDiffuseMaterial {
+    fn compute_scattering(&self, si: &SurfaceInteraction, _lambda: &SampledWavelengths) -> Option<BSDF> {
+        let bottom = BxDF::Diffuse(DiffuseBxDF::new(self.reflectance.evaluate(si)));
+        Some(BSDF::new(Vector3::from(si.shading.n), self.coating.layered(si, bottom)))
+    }
 }
 
-// --- Principled PBR Material (Metalness workflow) ---
+pub struct CoatedConductorMaterial {
+    pub conductor: ConductorMaterial,
+    pub coating: Coating,
+}
+
+impl CoatedConductorMaterial {
+    pub fn new(conductor: ConductorMaterial, coating: Coating) -> Self {
+        Self { conductor, coating }
+    }
+}
+
+impl Material for CoatedConductorMaterial {
+    fn compute_scattering(&self, si: &SurfaceInteraction, lambda: &SampledWavelengths) -> Option<BSDF> {
+        let bottom = self.conductor.bxdf(si, lambda);
+        let bxdf = self.coating.layered(si, bottom);
+        Some(BSDF::with_tangent(Vector3::from(si.shading.n), si.shading.dpdu, bxdf))
+    }
+}
+
+// --- Principled PBR Material (Disney / OpenPBR-style parameters) ---
+// Metalness workflow; every parameter is a grayscale texture in [0, 1]
+// unless noted, with defaults that give a plain plastic.
 pub struct PrincipledMaterial {
     pub base_color: Arc<dyn Texture>,
     pub metallic: Arc<dyn Texture>, // 0.0 = Dielectric, 1.0 = Metal
     pub roughness: Arc<dyn Texture>,
+    pub specular: Arc<dyn Texture>, // Dielectric reflection weight (default 1)
+    pub specular_tint: Arc<dyn Texture>,
+    pub anisotropic: Arc<dyn Texture>, // Stretches highlights along dpdu
+    pub sheen: Arc<dyn Texture>,
+    pub sheen_tint: Arc<dyn Texture>,
+    pub clearcoat: Arc<dyn Texture>,
+    pub clearcoat_gloss: Arc<dyn Texture>,
+    pub transmission: Arc<dyn Texture>,
+    pub ior: Arc<dyn Texture>, // Index of refraction (default 1.5)
+    pub subsurface: Arc<dyn Texture>,
+    pub thin_walled: bool,
+    pub diffuse_transmission: Arc<dyn Texture>, // Thin-walled only
+    pub emission: Option<Arc<dyn Texture>>,     // Emitted radiance
+}
+
+fn constant(value: f32) -> Arc<dyn Texture> {
+    Arc::new(ConstantTexture::new(SampledSpectrum::new(value)))
 }
 
 impl PrincipledMaterial {
@@ -76,52 +340,408 @@ impl PrincipledMaterial {
         metallic: Arc<dyn Texture>,
         roughness: Arc<dyn Texture>,
     ) -> Self {
-        Self { base_color, metallic, roughness }
+        Self {
+            base_color,
+            metallic,
+            roughness,
+            specular: constant(1.0),
+            specular_tint: constant(0.0),
+            anisotropic: constant(0.0),
+            sheen: constant(0.0),
+            sheen_tint: constant(0.5),
+            clearcoat: constant(0.0),
+            clearcoat_gloss: constant(1.0),
+            transmission: constant(0.0),
+            ior: constant(1.5),
+            subsurface: constant(0.0),
+            thin_walled: false,
+            diffuse_transmission: constant(0.0),
+            emission: None,
+        }
+    }
+
+    pub fn with_specular(mut self, specular: Arc<dyn Texture>, specular_tint: Arc<dyn Texture>) -> Self {
+        self.specular = specular;
+        self.specular_tint = specular_tint;
+        self
     }
-}
 
-fn lerp_spec(a: SampledSpectrum, b: SampledSpectrum, t: f32) -> SampledSpectrum {
-    a * (1.0 - t) + b * t
+    pub fn with_anisotropic(mut self, anisotropic: Arc<dyn Texture>) -> Self {
+        self.anisotropic = anisotropic;
+        self
+    }
+
+    pub fn with_sheen(mut self, sheen: Arc<dyn Texture>, sheen_tint: Arc<dyn Texture>) -> Self {
+        self.sheen = sheen;
+        self.sheen_tint = sheen_tint;
+        self
+    }
+
+    pub fn with_clearcoat(mut self, clearcoat: Arc<dyn Texture>, clearcoat_gloss: Arc<dyn Texture>) -> Self {
+        self.clearcoat = clearcoat;
+        self.clearcoat_gloss = clearcoat_gloss;
+        self
+    }
+
+    pub fn with_transmission(mut self, transmission: Arc<dyn Texture>, ior: Arc<dyn Texture>) -> Self {
+        self.transmission = transmission;
+        self.ior = ior;
+        self
+    }
+
+    pub fn with_subsurface(mut self, subsurface: Arc<dyn Texture>) -> Self {
+        self.subsurface = subsurface;
+        self
+    }
+
+    /// Thin-walled surfaces (leaves, paper, soap films) have no inside:
+    /// transmission passes straight through and part of the diffuse goes to the back
+    pub fn with_thin_walled(mut self, diffuse_transmission: Arc<dyn Texture>) -> Self {
+        self.thin_walled = true;
+        self.diffuse_transmission = diffuse_transmission;
+        self
+    }
+
+    pub fn with_emission(mut self, emission: Arc<dyn Texture>) -> Self {
+        self.emission = Some(emission);
+        self
+    }
 }
 
 impl Material for PrincipledMaterial {
-    fn compute_scattering(&self, si: &SurfaceInteraction) -> Option<BSDF> {
-        // 1. Sample Textures
-        let base_color_val = self.base_color.evaluate(si);
-        let metallic_val = self.metallic.evaluate(si).values[0]; // Assume grayscale
-        let roughness_val = self.roughness.evaluate(si).values[0];
-
-        // 2. Remap Roughness (Perceptual -> Alpha)
-        let alpha = roughness_val * roughness_val;
-        let distribution = TrowbridgeReitzDistribution::new(alpha, alpha);
-
-        // 3. Determine F0 (Fresnel at normal incidence)
-        let f0_dielectric = SampledSpectrum::splat(0.04);
-        let f0 = lerp_spec(f0_dielectric, base_color_val, metallic_val);
-
-        // 4. Determine Diffuse Color
-        let diffuse_color = lerp_spec(base_color_val, SampledSpectrum::new(0.0), metallic_val);
-
-        // 5. Construct BxDF
-        let bxdf = if metallic_val > 0.5 {
-            // --- METAL (Conductor) ---
-            // Use Schlick-like Fresnel encoded by F0: approximate with FresnelDielectric by deriving eta.
-            // This is an approximation; ideally we'd use FresnelConductor with spectral eta/k.
-            // Solve approx: ((eta-1)/(eta+1))^2 = F0 => eta = (1 + sqrt(F0)) / (1 - sqrt(F0))
-            let avg = (f0.values[0] + f0.values[1] + f0.values[2]) / 3.0;
-            let sqrt_f0 = avg.max(0.0).sqrt();
-            let eta = if (1.0 - sqrt_f0).abs() < 1e-6 { 1e6 } else { (1.0 + sqrt_f0) / (1.0 - sqrt_f0) };
-            let fresnel = Box::new(FresnelDielectric { eta_i: 1.0, eta_t: eta as f32 });
-            // Use full specular microfacet with tint from base_color (approximate)
-            BxDF::Microfacet(MicrofacetReflection::new(f0, distribution, fresnel))
-        } else {
-            // --- PLASTIC / DIELECTRIC ---
-            let fresnel = Box::new(FresnelDielectric { eta_i: 1.0, eta_t: 1.5 }); // IOR 1.5 standard
-            let spec = MicrofacetReflection::new(SampledSpectrum::splat(1.0), distribution, fresnel);
-            let diff = DiffuseBxDF::new(diffuse_color);
-            BxDF::FresnelBlend(FresnelBlend::new(diff, spec))
+    fn compute_scattering(&self, si: &SurfaceInteraction, _lambda: &SampledWavelengths) -> Option<BSDF> {
+        let scalar = |t: &Arc<dyn Texture>| t.evaluate(si).values[0]; // Assume grayscale
+        let roughness = scalar(&self.roughness);
+
+        // Anisotropy keeps the roughness^2 area of the highlight (Disney 2012)
+        let aspect = (1.0 - 0.9 * scalar(&self.anisotropic)).sqrt();
+        let distribution = TrowbridgeReitzDistribution::new(roughness / aspect.sqrt(), roughness * aspect.sqrt());
+
+        let mut bxdf = PrincipledBxDF::new(self.base_color.evaluate(si), scalar(&self.metallic), roughness, distribution);
+        bxdf.specular = scalar(&self.specular);
+        bxdf.specular_tint = scalar(&self.specular_tint);
+        bxdf.sheen = scalar(&self.sheen);
+        bxdf.sheen_tint = scalar(&self.sheen_tint);
+        bxdf.clearcoat = scalar(&self.clearcoat);
+        bxdf.clearcoat_gloss = scalar(&self.clearcoat_gloss);
+        bxdf.transmission = scalar(&self.transmission);
+        bxdf.eta = scalar(&self.ior);
+        bxdf.subsurface = scalar(&self.subsurface);
+        bxdf.thin = self.thin_walled;
+        bxdf.diffuse_transmission = scalar(&self.diffuse_transmission);
+
+        Some(BSDF::with_tangent(Vector3::from(si.shading.n), si.shading.dpdu, BxDF::Principled(bxdf)))
+    }
+
+    fn emitted(&self, si: &SurfaceInteraction) -> SampledSpectrum {
+        match &self.emission {
+            // Front face only, like a one-sided emissive material
+            Some(e) if Vector3::from(si.core.n).dot(si.core.wo) >= 0.0 => e.evaluate(si),
+            _ => SampledSpectrum::new(0.0),
+        }
+    }
+
+    fn emission(&self) -> Option<Emission> {
+        Some(Emission { le: self.emission.clone()?, two_sided: false, spectrum: None, power: None })
+    }
+}
+
+// --- Hair Material (Chiang et al. hair BSDF on curves) ---
+// Pigmentation sets the absorption inside the fiber; roughness values are
+// in [0, 1]. Meant for `CurveType::Cylinder` curves, whose v coordinate
+// gives the offset across the fiber.
+pub enum HairAbsorption {
+    // Eumelanin (brown-black) and pheomelanin (red) concentrations
+    Melanin { eumelanin: Arc<dyn Texture>, pheomelanin: Arc<dyn Texture> },
+    // Absorption coefficient per unit radius
+    SigmaA(Arc<dyn Texture>),
+    // Approximate color of the hair after multiple scattering
+    Reflectance(Arc<dyn Texture>),
+}
+
+pub struct HairMaterial {
+    pub absorption: HairAbsorption,
+    pub eta: f32,
+    pub beta_m: Arc<dyn Texture>, // Longitudinal roughness
+    pub beta_n: Arc<dyn Texture>, // Azimuthal roughness
+    pub alpha: f32,               // Cuticle scale tilt in degrees
+}
+
+impl HairMaterial {
+    pub fn new(absorption: HairAbsorption) -> Self {
+        Self { absorption, eta: 1.55, beta_m: constant(0.3), beta_n: constant(0.3), alpha: 2.0 }
+    }
+
+    pub fn melanin(eumelanin: Arc<dyn Texture>, pheomelanin: Arc<dyn Texture>) -> Self {
+        Self::new(HairAbsorption::Melanin { eumelanin, pheomelanin })
+    }
+
+    pub fn with_roughness(mut self, beta_m: Arc<dyn Texture>, beta_n: Arc<dyn Texture>) -> Self {
+        self.beta_m = beta_m;
+        self.beta_n = beta_n;
+        self
+    }
+
+    pub fn with_scale_angle(mut self, alpha: f32) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn with_eta(mut self, eta: f32) -> Self {
+        self.eta = eta;
+        self
+    }
+}
+
+impl Material for HairMaterial {
+    fn compute_scattering(&self, si: &SurfaceInteraction, lambda: &SampledWavelengths) -> Option<BSDF> {
+        let beta_m = self.beta_m.evaluate(si).values[0].clamp(0.0, 1.0);
+        let beta_n = self.beta_n.evaluate(si).values[0].clamp(0.0, 1.0);
+        let sigma_a = match &self.absorption {
+            HairAbsorption::Melanin { eumelanin, pheomelanin } => HairBxDF::sigma_a_from_concentration(
+                eumelanin.evaluate(si).values[0].max(0.0),
+                pheomelanin.evaluate(si).values[0].max(0.0),
+                &lambda.lambda,
+            ),
+            HairAbsorption::SigmaA(sigma_a) => sigma_a.evaluate(si),
+            HairAbsorption::Reflectance(c) => HairBxDF::sigma_a_from_reflectance(c.evaluate(si), beta_n),
         };
 
+        let h = -1.0 + 2.0 * si.core.uv.y;
+        let bxdf = HairBxDF::new(h, self.eta, sigma_a, beta_m, beta_n, self.alpha);
+        Some(BSDF::with_tangent(Vector3::from(si.shading.n), si.shading.dpdu, BxDF::Hair(bxdf)))
+    }
+}
+
+// --- Measured Material ---
+// Tabulated BRDF from a MERL .binary or RGL .bsdf file (see core::measured);
+// the data is shared by every surface using it
+pub struct MeasuredMaterial {
+    pub brdf: Arc<MeasuredBRDF>,
+}
+
+impl MeasuredMaterial {
+    pub fn new(brdf: Arc<MeasuredBRDF>) -> Self {
+        Self { brdf }
+    }
+
+    pub fn read(filename: &str) -> io::Result<Self> {
+        Ok(Self::new(Arc::new(MeasuredBRDF::read(filename)?)))
+    }
+}
+
+impl Material for MeasuredMaterial {
+    fn compute_scattering(&self, si: &SurfaceInteraction, lambda: &SampledWavelengths) -> Option<BSDF> {
+        let bxdf = MeasuredBxDF::new(self.brdf.clone(), *lambda);
+        Some(BSDF::with_tangent(Vector3::from(si.shading.n), si.shading.dpdu, BxDF::Measured(bxdf)))
+    }
+}
+
+// --- Subsurface Material (Skin / Marble / Wax / Milk) ---
+// Dielectric boundary over a scattering medium, rendered with a tabulated
+// photon beam diffusion BSSRDF: light refracted into the surface exits at
+// another point of the same material. Surfaces must be closed and share
+// one SubsurfaceMaterial (the probe rays look for exits by material).
+pub enum SubsurfaceCoefficients {
+    // Absorption and scattering coefficients per unit length
+    Coefficients { sigma_a: Arc<dyn Texture>, sigma_s: Arc<dyn Texture> },
+    // Diffuse reflectance color and mean free path (scene units)
+    Reflectance { reflectance: Arc<dyn Texture>, mfp: Arc<dyn Texture> },
+}
+
+pub struct SubsurfaceMaterial {
+    pub coefficients: SubsurfaceCoefficients,
+    pub scale: f32,                  // Multiplies sigma_a / sigma_s, divides mfp
+    pub roughness: Arc<dyn Texture>, // Boundary roughness
+    eta: f32,
+    g: f32,
+    table: Arc<BSSRDFTable>,
+}
+
+impl SubsurfaceMaterial {
+    pub fn new(coefficients: SubsurfaceCoefficients) -> Self {
+        let (eta, g) = (1.33, 0.0);
+        Self { coefficients, scale: 1.0, roughness: constant(0.0), eta, g, table: Arc::new(BSSRDFTable::beam_diffusion(g, eta)) }
+    }
+
+    pub fn from_reflectance(reflectance: Arc<dyn Texture>, mfp: Arc<dyn Texture>) -> Self {
+        Self::new(SubsurfaceCoefficients::Reflectance { reflectance, mfp })
+    }
+
+    /// `scale` converts the coefficients' length unit to scene units
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        assert!(scale > 0.0, "SubsurfaceMaterial scale must be positive, got {}", scale);
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_roughness(mut self, roughness: Arc<dyn Texture>) -> Self {
+        self.roughness = roughness;
+        self
+    }
+
+    // IOR and phase asymmetry both shape the profile table, which is rebuilt
+    pub fn with_medium(mut self, eta: f32, g: f32) -> Self {
+        self.eta = eta;
+        self.g = g;
+        self.table = Arc::new(BSSRDFTable::beam_diffusion(g, eta));
+        self
+    }
+}
+
+impl Material for SubsurfaceMaterial {
+    fn compute_scattering(&self, si: &SurfaceInteraction, _lambda: &SampledWavelengths) -> Option<BSDF> {
+        let roughness = self.roughness.evaluate(si).values[0];
+        let distribution = TrowbridgeReitzDistribution::new(roughness, roughness);
+        let bxdf = BxDF::Dielectric(DielectricBxDF::new(self.eta, distribution));
         Some(BSDF::new(Vector3::from(si.shading.n), bxdf))
     }
+
+    fn bssrdf(&self, si: &SurfaceInteraction, _lambda: &SampledWavelengths) -> Option<TabulatedBSSRDF> {
+        let (sigma_a, sigma_s) = match &self.coefficients {
+            SubsurfaceCoefficients::Coefficients { sigma_a, sigma_s } => {
+                (sigma_a.evaluate(si) * self.scale, sigma_s.evaluate(si) * self.scale)
+            }
+            SubsurfaceCoefficients::Reflectance { reflectance, mfp } => {
+                self.table.subsurface_from_diffuse(reflectance.evaluate(si), mfp.evaluate(si) * (1.0 / self.scale))
+            }
+        };
+        let ns = Vector3::from(si.shading.n);
+        Some(TabulatedBSSRDF::new(si.core.p, ns, self.eta, sigma_a, sigma_s, self.table.clone()))
+    }
+}
+
+// --- Mix Material (Rust over Metal / Dirt over Paint) ---
+// Two materials blended by a texture amount (0 = first, 1 = second). By
+// default one of them is picked per shading point, with a hash of the
+// position and direction so that every query at the vertex (NEE, BSDF
+// sampling, MIS) sees the same choice. `with_lobe_sum` instead shades with
+// both BSDFs weighted by the amount (smoother, twice the cost); subsurface
+// scattering is only kept in the stochastic mode.
+pub struct MixMaterial {
+    pub materials: [Arc<dyn Material>; 2],
+    pub amount: Arc<dyn Texture>,
+    pub lobe_sum: bool,
+}
+
+impl MixMaterial {
+    pub fn new(first: Arc<dyn Material>, second: Arc<dyn Material>, amount: Arc<dyn Texture>) -> Self {
+        Self { materials: [first, second], amount, lobe_sum: false }
+    }
+
+    pub fn with_lobe_sum(mut self) -> Self {
+        self.lobe_sum = true;
+        self
+    }
+
+    fn amount(&self, si: &SurfaceInteraction) -> f32 {
+        self.amount.evaluate(si).values[0].clamp(0.0, 1.0)
+    }
+
+    /// Material used at this shading point in stochastic mode
+    pub fn choose(&self, si: &SurfaceInteraction) -> &Arc<dyn Material> {
+        let amount = self.amount(si);
+        if amount <= 0.0 {
+            return &self.materials[0];
+        } else if amount >= 1.0 {
+            return &self.materials[1];
+        }
+        let (p, wo) = (si.core.p, si.core.wo);
+        let u = hash_floats(&[p.x, p.y, p.z, wo.x, wo.y, wo.z]);
+        &self.materials[if u < amount { 1 } else { 0 }]
+    }
+}
+
+impl Material for MixMaterial {
+    fn compute_scattering(&self, si: &SurfaceInteraction, lambda: &SampledWavelengths) -> Option<BSDF> {
+        if !self.lobe_sum {
+            return self.choose(si).compute_scattering(si, lambda);
+        }
+        let amount = self.amount(si);
+        let weights = [1.0 - amount, amount];
+        let lobes: Vec<(f32, BSDF)> = self
+            .materials
+            .iter()
+            .zip(weights)
+            .filter(|(_, w)| *w > 0.0)
+            .filter_map(|(m, w)| Some((w, m.compute_scattering(si, lambda)?)))
+            .collect();
+        if lobes.is_empty() {
+            return None;
+        }
+        Some(BSDF::sum(lobes))
+    }
+
+    fn emitted(&self, si: &SurfaceInteraction) -> SampledSpectrum {
+        if !self.lobe_sum {
+            return self.choose(si).emitted(si);
+        }
+        let amount = self.amount(si);
+        self.materials[0].emitted(si) * (1.0 - amount) + self.materials[1].emitted(si) * amount
+    }
+
+    fn bssrdf(&self, si: &SurfaceInteraction, lambda: &SampledWavelengths) -> Option<TabulatedBSSRDF> {
+        if self.lobe_sum { None } else { self.choose(si).bssrdf(si, lambda) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::geometry::{Normal3, Point2, Point3};
+
+    fn constant(v: f32) -> Arc<dyn Texture> {
+        Arc::new(ConstantTexture::new(SampledSpectrum::new(v)))
+    }
+
+    fn mix(amount: f32) -> MixMaterial {
+        let first: Arc<dyn Material> = Arc::new(MatteMaterial::new(constant(0.2), constant(0.0)));
+        let second: Arc<dyn Material> = Arc::new(MatteMaterial::new(constant(0.8), constant(0.0)));
+        MixMaterial::new(first, second, constant(amount))
+    }
+
+    // Shading point i of a grid on the z = 0 plane, seen from above
+    fn shading_point(i: usize) -> SurfaceInteraction {
+        let p = Point3::new((i % 100) as f32 * 0.013, (i / 100) as f32 * 0.017, 0.0);
+        let wo = Vector3::new(0.3, -0.2, 1.0).normalize();
+        SurfaceInteraction::new(p, Vector3::new(0.0, 0.0, 0.0), Point2 { x: 0.0, y: 0.0 }, wo, Normal3 { x: 0.0, y: 0.0, z: 1.0 }, 0.0)
+    }
+
+    #[test]
+    fn choose_is_deterministic_and_follows_amount() {
+        let material = mix(0.3);
+        let mut second = 0;
+        for i in 0..10_000 {
+            let si = shading_point(i);
+            let chosen = material.choose(&si);
+            assert!(Arc::ptr_eq(chosen, material.choose(&si)), "point {} changed material", i);
+            if Arc::ptr_eq(chosen, &material.materials[1]) {
+                second += 1;
+            }
+        }
+        let frequency = second as f32 / 10_000.0;
+        assert!((frequency - 0.3).abs() < 0.02, "second material chosen {} of the time", frequency);
+    }
+
+    #[test]
+    fn choose_saturates_at_the_ends() {
+        for (amount, index) in [(0.0, 0), (-0.5, 0), (1.0, 1), (1.5, 1)] {
+            let material = mix(amount);
+            for i in 0..1000 {
+                assert!(Arc::ptr_eq(material.choose(&shading_point(i)), &material.materials[index]), "amount {}", amount);
+            }
+        }
+    }
+
+    #[test]
+    fn lobe_sum_weights_both_materials() {
+        let material = mix(0.3).with_lobe_sum();
+        let lambda = SampledWavelengths::sample_uniform(0.5);
+        let si = shading_point(0);
+        let bsdf = material.compute_scattering(&si, &lambda).unwrap();
+        let (wo, wi) = (si.core.wo, Vector3::new(-0.4, 0.1, 1.0).normalize());
+        let f = bsdf.f(wo, wi).values[0];
+        let expected = (0.7 * 0.2 + 0.3 * 0.8) / std::f32::consts::PI;
+        assert!((f - expected).abs() < 1e-5, "{} vs {}", f, expected);
+    }
 }
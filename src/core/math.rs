@@ -1,142 +1,8 @@
 use std::f32;
 
-use crate::core::geometry::Point2;
+use crate::core::geometry::{Point2, Point3, Vector3};
 use std::f32::consts::PI;
 
-/// A mathematical interval [low, high] that guarantees the true value is inside.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Interval {
-    pub min: f32,
-    pub max: f32,
-}
-
-impl Interval {
-    // Exact value (thin interval)
-    pub fn new(v: f32) -> Self {
-        Interval { min: v, max: v }
-    }
-
-    // Interval with explicit error margin
-    pub fn with_error(v: f32, error: f32) -> Self {
-        Interval {
-            min: next_float_down(v - error),
-            max: next_float_up(v + error),
-        }
-    }
-
-    // Merge two intervals (union)
-    pub fn union(self, other: Interval) -> Self {
-        Interval {
-            min: self.min.min(other.min),
-            max: self.max.max(other.max),
-        }
-    }
-}
-
-// --- Arithmetic Overloads for Interval Propagation ---
-
-use std::ops::{Add, Mul, Sub};
-
-impl Add for Interval {
-    type Output = Interval;
-    fn add(self, rhs: Interval) -> Interval {
-        Interval {
-            min: next_float_down(self.min + rhs.min),
-            max: next_float_up(self.max + rhs.max),
-        }
-    }
-}
-
-impl Sub for Interval {
-    type Output = Interval;
-    fn sub(self, rhs: Interval) -> Interval {
-        Interval {
-            min: next_float_down(self.min - rhs.max),
-            max: next_float_up(self.max - rhs.min),
-        }
-    }
-}
-
-impl Mul for Interval {
-    type Output = Interval;
-    fn mul(self, rhs: Interval) -> Interval {
-        let p = [
-            self.min * rhs.min,
-            self.min * rhs.max,
-            self.max * rhs.min,
-            self.max * rhs.max,
-        ];
-
-        let min_val = p.iter().fold(f32::INFINITY, |a, &b| a.min(b));
-        let max_val = p.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
-
-        Interval {
-            min: next_float_down(min_val),
-            max: next_float_up(max_val),
-        }
-    }
-}
-
-// --- The "Dark Arts": Bitwise Float Manipulation ---
-
-pub fn next_float_up(v: f32) -> f32 {
-    if v.is_infinite() && v > 0.0 {
-        return v;
-    }
-    if v == -0.0 {
-        return 0.0;
-    }
-
-    let bits = v.to_bits();
-    if v >= 0.0 {
-        f32::from_bits(bits + 1)
-    } else {
-        f32::from_bits(bits - 1)
-    }
-}
-
-pub fn next_float_down(v: f32) -> f32 {
-    if v.is_infinite() && v < 0.0 {
-        return v;
-    }
-    if v == 0.0 {
-        return -0.0;
-    }
-
-    let bits = v.to_bits();
-    if v > 0.0 {
-        f32::from_bits(bits - 1)
-    } else {
-        f32::from_bits(bits + 1)
-    }
-}
-
-// --- Robust Quadratic Solver ---
-
-pub fn solve_quadratic(a: f32, b: f32, c: f32) -> Option<(f32, f32)> {
-    let discrim = (b as f64 * b as f64) - (4.0 * a as f64 * c as f64);
-    if discrim < 0.0 {
-        return None;
-    }
-
-    let root_discrim = discrim.sqrt() as f32;
-
-    let q = if b < 0.0 {
-        -0.5 * (b - root_discrim)
-    } else {
-        -0.5 * (b + root_discrim)
-    };
-
-    let t0 = q / a;
-    let t1 = c / q;
-
-    if t0 > t1 {
-        Some((t1, t0))
-    } else {
-        Some((t0, t1))
-    }
-}
-
 // --- Error-Free Arithmetic ---
 
 pub fn difference_of_products(a: f32, b: f32, c: f32, d: f32) -> f32 {
@@ -154,7 +20,7 @@ pub fn hash_float(x: f32, y: f32, z: f32) -> f32 {
     let iz = z.to_bits();
 
     let mut h = (ix ^ iy ^ iz).wrapping_mul(0xcc9e2d51);
-    h = (h << 13) | (h >> 19);
+    h = h.rotate_left(13);
     h = h.wrapping_mul(0x1b873593);
     h ^= h >> 16;
     h = h.wrapping_mul(0x85ebca6b);
@@ -165,6 +31,56 @@ pub fn hash_float(x: f32, y: f32, z: f32) -> f32 {
     (h as f32) / (u32::MAX as f32)
 }
 
+/// Order-sensitive hash of several floats to [0, 1] (unlike `hash_float`,
+/// permuted or equal coordinates do not collide)
+pub fn hash_floats(values: &[f32]) -> f32 {
+    let mut h: u32 = 0x9747b28c;
+    for v in values {
+        let mut k = v.to_bits().wrapping_mul(0xcc9e2d51);
+        k = k.rotate_left(15).wrapping_mul(0x1b873593);
+        h = (h ^ k).rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64);
+    }
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^= h >> 16;
+    (h as f32) / (u32::MAX as f32)
+}
+
+// --- Inverse Error Function ---
+// Polynomial approximation (Giles 2010), accurate to ~1e-7 on (-1, 1).
+// Used to turn a uniform sample into a Gaussian one.
+pub fn erf_inv(x: f32) -> f32 {
+    let x = x.clamp(-0.99999, 0.99999);
+    let mut w = -((1.0 - x) * (1.0 + x)).ln();
+    let mut p;
+    if w < 5.0 {
+        w -= 2.5;
+        p = 2.8102264e-8;
+        p = 3.4327394e-7 + p * w;
+        p = -3.5233877e-6 + p * w;
+        p = -4.3915065e-6 + p * w;
+        p = 0.00021858087 + p * w;
+        p = -0.001253725 + p * w;
+        p = -0.0041776816 + p * w;
+        p = 0.24664073 + p * w;
+        p = 1.5014094 + p * w;
+    } else {
+        w = w.sqrt() - 3.0;
+        p = -0.00020021426;
+        p = 0.00010095056 + p * w;
+        p = 0.0013493432 + p * w;
+        p = -0.0036734284 + p * w;
+        p = 0.0057395077 + p * w;
+        p = -0.007622461 + p * w;
+        p = 0.0094388705 + p * w;
+        p = 1.001674 + p * w;
+        p = 2.8329768 + p * w;
+    }
+    p * x
+}
+
 // --- Sampling Helpers ---
 
 /// Samples a point on a unit disk with uniform probability.
@@ -177,6 +93,47 @@ pub fn sample_uniform_disk_polar(u: Point2) -> Point2 {
     }
 }
 
+/// Cosine-weighted hemisphere sampling (Malley's method) around +Z.
+/// PDF is cos(theta) / PI.
+pub fn sample_cosine_hemisphere(u: Point2) -> Vector3 {
+    let d = sample_uniform_disk_polar(u);
+    let z = (1.0 - d.x * d.x - d.y * d.y).max(0.0).sqrt();
+    Vector3 { x: d.x, y: d.y, z }
+}
+
+/// Uniform direction on the unit sphere
+pub fn sample_uniform_sphere(u: Point2) -> Vector3 {
+    let z = 1.0 - 2.0 * u.x;
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    let phi = 2.0 * PI * u.y;
+    Vector3::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+pub fn uniform_sphere_pdf() -> f32 {
+    1.0 / (4.0 * PI)
+}
+
+/// Uniform direction in the cone of half-angle `acos(cos_theta_max)` around +z
+pub fn sample_uniform_cone(u: Point2, cos_theta_max: f32) -> Vector3 {
+    let cos_theta = (1.0 - u.x) + u.x * cos_theta_max;
+    let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+    let phi = 2.0 * PI * u.y;
+    Vector3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta)
+}
+
+pub fn uniform_cone_pdf(cos_theta_max: f32) -> f32 {
+    1.0 / (2.0 * PI * (1.0 - cos_theta_max))
+}
+
+/// Hermite smoothstep of `x` between `a` and `b`
+pub fn smooth_step(x: f32, a: f32, b: f32) -> f32 {
+    if a == b {
+        return if x < a { 0.0 } else { 1.0 };
+    }
+    let t = ((x - a) / (b - a)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
 /// **NEW — REQUIRED FOR AREA LIGHTS**
 /// Uniformly samples a triangle using area-preserving square-root warping.
 /// Returns barycentric coordinates (b0, b1); b2 = 1 - b0 - b1.
@@ -188,8 +145,90 @@ pub fn sample_uniform_triangle(u: Point2) -> Point2 {
     }
 }
 
+/// Solid angle of the spherical triangle with unit-vector vertices a, b, c
+pub fn spherical_triangle_area(a: Vector3, b: Vector3, c: Vector3) -> f32 {
+    (2.0 * a.dot(b.cross(c)).atan2(1.0 + a.dot(b) + a.dot(c) + b.dot(c))).abs()
+}
+
+// Numerically robust angle between two unit vectors
+fn angle_between(v1: Vector3, v2: Vector3) -> f32 {
+    if v1.dot(v2) < 0.0 {
+        PI - 2.0 * ((v1 + v2).length() / 2.0).clamp(-1.0, 1.0).asin()
+    } else {
+        2.0 * ((v2 - v1).length() / 2.0).clamp(-1.0, 1.0).asin()
+    }
+}
+
+// Component of v orthogonal to the unit vector w
+fn gram_schmidt(v: Vector3, w: Vector3) -> Vector3 {
+    v - w * v.dot(w)
+}
+
+/// Uniformly samples the solid angle subtended by triangle `v` as seen from
+/// `p` (Arvo's method). Returns the barycentrics of the point hit on the
+/// triangle and the solid angle pdf (1 / subtended area).
+pub fn sample_spherical_triangle(v: [Point3; 3], p: Point3, u: Point2) -> Option<([f32; 3], f32)> {
+    // Triangle vertices projected onto the unit sphere around p
+    let a = (v[0] - p).normalize();
+    let b = (v[1] - p).normalize();
+    let c = (v[2] - p).normalize();
+
+    // Normals of the great circles through each edge
+    let (n_ab, n_bc, n_ca) = (a.cross(b), b.cross(c), c.cross(a));
+    if n_ab.length_squared() == 0.0 || n_bc.length_squared() == 0.0 || n_ca.length_squared() == 0.0 {
+        return None;
+    }
+    let (n_ab, n_bc, n_ca) = (n_ab.normalize(), n_bc.normalize(), n_ca.normalize());
+
+    // Interior angles; their excess over pi is the solid angle
+    let alpha = angle_between(n_ab, -n_ca);
+    let beta = angle_between(n_bc, -n_ab);
+    let gamma = angle_between(n_ca, -n_bc);
+    let area = alpha + beta + gamma - PI;
+    if area <= 0.0 {
+        return None;
+    }
+
+    // Sub-triangle of area u.x * area determines the new vertex c' on arc ac
+    let ap_pi = PI + u.x * area;
+    let (sin_alpha, cos_alpha) = alpha.sin_cos();
+    let (sin_ap, cos_ap) = ap_pi.sin_cos();
+    let sin_phi = sin_ap * cos_alpha - cos_ap * sin_alpha;
+    let cos_phi = cos_ap * cos_alpha + sin_ap * sin_alpha;
+    let k1 = cos_phi + cos_alpha;
+    let k2 = sin_phi - sin_alpha * a.dot(b);
+    let cos_bp = ((k2 + difference_of_products(k2, cos_phi, k1, sin_phi) * cos_alpha)
+        / ((k2 * sin_phi + k1 * cos_phi) * sin_alpha))
+        .clamp(-1.0, 1.0);
+    let sin_bp = (1.0 - cos_bp * cos_bp).max(0.0).sqrt();
+    let cp = a * cos_bp + gram_schmidt(c, a).normalize() * sin_bp;
+
+    // Uniform point along the arc between b and c'
+    let cos_theta = 1.0 - u.y * (1.0 - cp.dot(b));
+    let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+    let w = b * cos_theta + gram_schmidt(cp, b).normalize() * sin_theta;
+
+    // Barycentrics where the ray p + t w hits the triangle
+    let (e1, e2) = (v[1] - v[0], v[2] - v[0]);
+    let s1 = w.cross(e2);
+    let divisor = s1.dot(e1);
+    if divisor == 0.0 {
+        return Some(([1.0 / 3.0; 3], 1.0 / area));
+    }
+    let s = p - v[0];
+    let mut b1 = (s.dot(s1) / divisor).clamp(0.0, 1.0);
+    let mut b2 = (w.dot(s.cross(e1)) / divisor).clamp(0.0, 1.0);
+    if b1 + b2 > 1.0 {
+        let sum = b1 + b2;
+        b1 /= sum;
+        b2 /= sum;
+    }
+    Some(([1.0 - b1 - b2, b1, b2], 1.0 / area))
+}
+
 // --- PCG32 Random Number Generator ---
 
+#[allow(clippy::upper_case_acronyms)]
 pub struct RNG {
     state: u64,
     inc: u64,
@@ -218,6 +257,551 @@ impl RNG {
     }
 
     pub fn next_f32(&mut self) -> f32 {
-        (self.next_u32() as f32) * 2.3283064365386963e-10
+        (self.next_u32() as f32) * 2.328_306_4e-10
+    }
+}
+
+// --- Piecewise-Constant 1D Distribution ---
+// Samples an index (or a point in [0,1)) proportionally to a tabulated function.
+pub struct Distribution1D {
+    pub func: Vec<f32>,
+    pub cdf: Vec<f32>,
+    pub func_int: f32,
+}
+
+impl Distribution1D {
+    pub fn new(f: &[f32]) -> Self {
+        let n = f.len();
+        let func: Vec<f32> = f.iter().map(|v| v.abs()).collect();
+
+        // Running integral, then normalize to a CDF
+        let mut cdf = vec![0.0; n + 1];
+        for i in 1..=n {
+            cdf[i] = cdf[i - 1] + func[i - 1] / n as f32;
+        }
+        let func_int = cdf[n];
+        if func_int == 0.0 {
+            // Degenerate: fall back to uniform
+            for (i, c) in cdf.iter_mut().enumerate() {
+                *c = i as f32 / n as f32;
+            }
+        } else {
+            for c in cdf.iter_mut() {
+                *c /= func_int;
+            }
+        }
+
+        Distribution1D { func, cdf, func_int }
+    }
+
+    pub fn count(&self) -> usize {
+        self.func.len()
+    }
+
+    // Index of the CDF segment containing u
+    fn find_interval(&self, u: f32) -> usize {
+        let first_above = self.cdf.partition_point(|&c| c <= u);
+        first_above.saturating_sub(1).min(self.count() - 1)
+    }
+
+    /// Returns (index, probability of that index).
+    pub fn sample_discrete(&self, u: f32) -> (usize, f32) {
+        let offset = self.find_interval(u);
+        (offset, self.discrete_pdf(offset))
+    }
+
+    pub fn discrete_pdf(&self, index: usize) -> f32 {
+        if self.func_int == 0.0 {
+            1.0 / self.count() as f32
+        } else {
+            self.func[index] / (self.func_int * self.count() as f32)
+        }
+    }
+
+    /// Returns (x in [0,1), density at x, segment index).
+    pub fn sample_continuous(&self, u: f32) -> (f32, f32, usize) {
+        let offset = self.find_interval(u);
+
+        // Position within the segment
+        let mut du = u - self.cdf[offset];
+        let width = self.cdf[offset + 1] - self.cdf[offset];
+        if width > 0.0 {
+            du /= width;
+        }
+        let x = ((offset as f32 + du) / self.count() as f32).min(1.0 - f32::EPSILON);
+        (x, self.pdf(x), offset)
+    }
+
+    /// Density of `sample_continuous` at x in [0,1)
+    pub fn pdf(&self, x: f32) -> f32 {
+        let n = self.count();
+        let offset = ((x * n as f32) as usize).min(n - 1);
+        if self.func_int == 0.0 { 1.0 } else { self.func[offset] / self.func_int }
+    }
+}
+
+// --- Piecewise-Constant 2D Distribution ---
+// Samples (u, v) in [0,1)^2 proportionally to an nu x nv table: v from the
+// marginal over rows, then u from the chosen row's conditional.
+pub struct Distribution2D {
+    conditional: Vec<Distribution1D>, // One per row (v)
+    marginal: Distribution1D,
+}
+
+impl Distribution2D {
+    /// `f` is row-major: f[v * nu + u]
+    pub fn new(f: &[f32], nu: usize, nv: usize) -> Self {
+        let conditional: Vec<Distribution1D> =
+            (0..nv).map(|v| Distribution1D::new(&f[v * nu..(v + 1) * nu])).collect();
+        let marginal_func: Vec<f32> = conditional.iter().map(|c| c.func_int).collect();
+        Distribution2D { conditional, marginal: Distribution1D::new(&marginal_func) }
+    }
+
+    /// Returns (sample point, density over [0,1)^2)
+    pub fn sample_continuous(&self, u: Point2) -> (Point2, f32) {
+        let (v, pdf_v, row) = self.marginal.sample_continuous(u.y);
+        let (x, pdf_u, _) = self.conditional[row].sample_continuous(u.x);
+        (Point2 { x, y: v }, pdf_u * pdf_v)
+    }
+
+    pub fn pdf(&self, p: Point2) -> f32 {
+        let nv = self.conditional.len();
+        let row = ((p.y * nv as f32) as usize).min(nv - 1);
+        self.marginal.pdf(p.y) * self.conditional[row].pdf(p.x)
+    }
+}
+
+// --- Piecewise-Linear 2D Distribution ---
+// Bilinear interpolant of an nx x ny grid over [0,1]^2, in slices indexed
+// by up to three extra parameters that are interpolated linearly (the
+// `Marginal2D` warp of Dupuy & Jakob 2018 / Mitsuba). Normalized tables
+// also carry marginal / conditional CDFs for sampling and its inverse.
+pub struct PiecewiseLinear2D {
+    nx: usize,
+    ny: usize,
+    params: Vec<Vec<f32>>,  // Grid of each parameter (increasing)
+    strides: Vec<usize>,     // Slice stride of each parameter
+    data: Vec<f32>,
+    marginal_cdf: Vec<f32>,
+    conditional_cdf: Vec<f32>,
+    normalized: bool,
+}
+
+// Slices around a parameter point with their interpolation weights
+struct ParamSlices {
+    slices: [(usize, f32); 8],
+    count: usize,
+}
+
+// Largest i in [0, n - 2] with pred(i) true (pred monotone, true then false)
+fn find_interval(n: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut first, mut len) = (1usize, n.saturating_sub(2));
+    while len > 0 {
+        let half = len / 2;
+        let middle = first + half;
+        if pred(middle) {
+            first = middle + 1;
+            len -= half + 1;
+        } else {
+            len = half;
+        }
+    }
+    first.saturating_sub(1).min(n.saturating_sub(2))
+}
+
+impl PiecewiseLinear2D {
+    /// `data` holds one ny x nx row-major slice per parameter combination,
+    /// the last parameter varying fastest. With `normalize`, each slice is
+    /// scaled to integrate to 1 and can be sampled; otherwise the table is
+    /// only interpolated.
+    pub fn new(data: &[f32], nx: usize, ny: usize, params: Vec<Vec<f32>>, normalize: bool) -> Self {
+        let mut strides = vec![0; params.len()];
+        let mut n_slices = 1;
+        for i in (0..params.len()).rev() {
+            strides[i] = if params[i].len() > 1 { n_slices } else { 0 };
+            n_slices *= params[i].len();
+        }
+        let n_values = nx * ny;
+        let mut table = PiecewiseLinear2D {
+            nx,
+            ny,
+            params,
+            strides,
+            data: data[..n_slices * n_values].to_vec(),
+            marginal_cdf: Vec::new(),
+            conditional_cdf: Vec::new(),
+            normalized: normalize,
+        };
+        if !normalize {
+            return table;
+        }
+
+        table.marginal_cdf = vec![0.0; n_slices * ny];
+        table.conditional_cdf = vec![0.0; n_slices * n_values];
+        for slice in 0..n_slices {
+            let data = &mut table.data[slice * n_values..(slice + 1) * n_values];
+            let conditional = &mut table.conditional_cdf[slice * n_values..(slice + 1) * n_values];
+            let marginal = &mut table.marginal_cdf[slice * ny..(slice + 1) * ny];
+
+            // Trapezoid sums along each row, then over the row totals
+            for y in 0..ny {
+                let mut sum = 0.0f64;
+                for x in 0..nx - 1 {
+                    let i = y * nx + x;
+                    sum += 0.5 * (data[i] as f64 + data[i + 1] as f64);
+                    conditional[i + 1] = sum as f32;
+                }
+            }
+            let mut sum = 0.0f64;
+            for y in 0..ny - 1 {
+                sum += 0.5 * (conditional[(y + 1) * nx - 1] as f64 + conditional[(y + 2) * nx - 1] as f64);
+                marginal[y + 1] = sum as f32;
+            }
+
+            let normalization = if sum > 0.0 { (1.0 / sum) as f32 } else { 0.0 };
+            conditional.iter_mut().for_each(|v| *v *= normalization);
+            marginal.iter_mut().for_each(|v| *v *= normalization);
+            data.iter_mut().for_each(|v| *v *= normalization);
+        }
+        table
+    }
+
+    fn param_slices(&self, param: &[f32]) -> ParamSlices {
+        let mut out = ParamSlices { slices: [(0, 0.0); 8], count: 1 };
+        out.slices[0] = (0, 1.0);
+        for (dim, values) in self.params.iter().enumerate() {
+            if values.len() == 1 {
+                continue;
+            }
+            let i = find_interval(values.len(), |idx| values[idx] <= param[dim]);
+            let (p0, p1) = (values[i], values[i + 1]);
+            let w1 = ((param[dim] - p0) / (p1 - p0)).clamp(0.0, 1.0);
+            let n = out.count;
+            for k in 0..n {
+                let (slice, w) = out.slices[k];
+                out.slices[k] = (slice + self.strides[dim] * i, w * (1.0 - w1));
+                out.slices[k + n] = (slice + self.strides[dim] * (i + 1), w * w1);
+            }
+            out.count = 2 * n;
+        }
+        out
+    }
+
+    // Parameter-interpolated entry `i` of a table with `size` entries per slice
+    fn lookup(&self, table: &[f32], i: usize, size: usize, slices: &ParamSlices) -> f32 {
+        slices.slices[..slices.count].iter().map(|&(slice, w)| w * table[slice * size + i]).sum()
+    }
+
+    fn inv_patch_area(&self) -> f32 {
+        ((self.nx - 1) * (self.ny - 1)) as f32
+    }
+
+    /// Interpolated value at p (a density over [0,1]^2 when normalized)
+    pub fn evaluate(&self, p: Point2, param: &[f32]) -> f32 {
+        let slices = self.param_slices(param);
+        let (px, py) = (p.x * (self.nx - 1) as f32, p.y * (self.ny - 1) as f32);
+        let x = (px.max(0.0) as usize).min(self.nx - 2);
+        let y = (py.max(0.0) as usize).min(self.ny - 2);
+        let (wx, wy) = (px - x as f32, py - y as f32);
+        let size = self.nx * self.ny;
+        let i = y * self.nx + x;
+        let v00 = self.lookup(&self.data, i, size, &slices);
+        let v10 = self.lookup(&self.data, i + 1, size, &slices);
+        let v01 = self.lookup(&self.data, i + self.nx, size, &slices);
+        let v11 = self.lookup(&self.data, i + self.nx + 1, size, &slices);
+        let v = (1.0 - wy) * ((1.0 - wx) * v00 + wx * v10) + wy * ((1.0 - wx) * v01 + wx * v11);
+        if self.normalized { v * self.inv_patch_area() } else { v }
+    }
+
+    /// Warps a uniform sample; returns (point, density). Normalized tables only.
+    pub fn sample(&self, u: Point2, param: &[f32]) -> (Point2, f32) {
+        let slices = self.param_slices(param);
+        let size = self.nx * self.ny;
+        let mut u = Point2 { x: u.x.clamp(f32::EPSILON, 1.0 - f32::EPSILON), y: u.y.clamp(f32::EPSILON, 1.0 - f32::EPSILON) };
+
+        // Row from the marginal CDF, inverting the linear density within it
+        let marginal = |i: usize| self.lookup(&self.marginal_cdf, i, self.ny, &slices);
+        let row = find_interval(self.ny, |i| marginal(i) < u.y);
+        u.y -= marginal(row);
+        let offset = row * self.nx;
+        let r0 = self.lookup(&self.conditional_cdf, offset + self.nx - 1, size, &slices);
+        let r1 = self.lookup(&self.conditional_cdf, offset + 2 * self.nx - 1, size, &slices);
+        let is_const = (r0 - r1).abs() < 1e-4 * (r0 + r1);
+        u.y = if is_const { 2.0 * u.y / (r0 + r1) } else { (r0 - (r0 * r0 - 2.0 * u.y * (r0 - r1)).max(0.0).sqrt()) / (r0 - r1) };
+
+        // Column from the conditional CDF, blended between the two rows
+        u.x *= (1.0 - u.y) * r0 + u.y * r1;
+        let uy = u.y;
+        let conditional = |i: usize| {
+            let v0 = self.lookup(&self.conditional_cdf, offset + i, size, &slices);
+            let v1 = self.lookup(&self.conditional_cdf, offset + self.nx + i, size, &slices);
+            (1.0 - uy) * v0 + uy * v1
+        };
+        let col = find_interval(self.nx, |i| conditional(i) < u.x);
+        u.x -= conditional(col);
+        let i = offset + col;
+        let v00 = self.lookup(&self.data, i, size, &slices);
+        let v10 = self.lookup(&self.data, i + 1, size, &slices);
+        let v01 = self.lookup(&self.data, i + self.nx, size, &slices);
+        let v11 = self.lookup(&self.data, i + self.nx + 1, size, &slices);
+        let c0 = (1.0 - u.y) * v00 + u.y * v01;
+        let c1 = (1.0 - u.y) * v10 + u.y * v11;
+        let is_const = (c0 - c1).abs() < 1e-4 * (c0 + c1);
+        u.x = if is_const { 2.0 * u.x / (c0 + c1) } else { (c0 - (c0 * c0 - 2.0 * u.x * (c0 - c1)).max(0.0).sqrt()) / (c0 - c1) };
+
+        let p = Point2 { x: (col as f32 + u.x) / (self.nx - 1) as f32, y: (row as f32 + u.y) / (self.ny - 1) as f32 };
+        (p, ((1.0 - u.x) * c0 + u.x * c1) * self.inv_patch_area())
+    }
+
+    /// Inverse of `sample`: the uniform sample that warps to p, and the density there
+    pub fn invert(&self, p: Point2, param: &[f32]) -> (Point2, f32) {
+        let slices = self.param_slices(param);
+        let size = self.nx * self.ny;
+        let (px, py) = (p.x * (self.nx - 1) as f32, p.y * (self.ny - 1) as f32);
+        let x = (px.max(0.0) as usize).min(self.nx - 2);
+        let y = (py.max(0.0) as usize).min(self.ny - 2);
+        let (mut sx, mut sy) = (px - x as f32, py - y as f32);
+
+        // Invert the x component
+        let i = y * self.nx + x;
+        let v00 = self.lookup(&self.data, i, size, &slices);
+        let v10 = self.lookup(&self.data, i + 1, size, &slices);
+        let v01 = self.lookup(&self.data, i + self.nx, size, &slices);
+        let v11 = self.lookup(&self.data, i + self.nx + 1, size, &slices);
+        let c0 = (1.0 - sy) * v00 + sy * v01;
+        let c1 = (1.0 - sy) * v10 + sy * v11;
+        let pdf = (1.0 - sx) * c0 + sx * c1;
+        sx *= c0 + 0.5 * sx * (c1 - c0);
+        let v0 = self.lookup(&self.conditional_cdf, i, size, &slices);
+        let v1 = self.lookup(&self.conditional_cdf, i + self.nx, size, &slices);
+        sx += (1.0 - sy) * v0 + sy * v1;
+        let offset = y * self.nx;
+        let r0 = self.lookup(&self.conditional_cdf, offset + self.nx - 1, size, &slices);
+        let r1 = self.lookup(&self.conditional_cdf, offset + 2 * self.nx - 1, size, &slices);
+        sx /= (1.0 - sy) * r0 + sy * r1;
+
+        // Invert the y component
+        sy *= r0 + 0.5 * sy * (r1 - r0);
+        sy += self.lookup(&self.marginal_cdf, y, self.ny, &slices);
+        (Point2 { x: sx, y: sy }, pdf * self.inv_patch_area())
+    }
+}
+
+// --- Catmull-Rom Splines ---
+// Splines through tabulated (x, f(x)) with nonuniform nodes, as used by the
+// BSSRDF profile tables: interpolation weights, integration, inversion and
+// sampling of the integral (pbrt-v3, section 8.6 / appendix A).
+
+/// Weights of the four nodes around x, starting at `offset` (which may be
+/// -1; out-of-range nodes get weight 0). None if x lies outside the nodes.
+pub fn catmull_rom_weights(nodes: &[f32], x: f32) -> Option<(isize, [f32; 4])> {
+    let n = nodes.len();
+    if x.is_nan() || x < nodes[0] || x > nodes[n - 1] {
+        return None;
+    }
+    let idx = find_interval(n, |i| nodes[i] <= x);
+    let (x0, x1) = (nodes[idx], nodes[idx + 1]);
+    let t = (x - x0) / (x1 - x0);
+    let (t2, t3) = (t * t, t * t * t);
+    let mut w = [0.0, 2.0 * t3 - 3.0 * t2 + 1.0, -2.0 * t3 + 3.0 * t2, 0.0];
+    if idx > 0 {
+        let w0 = (t3 - 2.0 * t2 + t) * (x1 - x0) / (x1 - nodes[idx - 1]);
+        w[0] = -w0;
+        w[2] += w0;
+    } else {
+        let w0 = t3 - 2.0 * t2 + t;
+        w[1] -= w0;
+        w[2] += w0;
+    }
+    if idx + 2 < n {
+        let w3 = (t3 - t2) * (x1 - x0) / (nodes[idx + 2] - x0);
+        w[1] -= w3;
+        w[3] = w3;
+    } else {
+        let w3 = t3 - t2;
+        w[1] -= w3;
+        w[2] += w3;
+    }
+    Some((idx as isize - 1, w))
+}
+
+// Endpoint derivatives (scaled by the segment width) of segment i
+fn catmull_rom_derivatives(x: &[f32], values: &[f32], i: usize) -> (f32, f32) {
+    let n = x.len();
+    let (f0, f1, width) = (values[i], values[i + 1], x[i + 1] - x[i]);
+    let d0 = if i > 0 { width * (f1 - values[i - 1]) / (x[i + 1] - x[i - 1]) } else { f1 - f0 };
+    let d1 = if i + 2 < n { width * (values[i + 2] - f0) / (x[i + 2] - x[i]) } else { f1 - f0 };
+    (d0, d1)
+}
+
+/// Running integral of the spline into `cdf` (cdf[0] = 0); returns the total
+pub fn integrate_catmull_rom(x: &[f32], values: &[f32], cdf: &mut [f32]) -> f32 {
+    let mut sum = 0.0;
+    cdf[0] = 0.0;
+    for i in 0..x.len() - 1 {
+        let (d0, d1) = catmull_rom_derivatives(x, values, i);
+        sum += ((d0 - d1) * (1.0 / 12.0) + (values[i] + values[i + 1]) * 0.5) * (x[i + 1] - x[i]);
+        cdf[i + 1] = sum;
+    }
+    sum
+}
+
+/// x at which a monotonically increasing spline reaches u (clamped to the ends)
+pub fn invert_catmull_rom(x: &[f32], values: &[f32], u: f32) -> f32 {
+    let n = x.len();
+    if u.is_nan() || u <= values[0] {
+        return x[0];
+    } else if u >= values[n - 1] {
+        return x[n - 1];
+    }
+    let i = find_interval(n, |i| values[i] <= u);
+    let (f0, f1) = (values[i], values[i + 1]);
+    let (d0, d1) = catmull_rom_derivatives(x, values, i);
+
+    // Newton-bisection on the cubic of the segment
+    let (mut a, mut b, mut t) = (0.0f32, 1.0f32, 0.5f32);
+    loop {
+        if t.is_nan() || t <= a || t >= b {
+            t = 0.5 * (a + b);
+        }
+        let (t2, t3) = (t * t, t * t * t);
+        let f_hat = (2.0 * t3 - 3.0 * t2 + 1.0) * f0 + (-2.0 * t3 + 3.0 * t2) * f1 + (t3 - 2.0 * t2 + t) * d0 + (t3 - t2) * d1;
+        let df_hat = (6.0 * t2 - 6.0 * t) * f0 + (-6.0 * t2 + 6.0 * t) * f1 + (3.0 * t2 - 4.0 * t + 1.0) * d0 + (3.0 * t2 - 2.0 * t) * d1;
+        if (f_hat - u).abs() < 1e-6 || b - a < 1e-6 {
+            break;
+        }
+        if f_hat - u < 0.0 { a = t; } else { b = t; }
+        t -= (f_hat - u) / df_hat;
+    }
+    x[i] + t * (x[i + 1] - x[i])
+}
+
+/// Samples x2 from a family of 1D splines (rows of `values`, indexed by
+/// `nodes1`, with running integrals `cdf` from `integrate_catmull_rom`)
+/// interpolated at `alpha`. Returns (x2, f(x2), pdf), or None when alpha is
+/// outside `nodes1` or the row is empty.
+pub fn sample_catmull_rom_2d(
+    nodes1: &[f32],
+    nodes2: &[f32],
+    values: &[f32],
+    cdf: &[f32],
+    alpha: f32,
+    u: f32,
+) -> Option<(f32, f32, f32)> {
+    let (offset, weights) = catmull_rom_weights(nodes1, alpha)?;
+    let size2 = nodes2.len();
+    let interpolate = |array: &[f32], idx: usize| -> f32 {
+        (0..4)
+            .filter(|&i| weights[i] != 0.0)
+            .map(|i| array[(offset + i as isize) as usize * size2 + idx] * weights[i])
+            .sum()
+    };
+    let maximum = interpolate(cdf, size2 - 1);
+    if maximum.is_nan() || maximum <= 0.0 {
+        return None;
+    }
+    let u = u * maximum;
+    let idx = find_interval(size2, |i| interpolate(cdf, i) <= u);
+
+    // Segment values and derivatives of the interpolated row
+    let (f0, f1) = (interpolate(values, idx), interpolate(values, idx + 1));
+    let (x0, x1) = (nodes2[idx], nodes2[idx + 1]);
+    let width = x1 - x0;
+    let d0 = if idx > 0 { width * (f1 - interpolate(values, idx - 1)) / (x1 - nodes2[idx - 1]) } else { f1 - f0 };
+    let d1 = if idx + 2 < size2 { width * (interpolate(values, idx + 2) - f0) / (nodes2[idx + 2] - x0) } else { f1 - f0 };
+    let u = (u - interpolate(cdf, idx)) / width;
+
+    // Invert the segment's integral by Newton-bisection, starting from the linear guess
+    let mut t = if f0 != f1 { (f0 - (f0 * f0 + 2.0 * u * (f1 - f0)).max(0.0).sqrt()) / (f0 - f1) } else { u / f0 };
+    let (mut a, mut b) = (0.0f32, 1.0f32);
+    let mut f_hat;
+    loop {
+        if t.is_nan() || t < a || t > b {
+            t = 0.5 * (a + b);
+        }
+        let big_f_hat = t * (f0 + t * (0.5 * d0 + t * ((1.0 / 3.0) * (-2.0 * d0 - d1) + f1 - f0 + t * (0.25 * (d0 + d1) + 0.5 * (f0 - f1)))));
+        f_hat = f0 + t * (d0 + t * (-2.0 * d0 - d1 + 3.0 * (f1 - f0) + t * (d0 + d1 + 2.0 * (f0 - f1))));
+        if (big_f_hat - u).abs() < 1e-6 || b - a < 1e-6 {
+            break;
+        }
+        if big_f_hat - u < 0.0 { a = t; } else { b = t; }
+        t -= (big_f_hat - u) / f_hat;
+    }
+    Some((x0 + width * t, f_hat, f_hat / maximum))
+}
+
+// --- Alias Table ---
+//
+// O(1) sampling of a discrete distribution (Walker / Vose). Each bin keeps
+// the probability `q` of returning itself, otherwise it returns its alias.
+#[derive(Debug, Clone, Copy)]
+struct AliasBin {
+    q: f32,
+    p: f32, // Normalized probability of this index
+    alias: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct AliasTable {
+    bins: Vec<AliasBin>,
+}
+
+impl AliasTable {
+    pub fn new(weights: &[f32]) -> Self {
+        let n = weights.len();
+        let sum: f64 = weights.iter().map(|&w| w.max(0.0) as f64).sum();
+        let mut bins: Vec<AliasBin> = weights
+            .iter()
+            .enumerate()
+            .map(|(i, &w)| AliasBin {
+                q: 0.0,
+                p: if sum > 0.0 { (w.max(0.0) as f64 / sum) as f32 } else { 1.0 / n as f32 },
+                alias: i,
+            })
+            .collect();
+
+        // Split bins into those below and above the average (p * n < 1 or >= 1)
+        let mut under = Vec::new();
+        let mut over = Vec::new();
+        for (i, b) in bins.iter().enumerate() {
+            let p_hat = b.p as f64 * n as f64;
+            if p_hat < 1.0 { under.push((i, p_hat)) } else { over.push((i, p_hat)) }
+        }
+
+        // Fill each underfull bin with probability mass from an overfull one
+        while let (Some(&(un, un_p)), Some(&(ov, ov_p))) = (under.last(), over.last()) {
+            under.pop();
+            over.pop();
+            bins[un].q = un_p as f32;
+            bins[un].alias = ov;
+
+            let excess = un_p + ov_p - 1.0;
+            if excess < 1.0 { under.push((ov, excess)) } else { over.push((ov, excess)) }
+        }
+
+        // Leftovers are (up to round-off) exactly full
+        for (i, _) in under.into_iter().chain(over) {
+            bins[i].q = 1.0;
+            bins[i].alias = i;
+        }
+
+        AliasTable { bins }
+    }
+
+    /// Returns (index, probability of that index)
+    pub fn sample(&self, u: f32) -> (usize, f32) {
+        let n = self.bins.len();
+        let offset = ((u * n as f32) as usize).min(n - 1);
+        let up = (u * n as f32 - offset as f32).min(1.0 - f32::EPSILON);
+        if up < self.bins[offset].q {
+            (offset, self.bins[offset].p)
+        } else {
+            let alias = self.bins[offset].alias;
+            (alias, self.bins[alias].p)
+        }
+    }
+
+    pub fn pmf(&self, index: usize) -> f32 {
+        self.bins[index].p
     }
 }
@@ -0,0 +1,621 @@
+use std::f32::consts::PI;
+use std::io;
+use std::sync::Arc;
+
+use crate::core::geometry::{Point2, Vector3};
+use crate::core::math::{sample_cosine_hemisphere, Distribution2D, PiecewiseLinear2D};
+use crate::core::spectrum::{SampledSpectrum, SampledWavelengths};
+
+// --- Measured BRDFs ---
+//
+// Two tabulated formats:
+//
+//   MERL (.binary)  Matusik et al. 2003: isotropic RGB reflectance on a
+//                   90 x 90 x 180 grid over the half / difference angles
+//                   (theta_h, theta_d, phi_d) of Rusinkiewicz's coordinates.
+//   RGL  (.bsdf)    Dupuy & Jakob 2018: spectral tables in a warped space
+//                   that follows the material's visible normals, with the
+//                   warps needed to importance sample them.
+//
+// Both are reflection-only and are mirrored below the surface.
+
+const MERL_RES_THETA_H: usize = 90;
+const MERL_RES_THETA_D: usize = 90;
+const MERL_RES_PHI_D: usize = 180;
+const MERL_SCALE: [f32; 3] = [1.0 / 1500.0, 1.15 / 1500.0, 1.66 / 1500.0];
+
+// MERL sampling tables: the BRDF tabulated over (theta_i, phi_i) for a set
+// of theta_o, mixed with cosine sampling for the cells it underestimates
+const MERL_SAMPLE_THETA_O: usize = 16;
+const MERL_SAMPLE_THETA_I: usize = 32;
+const MERL_SAMPLE_PHI_I: usize = 64;
+const MERL_COSINE_FRACTION: f32 = 0.1;
+
+// Dominant wavelengths of the sRGB primaries, for spreading RGB data over
+// the spectrum: linear between them, constant beyond
+const RGB_WAVELENGTHS: [f32; 3] = [610.0, 550.0, 465.0];
+
+const TENSOR_MAGIC: &[u8; 12] = b"tensor_file\0";
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("Measured BRDF: {}", msg))
+}
+
+fn rgb_at(rgb: [f32; 3], lambda: f32) -> f32 {
+    let [r, g, b] = rgb;
+    let [lr, lg, lb] = RGB_WAVELENGTHS;
+    if lambda <= lb {
+        b
+    } else if lambda <= lg {
+        b + (g - b) * (lambda - lb) / (lg - lb)
+    } else if lambda <= lr {
+        g + (r - g) * (lambda - lg) / (lr - lg)
+    } else {
+        r
+    }
+}
+
+// atan2 rather than acos(z), which loses precision near the pole where
+// the RGL warps are steepest
+fn spherical_theta(w: Vector3) -> f32 {
+    (w.x * w.x + w.y * w.y).sqrt().atan2(w.z)
+}
+
+fn spherical_direction(sin_theta: f32, cos_theta: f32, phi: f32) -> Vector3 {
+    Vector3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta)
+}
+
+fn reflect(wo: Vector3, n: Vector3) -> Vector3 {
+    n * (2.0 * wo.dot(n)) - wo
+}
+
+// --- MERL ---
+
+pub struct MerlBRDF {
+    data: Vec<[f32; 3]>,            // Scaled RGB reflectance, negative (missing) values clamped
+    sampling: Vec<Distribution2D>,  // One per theta_o band
+}
+
+fn merl_index(theta_h: f32, theta_d: f32, phi_d: f32) -> usize {
+    // theta_h is sampled more densely near the specular peak
+    let theta_h_index = if theta_h <= 0.0 {
+        0
+    } else {
+        (((theta_h / (PI / 2.0)) * (MERL_RES_THETA_H * MERL_RES_THETA_H) as f32).sqrt() as usize).min(MERL_RES_THETA_H - 1)
+    };
+    let theta_d_index = ((theta_d / (PI / 2.0) * MERL_RES_THETA_D as f32).max(0.0) as usize).min(MERL_RES_THETA_D - 1);
+    // Reciprocity: phi_d and phi_d + pi are the same entry
+    let phi_d = if phi_d < 0.0 { phi_d + PI } else { phi_d };
+    let phi_d_index = ((phi_d / PI * MERL_RES_PHI_D as f32).max(0.0) as usize).min(MERL_RES_PHI_D - 1);
+    phi_d_index + MERL_RES_PHI_D * (theta_d_index + MERL_RES_THETA_D * theta_h_index)
+}
+
+// Half and difference angles (theta_h, theta_d, phi_d) of a direction pair
+fn half_diff_angles(wo: Vector3, wi: Vector3) -> (f32, f32, f32) {
+    let h = (wo + wi).normalize();
+    let theta_h = spherical_theta(h);
+    let phi_h = h.y.atan2(h.x);
+    // Rotate wi so the half vector becomes +z
+    let (sin_p, cos_p) = (-phi_h).sin_cos();
+    let d = Vector3::new(wi.x * cos_p - wi.y * sin_p, wi.x * sin_p + wi.y * cos_p, wi.z);
+    let (sin_t, cos_t) = (-theta_h).sin_cos();
+    let d = Vector3::new(d.x * cos_t + d.z * sin_t, d.y, -d.x * sin_t + d.z * cos_t);
+    (theta_h, spherical_theta(d), d.y.atan2(d.x))
+}
+
+impl MerlBRDF {
+    /// Parses a MERL .binary file: three i32 dimensions, then the red,
+    /// green and blue tables as f64
+    pub fn parse(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() < 12 {
+            return Err(invalid("MERL file too short"));
+        }
+        let dims: Vec<usize> =
+            (0..3).map(|i| i32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()).max(0) as usize).collect();
+        let n = dims.iter().product::<usize>();
+        if n != MERL_RES_THETA_H * MERL_RES_THETA_D * MERL_RES_PHI_D {
+            return Err(invalid(&format!("unexpected MERL dimensions {:?}", dims)));
+        }
+        if bytes.len() < 12 + 3 * n * 8 {
+            return Err(invalid("MERL file truncated"));
+        }
+        let value = |i: usize| f64::from_le_bytes(bytes[12 + 8 * i..20 + 8 * i].try_into().unwrap()) as f32;
+        let data = (0..n)
+            .map(|i| std::array::from_fn(|c| (value(c * n + i) * MERL_SCALE[c]).max(0.0)))
+            .collect();
+
+        let mut brdf = MerlBRDF { data, sampling: Vec::new() };
+        brdf.sampling = (0..MERL_SAMPLE_THETA_O).map(|k| brdf.sampling_table(k)).collect();
+        Ok(brdf)
+    }
+
+    fn rgb(&self, wo: Vector3, wi: Vector3) -> [f32; 3] {
+        let (theta_h, theta_d, phi_d) = half_diff_angles(wo, wi);
+        self.data[merl_index(theta_h, theta_d, phi_d)]
+    }
+
+    // Distribution over (theta_i / (pi/2), phi_i / 2pi) for wo in band k,
+    // at phi_o = 0, proportional to f cos(theta_i) sin(theta_i)
+    fn sampling_table(&self, k: usize) -> Distribution2D {
+        let theta_o = (k as f32 + 0.5) / MERL_SAMPLE_THETA_O as f32 * (PI / 2.0);
+        let wo = spherical_direction(theta_o.sin(), theta_o.cos(), 0.0);
+        let mut f = Vec::with_capacity(MERL_SAMPLE_THETA_I * MERL_SAMPLE_PHI_I);
+        for v in 0..MERL_SAMPLE_PHI_I {
+            for u in 0..MERL_SAMPLE_THETA_I {
+                let theta_i = (u as f32 + 0.5) / MERL_SAMPLE_THETA_I as f32 * (PI / 2.0);
+                let phi_i = (v as f32 + 0.5) / MERL_SAMPLE_PHI_I as f32 * (2.0 * PI);
+                let wi = spherical_direction(theta_i.sin(), theta_i.cos(), phi_i);
+                let [r, g, b] = self.rgb(wo, wi);
+                f.push((r + g + b) / 3.0 * theta_i.cos() * theta_i.sin());
+            }
+        }
+        Distribution2D::new(&f, MERL_SAMPLE_THETA_I, MERL_SAMPLE_PHI_I)
+    }
+
+    fn table_for(&self, wo: Vector3) -> &Distribution2D {
+        let band = (spherical_theta(wo) / (PI / 2.0) * MERL_SAMPLE_THETA_O as f32) as usize;
+        &self.sampling[band.min(MERL_SAMPLE_THETA_O - 1)]
+    }
+
+    fn sample_wi(&self, wo: Vector3, u: Point2) -> Option<Vector3> {
+        if u.x < MERL_COSINE_FRACTION {
+            return Some(sample_cosine_hemisphere(Point2 { x: u.x / MERL_COSINE_FRACTION, y: u.y }));
+        }
+        let u = Point2 { x: (u.x - MERL_COSINE_FRACTION) / (1.0 - MERL_COSINE_FRACTION), y: u.y };
+        let (p, pdf) = self.table_for(wo).sample_continuous(u);
+        if pdf == 0.0 {
+            return None;
+        }
+        let theta_i = p.x * (PI / 2.0);
+        let phi_i = p.y * (2.0 * PI) + wo.y.atan2(wo.x);
+        Some(spherical_direction(theta_i.sin(), theta_i.cos(), phi_i))
+    }
+
+    fn pdf(&self, wo: Vector3, wi: Vector3) -> f32 {
+        let theta_i = spherical_theta(wi);
+        let sin_theta_i = theta_i.sin();
+        let mut phi = wi.y.atan2(wi.x) - wo.y.atan2(wo.x);
+        phi -= (phi / (2.0 * PI)).floor() * 2.0 * PI;
+        let p = Point2 { x: (theta_i / (PI / 2.0)).min(1.0), y: (phi / (2.0 * PI)).min(1.0) };
+        let table = if sin_theta_i > 0.0 {
+            self.table_for(wo).pdf(p) / ((PI / 2.0) * (2.0 * PI) * sin_theta_i)
+        } else {
+            0.0
+        };
+        (1.0 - MERL_COSINE_FRACTION) * table + MERL_COSINE_FRACTION * wi.z / PI
+    }
+}
+
+// --- RGL ---
+
+// One field of a tensor file, converted to f32
+struct TensorField {
+    shape: Vec<usize>,
+    data: Vec<f32>,
+}
+
+// Fields of a Mitsuba tensor file: magic, version 1.0, field count, then
+// per field its name, rank, dtype, data offset and shape
+fn parse_tensor_file(bytes: &[u8]) -> io::Result<Vec<(String, TensorField)>> {
+    let read = |off: usize, n: usize| {
+        let end = off.checked_add(n).ok_or_else(|| invalid("tensor field offset out of range"))?;
+        bytes.get(off..end).ok_or_else(|| invalid("tensor file truncated"))
+    };
+    let u16_at = |off: usize| read(off, 2).map(|b| u16::from_le_bytes(b.try_into().unwrap()) as usize);
+    let u32_at = |off: usize| read(off, 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()) as usize);
+    let u64_at = |off: usize| read(off, 8).map(|b| u64::from_le_bytes(b.try_into().unwrap()) as usize);
+
+    if read(0, 12)? != TENSOR_MAGIC {
+        return Err(invalid("not a tensor file"));
+    }
+    if read(12, 2)? != [1, 0] {
+        return Err(invalid("unsupported tensor file version"));
+    }
+    let n_fields = u32_at(14)?;
+    let mut pos = 18;
+    let mut fields = Vec::with_capacity(n_fields);
+    for _ in 0..n_fields {
+        let name_len = u16_at(pos)?;
+        let name = String::from_utf8_lossy(read(pos + 2, name_len)?).into_owned();
+        pos += 2 + name_len;
+        let ndim = u16_at(pos)?;
+        let dtype = read(pos + 2, 1)?[0];
+        let offset = u64_at(pos + 3)?;
+        pos += 11;
+        let shape = (0..ndim).map(|i| u64_at(pos + 8 * i)).collect::<io::Result<Vec<usize>>>()?;
+        pos += 8 * ndim;
+
+        let size = |width: usize| {
+            shape
+                .iter()
+                .try_fold(width, |n, &d| n.checked_mul(d))
+                .ok_or_else(|| invalid(&format!("field '{}' is too large", name)))
+        };
+        let data = match dtype {
+            // UInt8
+            1 => read(offset, size(1)?)?.iter().map(|&b| b as f32).collect(),
+            // Float32
+            10 => read(offset, size(4)?)?.chunks_exact(4).map(|b| f32::from_le_bytes(b.try_into().unwrap())).collect(),
+            // Float64
+            11 => read(offset, size(8)?)?.chunks_exact(8).map(|b| f64::from_le_bytes(b.try_into().unwrap()) as f32).collect(),
+            _ => return Err(invalid(&format!("unsupported dtype {} for field '{}'", dtype, name))),
+        };
+        fields.push((name, TensorField { shape, data }));
+    }
+    Ok(fields)
+}
+
+pub struct RglBRDF {
+    spectra: PiecewiseLinear2D,   // Reflectance in warped space, over (phi_o, theta_o, lambda)
+    ndf: PiecewiseLinear2D,       // Microfacet distribution D(wm)
+    sigma: PiecewiseLinear2D,     // Projected microfacet area sigma(wo)
+    vndf: PiecewiseLinear2D,      // Visible normals, over (phi_o, theta_o)
+    luminance: PiecewiseLinear2D, // Luminance in warped space, over (phi_o, theta_o)
+    isotropic: bool,
+    reduction: usize, // Rotational symmetry of anisotropic data (phi_o covers 2pi / reduction)
+    phi_o_min: f32,
+}
+
+// (theta, phi) <-> unit square, denser near the pole
+fn theta_to_u(theta: f32) -> f32 { (theta * (2.0 / PI)).max(0.0).sqrt() }
+fn phi_to_u(phi: f32) -> f32 { phi * (0.5 / PI) + 0.5 }
+fn u_to_theta(u: f32) -> f32 { u * u * (PI / 2.0) }
+fn u_to_phi(u: f32) -> f32 { (2.0 * u - 1.0) * PI }
+
+impl RglBRDF {
+    /// Parses an RGL .bsdf tensor file
+    pub fn parse(bytes: &[u8]) -> io::Result<Self> {
+        let mut fields = parse_tensor_file(bytes)?;
+        let mut take = |name: &str, ndim: usize| -> io::Result<TensorField> {
+            let i = fields
+                .iter()
+                .position(|(n, _)| n == name)
+                .ok_or_else(|| invalid(&format!("missing field '{}'", name)))?;
+            let field = fields.swap_remove(i).1;
+            if field.shape.len() != ndim {
+                return Err(invalid(&format!("field '{}' should have {} dimensions", name, ndim)));
+            }
+            Ok(field)
+        };
+        let theta_i = take("theta_i", 1)?;
+        let phi_i = take("phi_i", 1)?;
+        let ndf = take("ndf", 2)?;
+        let sigma = take("sigma", 2)?;
+        let vndf = take("vndf", 4)?;
+        let luminance = take("luminance", 4)?;
+        let spectra = take("spectra", 5)?;
+        let wavelengths = take("wavelengths", 1)?;
+
+        let (n_phi, n_theta) = (phi_i.shape[0], theta_i.shape[0]);
+        let consistent = vndf.shape[..2] == [n_phi, n_theta]
+            && luminance.shape[..2] == [n_phi, n_theta]
+            && spectra.shape[..3] == [n_phi, n_theta, wavelengths.shape[0]]
+            && spectra.shape[3..] == luminance.shape[2..];
+        if !consistent {
+            return Err(invalid("inconsistent table shapes"));
+        }
+
+        let isotropic = n_phi <= 2;
+        let phi_range = phi_i.data[n_phi - 1] - phi_i.data[0];
+        let reduction = if isotropic || phi_range <= 0.0 { 1 } else { (2.0 * PI / phi_range).round().max(1.0) as usize };
+        let params = vec![phi_i.data.clone(), theta_i.data.clone()];
+        let spectra_params = vec![phi_i.data.clone(), theta_i.data.clone(), wavelengths.data];
+        Ok(RglBRDF {
+            spectra: PiecewiseLinear2D::new(&spectra.data, spectra.shape[4], spectra.shape[3], spectra_params, false),
+            ndf: PiecewiseLinear2D::new(&ndf.data, ndf.shape[1], ndf.shape[0], Vec::new(), false),
+            sigma: PiecewiseLinear2D::new(&sigma.data, sigma.shape[1], sigma.shape[0], Vec::new(), false),
+            vndf: PiecewiseLinear2D::new(&vndf.data, vndf.shape[3], vndf.shape[2], params.clone(), true),
+            luminance: PiecewiseLinear2D::new(&luminance.data, luminance.shape[3], luminance.shape[2], params, true),
+            isotropic,
+            reduction,
+            phi_o_min: phi_i.data[0],
+        })
+    }
+
+    // Table parameters (phi_o, theta_o) for wo, and the rotation that maps
+    // wo into the measured phi_o range (nonzero for symmetric anisotropic data)
+    fn params(&self, wo: Vector3) -> ([f32; 2], f32) {
+        let (theta_o, phi_o) = (spherical_theta(wo), wo.y.atan2(wo.x));
+        if self.isotropic || self.reduction < 2 {
+            return ([phi_o, theta_o], 0.0);
+        }
+        let period = 2.0 * PI / self.reduction as f32;
+        let shift = ((phi_o - self.phi_o_min) / period).floor() * period;
+        ([phi_o - shift, theta_o], shift)
+    }
+
+    // Warped-space position of the microfacet normal wm
+    fn u_wm(&self, wm: Vector3, phi_o: f32, shift: f32) -> Point2 {
+        let phi_m = wm.y.atan2(wm.x) - shift;
+        let y = phi_to_u(if self.isotropic { phi_m - phi_o } else { phi_m });
+        Point2 { x: theta_to_u(spherical_theta(wm)), y: y - y.floor() }
+    }
+
+    fn f(&self, wo: Vector3, wi: Vector3, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let wm = wo + wi;
+        if wm.length_squared() == 0.0 {
+            return SampledSpectrum::new(0.0);
+        }
+        let ([phi_o, theta_o], shift) = self.params(wo);
+        let u_wm = self.u_wm(wm.normalize(), phi_o, shift);
+        let (u, _) = self.vndf.invert(u_wm, &[phi_o, theta_o]);
+        self.value(u, u_wm, phi_o, theta_o, wi, lambda)
+    }
+
+    // BRDF at warped sample u: the tabulated spectrum times D / (4 sigma cos)
+    fn value(&self, u: Point2, u_wm: Point2, phi_o: f32, theta_o: f32, wi: Vector3, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let mut fr = SampledSpectrum::new(0.0);
+        for (v, &l) in fr.values.iter_mut().zip(&lambda.lambda) {
+            *v = self.spectra.evaluate(u, &[phi_o, theta_o, l]).max(0.0);
+        }
+        let u_wo = Point2 { x: theta_to_u(theta_o), y: phi_to_u(phi_o) };
+        let sigma = self.sigma.evaluate(u_wo, &[]);
+        if sigma <= 0.0 || wi.z <= 0.0 {
+            return SampledSpectrum::new(0.0);
+        }
+        fr * (self.ndf.evaluate(u_wm, &[]) / (4.0 * sigma * wi.z))
+    }
+
+    fn sample_f(&self, wo: Vector3, u: Point2, lambda: &SampledWavelengths) -> Option<(SampledSpectrum, Vector3, f32)> {
+        let ([phi_o, theta_o], shift) = self.params(wo);
+        // Luminance warp, then the visible normal warp
+        let (u, lum_pdf) = self.luminance.sample(u, &[phi_o, theta_o]);
+        let (u_wm, vndf_pdf) = self.vndf.sample(u, &[phi_o, theta_o]);
+
+        let theta_m = u_to_theta(u_wm.x);
+        let mut phi_m = u_to_phi(u_wm.y) + shift;
+        if self.isotropic {
+            phi_m += phi_o;
+        }
+        let wm = spherical_direction(theta_m.sin(), theta_m.cos(), phi_m);
+        let wi = reflect(wo, wm);
+        if wi.z <= 0.0 {
+            return None;
+        }
+
+        let f = self.value(u, u_wm, phi_o, theta_o, wi, lambda);
+        let jacobian = 4.0 * wo.dot(wm).abs() * (2.0 * PI * PI * u_wm.x * theta_m.sin()).max(1e-6);
+        let pdf = vndf_pdf * lum_pdf / jacobian;
+        if pdf <= 0.0 { None } else { Some((f, wi, pdf)) }
+    }
+
+    fn pdf(&self, wo: Vector3, wi: Vector3) -> f32 {
+        let wm = wo + wi;
+        if wm.length_squared() == 0.0 {
+            return 0.0;
+        }
+        let wm = wm.normalize();
+        let ([phi_o, theta_o], shift) = self.params(wo);
+        let u_wm = self.u_wm(wm, phi_o, shift);
+        let (u, vndf_pdf) = self.vndf.invert(u_wm, &[phi_o, theta_o]);
+        let lum_pdf = self.luminance.evaluate(u, &[phi_o, theta_o]);
+        let sin_theta_m = (wm.x * wm.x + wm.y * wm.y).sqrt();
+        let jacobian = 4.0 * wi.dot(wm).abs() * (2.0 * PI * PI * u_wm.x * sin_theta_m).max(1e-6);
+        vndf_pdf * lum_pdf / jacobian
+    }
+}
+
+// --- Loaded Data ---
+
+pub enum MeasuredBRDF {
+    Merl(MerlBRDF),
+    Rgl(Box<RglBRDF>),
+}
+
+impl MeasuredBRDF {
+    /// Loads an RGL tensor file (recognized by its header) or a MERL .binary file
+    pub fn read(filename: &str) -> io::Result<Self> {
+        let bytes = std::fs::read(filename)?;
+        let with_name = |e: io::Error| io::Error::new(e.kind(), format!("{}: {}", filename, e));
+        if bytes.starts_with(TENSOR_MAGIC) {
+            RglBRDF::parse(&bytes).map(|rgl| MeasuredBRDF::Rgl(Box::new(rgl))).map_err(with_name)
+        } else {
+            MerlBRDF::parse(&bytes).map(MeasuredBRDF::Merl).map_err(with_name)
+        }
+    }
+}
+
+// --- Measured BxDF ---
+// A loaded table evaluated at the path's wavelengths
+pub struct MeasuredBxDF {
+    brdf: Arc<MeasuredBRDF>,
+    lambda: SampledWavelengths,
+}
+
+impl MeasuredBxDF {
+    pub fn new(brdf: Arc<MeasuredBRDF>, lambda: SampledWavelengths) -> Self {
+        Self { brdf, lambda }
+    }
+
+    pub fn f(&self, wo: Vector3, wi: Vector3) -> SampledSpectrum {
+        if wo.z * wi.z <= 0.0 {
+            return SampledSpectrum::new(0.0);
+        }
+        // Mirrored by scaling: with a (-wo, -wi) tuple here, optimized
+        // builds (rustc 1.95) passed callers' later f / pdf calls a negated wi
+        let s = if wo.z < 0.0 { -1.0 } else { 1.0 };
+        let (wo, wi) = (wo * s, wi * s);
+        match &*self.brdf {
+            MeasuredBRDF::Merl(merl) => {
+                let rgb = merl.rgb(wo, wi);
+                SampledSpectrum { values: self.lambda.lambda.map(|l| rgb_at(rgb, l)) }
+            }
+            MeasuredBRDF::Rgl(rgl) => rgl.f(wo, wi, &self.lambda),
+        }
+    }
+
+    pub fn sample_f(&self, wo: Vector3, u: Point2) -> Option<(SampledSpectrum, Vector3, f32, bool)> {
+        if wo.z == 0.0 {
+            return None;
+        }
+        let flip = wo.z < 0.0;
+        let wo_up = if flip { -wo } else { wo };
+        let wi = match &*self.brdf {
+            MeasuredBRDF::Merl(merl) => merl.sample_wi(wo_up, u)?,
+            MeasuredBRDF::Rgl(rgl) => {
+                let (f, wi, pdf) = rgl.sample_f(wo_up, u, &self.lambda)?;
+                return Some((f, if flip { -wi } else { wi }, pdf, false));
+            }
+        };
+        let wi = if flip { -wi } else { wi };
+        let pdf = self.pdf(wo, wi);
+        if pdf <= 0.0 {
+            return None;
+        }
+        Some((self.f(wo, wi), wi, pdf, false))
+    }
+
+    pub fn pdf(&self, wo: Vector3, wi: Vector3) -> f32 {
+        if wo.z * wi.z <= 0.0 {
+            return 0.0;
+        }
+        // Mirrored by scaling, as in f
+        let s = if wo.z < 0.0 { -1.0 } else { 1.0 };
+        let (wo, wi) = (wo * s, wi * s);
+        match &*self.brdf {
+            MeasuredBRDF::Merl(merl) => merl.pdf(wo, wi),
+            MeasuredBRDF::Rgl(rgl) => rgl.pdf(wo, wi),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bsdf::tests::{check_bxdf, tested_without_mode, Checks, Sample, Tested};
+    use crate::core::bsdf::TransportMode;
+
+    // MERL file of a smooth isotropic BRDF: a tinted base plus a highlight
+    // around the half vector that fades with theta_d and varies with phi_d
+    fn merl_file() -> Vec<u8> {
+        let n = MERL_RES_THETA_H * MERL_RES_THETA_D * MERL_RES_PHI_D;
+        let mut bytes = Vec::with_capacity(12 + 24 * n);
+        for dim in [MERL_RES_THETA_H, MERL_RES_THETA_D, MERL_RES_PHI_D] {
+            bytes.extend_from_slice(&(dim as i32).to_le_bytes());
+        }
+        for (c, base) in [0.2f64, 0.3, 0.4].into_iter().enumerate() {
+            for i in 0..n {
+                let phi_d = (i % MERL_RES_PHI_D) as f64 / MERL_RES_PHI_D as f64 * std::f64::consts::PI;
+                let theta_d = (i / MERL_RES_PHI_D % MERL_RES_THETA_D) as f64 / MERL_RES_THETA_D as f64;
+                let theta_h = ((i / (MERL_RES_PHI_D * MERL_RES_THETA_D)) as f64 / MERL_RES_THETA_H as f64).powi(2);
+                let lobe = 3.0 * (-(theta_h / 0.05).powi(2)).exp() * (1.0 - 0.5 * theta_d) * (1.0 + 0.3 * (2.0 * phi_d).cos());
+                let f = (base + lobe) / std::f64::consts::PI;
+                bytes.extend_from_slice(&(f / MERL_SCALE[c] as f64).to_le_bytes());
+            }
+        }
+        bytes
+    }
+
+    // Mitsuba tensor file with Float32 fields
+    fn tensor_file(fields: &[(&str, Vec<usize>, Vec<f32>)]) -> Vec<u8> {
+        let header: usize = 18 + fields.iter().map(|(name, shape, _)| 13 + name.len() + 8 * shape.len()).sum::<usize>();
+        let mut bytes = TENSOR_MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(fields.len() as u32).to_le_bytes());
+        let mut offset = header;
+        for (name, shape, data) in fields {
+            bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.extend_from_slice(&(shape.len() as u16).to_le_bytes());
+            bytes.push(10);
+            bytes.extend_from_slice(&(offset as u64).to_le_bytes());
+            for &d in shape {
+                bytes.extend_from_slice(&(d as u64).to_le_bytes());
+            }
+            offset += 4 * data.len();
+        }
+        for (_, _, data) in fields {
+            bytes.extend(data.iter().flat_map(|v| v.to_le_bytes()));
+        }
+        bytes
+    }
+
+    // Isotropic RGL file over a few theta_o with smooth, strictly positive
+    // tables; enough to exercise the warps, not a physical material
+    fn rgl_file() -> Vec<u8> {
+        const N: usize = 16;
+        let theta_i = vec![0.0, 0.4, 0.8, 1.2, 1.5];
+        let wavelengths = vec![360.0, 600.0, 830.0];
+        let grid = |g: &dyn Fn(f32, f32, f32) -> f32, t: f32| -> Vec<f32> {
+            (0..N * N).map(|i| g((i % N) as f32 / (N - 1) as f32, (i / N) as f32 / (N - 1) as f32, t)).collect()
+        };
+        // Densities over the warped square vanish like u^3 at the pole, as
+        // a finite density per solid angle does
+        let peak = |x: f32, _: f32, _: f32| x.powi(3) * (0.002 + (-(x / 0.4).powi(2)).exp());
+        let vndf = |x: f32, y: f32, t: f32| peak(x, y, t) * (1.0 + 0.3 * t * (2.0 * PI * y).cos());
+        let luminance = |x: f32, y: f32, _: f32| 1.0 + x + 0.5 * y;
+        let mut vndf_data = Vec::new();
+        let mut luminance_data = Vec::new();
+        let mut spectra_data = Vec::new();
+        for &t in &theta_i {
+            vndf_data.extend(grid(&vndf, t));
+            luminance_data.extend(grid(&luminance, t));
+            for (k, _) in wavelengths.iter().enumerate() {
+                spectra_data.extend(grid(&|x, y, t| 0.3 + 0.2 * k as f32 + 0.1 * x * y + 0.05 * t, t));
+            }
+        }
+        let n_theta = theta_i.len();
+        tensor_file(&[
+            ("theta_i", vec![n_theta], theta_i),
+            ("phi_i", vec![1], vec![0.0]),
+            ("ndf", vec![N, N], grid(&peak, 0.0)),
+            ("sigma", vec![N, N], vec![0.5; N * N]),
+            ("vndf", vec![1, n_theta, N, N], vndf_data),
+            ("luminance", vec![1, n_theta, N, N], luminance_data),
+            ("spectra", vec![1, n_theta, 3, N, N], spectra_data),
+            ("wavelengths", vec![3], wavelengths),
+        ])
+    }
+
+    fn bxdf(brdf: MeasuredBRDF) -> MeasuredBxDF {
+        MeasuredBxDF::new(Arc::new(brdf), SampledWavelengths::sample_uniform(0.3))
+    }
+
+    fn merl() -> MeasuredBxDF {
+        bxdf(MeasuredBRDF::Merl(MerlBRDF::parse(&merl_file()).unwrap()))
+    }
+
+    fn rgl() -> MeasuredBxDF {
+        bxdf(MeasuredBRDF::Rgl(Box::new(RglBRDF::parse(&rgl_file()).unwrap())))
+    }
+
+    tested_without_mode!(MeasuredBxDF);
+
+    #[test]
+    fn measured_consistency() {
+        let checks = Checks::new(&[0.2, 0.7, -0.5]).with_pdf_tolerance(512, 0.02).with_estimate(0.02);
+        check_bxdf("merl", &merl(), &checks);
+        check_bxdf("rgl", &rgl(), &checks);
+    }
+
+    #[test]
+    fn merl_reciprocity() {
+        // Exchanging wo and wi keeps the half vector and theta_d and moves
+        // phi_d by pi, which the table folds onto the same entry. RGL tables
+        // are parameterized by wo and reciprocal only as far as the
+        // measurement is.
+        check_bxdf("merl", &merl(), &Checks::new(&[]).with_reciprocity(1.0, 1e-4));
+    }
+
+    #[test]
+    fn overflowing_tensor_offsets_are_rejected() {
+        let mut rgl = rgl_file();
+        // Data offset of the first field ("theta_i"), then its shape
+        let at = 18 + 2 + "theta_i".len() + 3;
+        rgl[at..at + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        let err = RglBRDF::parse(&rgl).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut rgl = rgl_file();
+        rgl[at + 8..at + 16].copy_from_slice(&(u64::MAX / 2).to_le_bytes());
+        assert_eq!(RglBRDF::parse(&rgl).err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn truncated_files_are_rejected() {
+        let merl = merl_file();
+        assert!(MerlBRDF::parse(&merl[..merl.len() - 1]).is_err());
+        let rgl = rgl_file();
+        assert!(RglBRDF::parse(&rgl[..rgl.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,596 @@
+use std::f32::consts::PI;
+use std::io;
+use std::sync::Arc;
+
+use crate::core::bsdf::Frame;
+use crate::core::geometry::{Bounds3, Normal3, Point2, Point3, Vector3};
+use crate::core::nanovdb::NanoVDBGrid;
+use crate::core::ray::Ray;
+use crate::core::spectrum::{BlackbodySpectrum, SampledSpectrum, SampledWavelengths};
+
+// --- 1. Henyey-Greenstein Phase Function ---
+//
+// Convention (as in pbrt-v4): both wo and wi point away from the scattering
+// point, so forward scattering (g > 0) favours wi close to -wo.
+pub fn henyey_greenstein(cos_theta: f32, g: f32) -> f32 {
+    let denom = 1.0 + g * g + 2.0 * g * cos_theta;
+    (1.0 / (4.0 * PI)) * (1.0 - g * g) / (denom * denom.max(0.0).sqrt())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HGPhaseFunction {
+    pub g: f32, // Asymmetry: -1 = back scattering, 0 = isotropic, 1 = forward
+}
+
+impl HGPhaseFunction {
+    pub fn new(g: f32) -> Self {
+        HGPhaseFunction { g: g.clamp(-0.99, 0.99) }
+    }
+
+    pub fn p(&self, wo: Vector3, wi: Vector3) -> f32 {
+        henyey_greenstein(wo.dot(wi), self.g)
+    }
+
+    // Returns (p, wi, pdf). The sampling is exact, so p == pdf.
+    pub fn sample_p(&self, wo: Vector3, u: Point2) -> Option<(f32, Vector3, f32)> {
+        let g = self.g;
+        let cos_theta = if g.abs() < 1e-3 {
+            1.0 - 2.0 * u.x
+        } else {
+            let sqr_term = (1.0 - g * g) / (1.0 + g - 2.0 * g * u.x);
+            -(1.0 + g * g - sqr_term * sqr_term) / (2.0 * g)
+        };
+        let cos_theta = cos_theta.clamp(-1.0, 1.0);
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+        let phi = 2.0 * PI * u.y;
+
+        let local = Vector3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta);
+        let wi = Frame::from_z(wo).from_local(local);
+        let pdf = henyey_greenstein(cos_theta, g);
+        Some((pdf, wi, pdf))
+    }
+}
+
+// --- 2. The Medium Trait ---
+
+/// Local scattering properties at a point inside a medium
+pub struct MediumProperties {
+    pub sigma_a: SampledSpectrum, // Absorption coefficient
+    pub sigma_s: SampledSpectrum, // Scattering coefficient
+    pub phase: HGPhaseFunction,
+    pub le: SampledSpectrum,      // Emitted radiance (volumetric emission)
+}
+
+/// A ray interval with a bound on the extinction coefficient inside it.
+/// `sigma_min <= sigma_t(p) <= sigma_maj` must hold for every point in [t_min, t_max].
+/// Homogeneous segments have sigma_min == sigma_maj and need no tracking.
+#[derive(Debug, Clone, Copy)]
+pub struct MajorantSegment {
+    pub t_min: f32,
+    pub t_max: f32,
+    pub sigma_min: SampledSpectrum,
+    pub sigma_maj: SampledSpectrum,
+}
+
+/// Participating medium interface used by the volumetric path tracer
+pub trait Medium: Send + Sync {
+    fn sample_point(&self, p: Point3, lambda: &SampledWavelengths) -> MediumProperties;
+
+    /// Bounding segments of the extinction along `ray` up to `t_max`
+    /// (ray parameter units; segments are ordered front to back).
+    fn majorants(&self, ray: &Ray, t_max: f32) -> Vec<MajorantSegment>;
+}
+
+// --- 3. Medium Interface (attached to primitives) ---
+
+/// The media on either side of a surface. `outside` is the side the geometric
+/// normal points to. A primitive without a material but with an interface is
+/// an invisible boundary (e.g. the surface of a fog volume).
+#[derive(Clone, Default)]
+pub struct MediumInterface {
+    pub inside: Option<Arc<dyn Medium>>,
+    pub outside: Option<Arc<dyn Medium>>,
+}
+
+impl MediumInterface {
+    pub fn new(inside: Option<Arc<dyn Medium>>, outside: Option<Arc<dyn Medium>>) -> Self {
+        MediumInterface { inside, outside }
+    }
+
+    /// Medium a ray leaving the surface along `w` travels through
+    pub fn medium_for(&self, w: Vector3, n: Normal3) -> Option<Arc<dyn Medium>> {
+        if w.dot(Vector3::from(n)) > 0.0 {
+            self.outside.clone()
+        } else {
+            self.inside.clone()
+        }
+    }
+}
+
+impl std::fmt::Debug for MediumInterface {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MediumInterface")
+            .field("inside", &self.inside.is_some())
+            .field("outside", &self.outside.is_some())
+            .finish()
+    }
+}
+
+// --- 4. Homogeneous Medium (fog, murky water) ---
+pub struct HomogeneousMedium {
+    pub sigma_a: SampledSpectrum,
+    pub sigma_s: SampledSpectrum,
+    pub phase: HGPhaseFunction,
+}
+
+impl HomogeneousMedium {
+    pub fn new(sigma_a: SampledSpectrum, sigma_s: SampledSpectrum, g: f32) -> Self {
+        HomogeneousMedium { sigma_a, sigma_s, phase: HGPhaseFunction::new(g) }
+    }
+}
+
+impl Medium for HomogeneousMedium {
+    fn sample_point(&self, _p: Point3, _lambda: &SampledWavelengths) -> MediumProperties {
+        MediumProperties {
+            sigma_a: self.sigma_a,
+            sigma_s: self.sigma_s,
+            phase: self.phase,
+            le: SampledSpectrum::new(0.0),
+        }
+    }
+
+    fn majorants(&self, _ray: &Ray, t_max: f32) -> Vec<MajorantSegment> {
+        let sigma_t = self.sigma_a + self.sigma_s;
+        vec![MajorantSegment { t_min: 0.0, t_max, sigma_min: sigma_t, sigma_maj: sigma_t }]
+    }
+}
+
+// --- 5. Sampled Grid ---
+//
+// Scalar values on a regular nx * ny * nz lattice over the unit cube, with
+// samples at voxel centers. Lookups outside the lattice return zero.
+#[derive(Debug, Clone)]
+pub struct SampledGrid {
+    pub nx: usize,
+    pub ny: usize,
+    pub nz: usize,
+    values: Vec<f32>,
+}
+
+impl SampledGrid {
+    pub fn new(nx: usize, ny: usize, nz: usize, values: Vec<f32>) -> Self {
+        assert_eq!(values.len(), nx * ny * nz, "SampledGrid: value count mismatch");
+        SampledGrid { nx, ny, nz, values }
+    }
+
+    fn at(&self, x: i32, y: i32, z: i32) -> f32 {
+        if x < 0 || y < 0 || z < 0
+            || x >= self.nx as i32 || y >= self.ny as i32 || z >= self.nz as i32
+        {
+            return 0.0;
+        }
+        self.values[(z as usize * self.ny + y as usize) * self.nx + x as usize]
+    }
+
+    /// Trilinear lookup at p in [0,1]^3
+    pub fn lookup(&self, p: Point3) -> f32 {
+        let gx = p.x * self.nx as f32 - 0.5;
+        let gy = p.y * self.ny as f32 - 0.5;
+        let gz = p.z * self.nz as f32 - 0.5;
+        let (ix, iy, iz) = (gx.floor() as i32, gy.floor() as i32, gz.floor() as i32);
+        let (fx, fy, fz) = (gx - ix as f32, gy - iy as f32, gz - iz as f32);
+
+        let lerp = |t: f32, a: f32, b: f32| a * (1.0 - t) + b * t;
+        let d00 = lerp(fx, self.at(ix, iy, iz), self.at(ix + 1, iy, iz));
+        let d10 = lerp(fx, self.at(ix, iy + 1, iz), self.at(ix + 1, iy + 1, iz));
+        let d01 = lerp(fx, self.at(ix, iy, iz + 1), self.at(ix + 1, iy, iz + 1));
+        let d11 = lerp(fx, self.at(ix, iy + 1, iz + 1), self.at(ix + 1, iy + 1, iz + 1));
+        lerp(fz, lerp(fy, d00, d10), lerp(fy, d01, d11))
+    }
+
+    pub fn max_value(&self) -> f32 {
+        self.values.iter().fold(0.0f32, |a, &b| a.max(b))
+    }
+
+    /// Largest value `lookup` can return inside the box [lo, hi] (unit-cube coords)
+    pub fn max_value_in(&self, lo: Point3, hi: Point3) -> f32 {
+        // Sample i influences lookups within one voxel of its center
+        let range = |lo: f32, hi: f32, n: usize| {
+            let i0 = ((lo * n as f32 - 1.5).floor() as i32).max(0);
+            let i1 = ((hi * n as f32 + 0.5).ceil() as i32).min(n as i32 - 1);
+            (i0, i1)
+        };
+        let (x0, x1) = range(lo.x, hi.x, self.nx);
+        let (y0, y1) = range(lo.y, hi.y, self.ny);
+        let (z0, z1) = range(lo.z, hi.z, self.nz);
+
+        let mut max = 0.0f32;
+        for z in z0..=z1 {
+            for y in y0..=y1 {
+                for x in x0..=x1 {
+                    max = max.max(self.at(x, y, z));
+                }
+            }
+        }
+        max
+    }
+
+    /// Raw dense fallback format: nx * ny * nz little-endian f32 values,
+    /// x varying fastest, with no header.
+    pub fn read_raw(filename: &str, nx: usize, ny: usize, nz: usize) -> io::Result<Self> {
+        let bytes = std::fs::read(filename)?;
+        if bytes.len() != nx * ny * nz * 4 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: expected {}x{}x{} f32 values, got {} bytes", filename, nx, ny, nz, bytes.len()),
+            ));
+        }
+        let values = bytes
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect();
+        Ok(SampledGrid::new(nx, ny, nz, values))
+    }
+}
+
+// --- 6. Majorant Grid ---
+//
+// Coarse grid over a medium's bounds storing an upper bound of the density in
+// each cell. Rays walk it with a 3D DDA, producing one majorant segment per
+// cell so that delta/ratio tracking takes short steps only where the medium
+// is actually dense.
+pub struct MajorantGrid {
+    pub bounds: Bounds3,
+    pub res: [usize; 3],
+    voxels: Vec<f32>,
+}
+
+impl MajorantGrid {
+    pub fn new(bounds: Bounds3, res: [usize; 3]) -> Self {
+        MajorantGrid { bounds, res, voxels: vec![0.0; res[0] * res[1] * res[2]] }
+    }
+
+    fn index(&self, x: usize, y: usize, z: usize) -> usize {
+        (z * self.res[1] + y) * self.res[0] + x
+    }
+
+    pub fn lookup(&self, x: usize, y: usize, z: usize) -> f32 {
+        self.voxels[self.index(x, y, z)]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, z: usize, v: f32) {
+        let i = self.index(x, y, z);
+        self.voxels[i] = v;
+    }
+
+    /// Unit-cube bounds of cell (x, y, z)
+    pub fn voxel_bounds(&self, x: usize, y: usize, z: usize) -> (Point3, Point3) {
+        let [nx, ny, nz] = self.res.map(|n| n as f32);
+        (
+            Point3::new(x as f32 / nx, y as f32 / ny, z as f32 / nz),
+            Point3::new((x + 1) as f32 / nx, (y + 1) as f32 / ny, (z + 1) as f32 / nz),
+        )
+    }
+
+    /// Raises every cell overlapping the world-space box `b` to at least `v`
+    pub fn splat_max(&mut self, b: &Bounds3, v: f32) {
+        let d = self.bounds.max - self.bounds.min;
+        let lo = b.min - self.bounds.min;
+        let hi = b.max - self.bounds.min;
+        let cell_range = |lo: f32, hi: f32, extent: f32, n: usize| {
+            let i0 = ((lo / extent * n as f32).floor() as i32).clamp(0, n as i32 - 1) as usize;
+            let i1 = ((hi / extent * n as f32).floor() as i32).clamp(0, n as i32 - 1) as usize;
+            i0..=i1
+        };
+        for z in cell_range(lo.z, hi.z, d.z, self.res[2]) {
+            for y in cell_range(lo.y, hi.y, d.y, self.res[1]) {
+                for x in cell_range(lo.x, hi.x, d.x, self.res[0]) {
+                    let i = self.index(x, y, z);
+                    self.voxels[i] = self.voxels[i].max(v);
+                }
+            }
+        }
+    }
+
+    /// DDA walk along `ray` up to `t_max`; `sigma_t` is the extinction at unit density
+    pub fn segments(&self, ray: &Ray, t_max: f32, sigma_t: SampledSpectrum) -> Vec<MajorantSegment> {
+        let Some((t0, t1)) = self.bounds.intersect_p(ray.o, ray.d, t_max) else {
+            return Vec::new();
+        };
+
+        // Work in grid coordinates [0, res]^3; the ray parameter is unchanged
+        let ext = self.bounds.max - self.bounds.min;
+        let scale = [
+            self.res[0] as f32 / ext.x,
+            self.res[1] as f32 / ext.y,
+            self.res[2] as f32 / ext.z,
+        ];
+        let p0 = ray.at(t0) - self.bounds.min;
+        let p = [p0.x * scale[0], p0.y * scale[1], p0.z * scale[2]];
+        let d = [ray.d.x * scale[0], ray.d.y * scale[1], ray.d.z * scale[2]];
+
+        let mut voxel = [0i32; 3];
+        let mut step = [0i32; 3];
+        let mut next_t = [f32::INFINITY; 3];
+        let mut delta_t = [f32::INFINITY; 3];
+        for a in 0..3 {
+            let n = self.res[a] as i32;
+            voxel[a] = (p[a].floor() as i32).clamp(0, n - 1);
+            if d[a] > 0.0 {
+                step[a] = 1;
+                next_t[a] = t0 + ((voxel[a] + 1) as f32 - p[a]) / d[a];
+                delta_t[a] = 1.0 / d[a];
+            } else if d[a] < 0.0 {
+                step[a] = -1;
+                next_t[a] = t0 + (voxel[a] as f32 - p[a]) / d[a];
+                delta_t[a] = -1.0 / d[a];
+            }
+        }
+
+        let mut segments = Vec::new();
+        let mut t = t0;
+        loop {
+            let axis = if next_t[0] < next_t[1] {
+                if next_t[0] < next_t[2] { 0 } else { 2 }
+            } else if next_t[1] < next_t[2] { 1 } else { 2 };
+            let t_end = next_t[axis].min(t1);
+
+            if t_end > t {
+                let v = self.lookup(voxel[0] as usize, voxel[1] as usize, voxel[2] as usize);
+                segments.push(MajorantSegment {
+                    t_min: t,
+                    t_max: t_end,
+                    sigma_min: SampledSpectrum::new(0.0),
+                    sigma_maj: sigma_t * v,
+                });
+            }
+            if t_end >= t1 {
+                break;
+            }
+
+            t = t_end;
+            voxel[axis] += step[axis];
+            if voxel[axis] < 0 || voxel[axis] >= self.res[axis] as i32 {
+                break;
+            }
+            next_t[axis] += delta_t[axis];
+        }
+        segments
+    }
+}
+
+// --- 7. Blackbody Emission ---
+//
+// Emission driven by a temperature field: Le = le_scale * B(T), with B the
+// peak-normalized blackbody spectrum. The stored value is remapped as
+// T = (value - temperature_offset) * temperature_scale; cooler voxels emit nothing.
+#[derive(Debug, Clone, Copy)]
+pub struct BlackbodyEmission {
+    pub le_scale: f32,
+    pub temperature_offset: f32,
+    pub temperature_scale: f32,
+    pub temperature_cutoff: f32, // Kelvin
+}
+
+impl BlackbodyEmission {
+    pub fn new(le_scale: f32) -> Self {
+        BlackbodyEmission {
+            le_scale,
+            temperature_offset: 0.0,
+            temperature_scale: 1.0,
+            temperature_cutoff: 100.0,
+        }
+    }
+
+    pub fn le(&self, value: f32, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let temp = (value - self.temperature_offset) * self.temperature_scale;
+        if temp <= self.temperature_cutoff || self.le_scale <= 0.0 {
+            return SampledSpectrum::new(0.0);
+        }
+        BlackbodySpectrum::new(temp).sample(lambda) * self.le_scale
+    }
+}
+
+// --- 8. Heterogeneous Grid Medium (smoke) ---
+//
+// The density grid spans `bounds` (world space) and scales sigma_a / sigma_s.
+// Outside the bounds the density is zero. An optional temperature grid over
+// the same bounds adds blackbody emission.
+pub struct GridMedium {
+    pub bounds: Bounds3,
+    pub sigma_a: SampledSpectrum,
+    pub sigma_s: SampledSpectrum,
+    pub phase: HGPhaseFunction,
+    density: SampledGrid,
+    temperature: Option<(SampledGrid, BlackbodyEmission)>,
+    majorant_grid: MajorantGrid,
+}
+
+impl GridMedium {
+    pub fn new(
+        bounds: Bounds3,
+        sigma_a: SampledSpectrum,
+        sigma_s: SampledSpectrum,
+        g: f32,
+        density: SampledGrid,
+    ) -> Self {
+        let mut majorant_grid = MajorantGrid::new(bounds, [16, 16, 16]);
+        for z in 0..16 {
+            for y in 0..16 {
+                for x in 0..16 {
+                    let (lo, hi) = majorant_grid.voxel_bounds(x, y, z);
+                    majorant_grid.set(x, y, z, density.max_value_in(lo, hi));
+                }
+            }
+        }
+
+        GridMedium {
+            bounds,
+            sigma_a,
+            sigma_s,
+            phase: HGPhaseFunction::new(g),
+            density,
+            temperature: None,
+            majorant_grid,
+        }
+    }
+
+    /// Adds blackbody emission from a temperature grid covering the same bounds
+    pub fn with_temperature(mut self, temperature: SampledGrid, emission: BlackbodyEmission) -> Self {
+        self.temperature = Some((temperature, emission));
+        self
+    }
+
+    // World-space point to [0,1]^3 grid coordinates
+    fn to_grid(&self, p: Point3) -> Point3 {
+        let d = self.bounds.max - self.bounds.min;
+        let o = p - self.bounds.min;
+        Point3::new(o.x / d.x, o.y / d.y, o.z / d.z)
+    }
+
+    // Negative voxels (e.g. from signed-distance exports) read as empty
+    pub fn density(&self, p: Point3) -> f32 {
+        if !self.bounds.inside(p) {
+            return 0.0;
+        }
+        self.density.lookup(self.to_grid(p)).max(0.0)
+    }
+}
+
+impl Medium for GridMedium {
+    fn sample_point(&self, p: Point3, lambda: &SampledWavelengths) -> MediumProperties {
+        let density = self.density(p);
+        let le = match &self.temperature {
+            Some((grid, emission)) if self.bounds.inside(p) => {
+                emission.le(grid.lookup(self.to_grid(p)), lambda)
+            }
+            _ => SampledSpectrum::new(0.0),
+        };
+        MediumProperties {
+            sigma_a: self.sigma_a * density,
+            sigma_s: self.sigma_s * density,
+            phase: self.phase,
+            le,
+        }
+    }
+
+    fn majorants(&self, ray: &Ray, t_max: f32) -> Vec<MajorantSegment> {
+        self.majorant_grid.segments(ray, t_max, self.sigma_a + self.sigma_s)
+    }
+}
+
+// --- 9. NanoVDB Medium (sparse smoke / fire) ---
+//
+// Density (and optionally temperature) come from NanoVDB float grids, which
+// carry their own index -> world transform. Emission is blackbody radiation
+// from the temperature grid.
+pub struct NanoVDBMedium {
+    pub bounds: Bounds3,
+    pub sigma_a: SampledSpectrum,
+    pub sigma_s: SampledSpectrum,
+    pub phase: HGPhaseFunction,
+    density: NanoVDBGrid,
+    temperature: Option<(NanoVDBGrid, BlackbodyEmission)>,
+    majorant_grid: MajorantGrid,
+}
+
+impl NanoVDBMedium {
+    pub fn new(density: NanoVDBGrid, sigma_a: SampledSpectrum, sigma_s: SampledSpectrum, g: f32) -> Self {
+        let bounds = density.world_bounds();
+
+        // Rasterize the tree's tiles and leaves (with the trilinear footprint)
+        let mut majorant_grid = MajorantGrid::new(bounds, [64, 64, 64]);
+        let background = density.background().max(0.0);
+        if background > 0.0 {
+            majorant_grid.splat_max(&bounds, background);
+        }
+        density.for_each_region(|lo, hi, v| {
+            if v > 0.0 {
+                let lo = lo.map(|c| c as f32 - 1.0);
+                let hi = hi.map(|c| c as f32 + 1.0);
+                majorant_grid.splat_max(&density.index_box_to_world(lo, hi), v);
+            }
+        });
+
+        NanoVDBMedium {
+            bounds,
+            sigma_a,
+            sigma_s,
+            phase: HGPhaseFunction::new(g),
+            density,
+            temperature: None,
+            majorant_grid,
+        }
+    }
+
+    /// Loads the "density" grid and, if present and `le_scale > 0`, the
+    /// "temperature" grid from a .nvdb file.
+    pub fn read(
+        filename: &str,
+        sigma_a: SampledSpectrum,
+        sigma_s: SampledSpectrum,
+        g: f32,
+        le_scale: f32,
+    ) -> io::Result<Self> {
+        let density = NanoVDBGrid::read(filename, "density")?;
+        let medium = NanoVDBMedium::new(density, sigma_a, sigma_s, g);
+        let has_temperature = NanoVDBGrid::grid_names(filename)?.iter().any(|n| n == "temperature");
+        if le_scale > 0.0 && has_temperature {
+            let temperature = NanoVDBGrid::read(filename, "temperature")?;
+            return Ok(medium.with_temperature(temperature, BlackbodyEmission::new(le_scale)));
+        }
+        Ok(medium)
+    }
+
+    pub fn with_temperature(mut self, temperature: NanoVDBGrid, emission: BlackbodyEmission) -> Self {
+        self.temperature = Some((temperature, emission));
+        self
+    }
+}
+
+impl Medium for NanoVDBMedium {
+    fn sample_point(&self, p: Point3, lambda: &SampledWavelengths) -> MediumProperties {
+        let (density, le) = if self.bounds.inside(p) {
+            let le = match &self.temperature {
+                Some((grid, emission)) => emission.le(grid.lookup(p), lambda),
+                None => SampledSpectrum::new(0.0),
+            };
+            (self.density.lookup(p).max(0.0), le)
+        } else {
+            (0.0, SampledSpectrum::new(0.0))
+        };
+        MediumProperties {
+            sigma_a: self.sigma_a * density,
+            sigma_s: self.sigma_s * density,
+            phase: self.phase,
+            le,
+        }
+    }
+
+    fn majorants(&self, ray: &Ray, t_max: f32) -> Vec<MajorantSegment> {
+        self.majorant_grid.segments(ray, t_max, self.sigma_a + self.sigma_s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_grid_voxels_do_not_emit_negative_coefficients() {
+        let mut values = vec![1.0; 8];
+        values[3] = -4.0;
+        let bounds = Bounds3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+        let sigma = SampledSpectrum::new(0.5);
+        let medium = GridMedium::new(bounds, sigma, sigma, 0.0, SampledGrid::new(2, 2, 2, values));
+        let lambda = SampledWavelengths::sample_uniform(0.5);
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            let p = Point3::new(t, t, 0.5 * t);
+            let mp = medium.sample_point(p, &lambda);
+            assert!(mp.sigma_a.values.iter().chain(&mp.sigma_s.values).all(|&s| s >= 0.0), "negative sigma at {:?}", p);
+        }
+        // The negative voxel's center (x = y = 1, z = 0) is empty
+        assert_eq!(medium.density(Point3::new(0.75, 0.75, 0.25)), 0.0);
+    }
+}
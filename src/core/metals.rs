@@ -0,0 +1,91 @@
+use crate::core::spectrum::{PiecewiseLinearSpectrum, Spectrum};
+
+// --- Measured Metals ---
+// Complex refractive index (eta + i k) of common metals, as (wavelength nm,
+// eta, k) samples across the visible range. Approximate tabulations of
+// published measurements: Johnson & Christy (Au, Ag, Cu, Cr, Ti, Fe) and
+// Rakić (Al), thinned to a handful of samples per metal.
+
+const AU: [(f32, f32, f32); 16] = [
+    (360.0, 1.41, 1.82), (381.5, 1.46, 1.93), (397.4, 1.47, 1.95), (413.3, 1.46, 1.96),
+    (430.5, 1.45, 1.95), (450.9, 1.38, 1.91), (471.4, 1.31, 1.85), (495.9, 1.04, 1.83),
+    (520.9, 0.62, 2.08), (548.6, 0.43, 2.46), (582.1, 0.29, 2.86), (616.8, 0.21, 3.27),
+    (659.5, 0.14, 3.70), (704.4, 0.13, 4.10), (756.0, 0.14, 4.54), (830.0, 0.16, 5.08),
+];
+
+const AG: [(f32, f32, f32); 16] = [
+    (360.0, 0.06, 1.70), (381.5, 0.05, 1.86), (397.4, 0.05, 2.07), (413.3, 0.05, 2.28),
+    (430.5, 0.04, 2.46), (450.9, 0.04, 2.66), (471.4, 0.05, 2.87), (495.9, 0.05, 3.09),
+    (520.9, 0.05, 3.32), (548.6, 0.06, 3.59), (582.1, 0.05, 3.86), (616.8, 0.06, 4.15),
+    (659.5, 0.05, 4.48), (704.4, 0.04, 4.84), (756.0, 0.03, 5.24), (830.0, 0.04, 5.73),
+];
+
+const CU: [(f32, f32, f32); 20] = [
+    (360.0, 1.27, 1.95), (375.7, 1.23, 2.02), (387.5, 1.18, 2.21), (399.9, 1.18, 2.13),
+    (413.3, 1.18, 2.21), (427.5, 1.18, 2.29), (442.8, 1.17, 2.36), (459.2, 1.16, 2.43),
+    (476.9, 1.15, 2.50), (495.9, 1.14, 2.56), (516.6, 1.12, 2.61), (539.1, 1.04, 2.58),
+    (563.6, 0.83, 2.60), (590.4, 0.47, 2.81), (619.9, 0.27, 3.24), (652.5, 0.21, 3.67),
+    (688.8, 0.21, 4.05), (729.3, 0.22, 4.43), (774.9, 0.25, 4.82), (830.0, 0.27, 5.31),
+];
+
+const AL: [(f32, f32, f32); 12] = [
+    (360.0, 0.40, 4.40), (400.0, 0.49, 4.86), (450.0, 0.62, 5.47), (500.0, 0.77, 6.08),
+    (550.0, 0.96, 6.69), (600.0, 1.20, 7.26), (650.0, 1.47, 7.79), (700.0, 1.83, 8.31),
+    (750.0, 2.40, 8.62), (800.0, 2.80, 8.45), (815.0, 2.76, 8.38), (830.0, 2.70, 8.30),
+];
+
+const CR: [(f32, f32, f32); 10] = [
+    (360.0, 1.85, 3.05), (400.0, 2.10, 3.20), (450.0, 2.40, 3.30), (500.0, 2.75, 3.35),
+    (550.0, 3.05, 3.35), (600.0, 3.25, 3.40), (650.0, 3.40, 3.50), (700.0, 3.55, 3.65),
+    (800.0, 3.75, 4.00), (830.0, 3.80, 4.10),
+];
+
+const TI: [(f32, f32, f32); 10] = [
+    (360.0, 1.75, 2.45), (400.0, 1.90, 2.70), (450.0, 2.10, 2.95), (500.0, 2.30, 3.15),
+    (550.0, 2.55, 3.35), (600.0, 2.80, 3.45), (650.0, 3.05, 3.50), (700.0, 3.30, 3.55),
+    (800.0, 3.70, 3.70), (830.0, 3.80, 3.75),
+];
+
+const FE: [(f32, f32, f32); 8] = [
+    (360.0, 2.10, 2.60), (400.0, 2.30, 2.75), (500.0, 2.65, 2.95), (600.0, 2.90, 3.10),
+    (700.0, 3.05, 3.35), (750.0, 3.10, 3.50), (800.0, 3.15, 3.65), (830.0, 3.18, 3.75),
+];
+
+/// Names understood by `metal_ior`
+pub const METAL_NAMES: [&str; 7] = ["Au", "Ag", "Cu", "Al", "Cr", "Ti", "Fe"];
+
+/// Spectral (eta, k) of a metal from the built-in library, by chemical symbol
+pub fn metal_ior(name: &str) -> Option<(Spectrum, Spectrum)> {
+    let table: &[(f32, f32, f32)] = match name {
+        "Au" => &AU,
+        "Ag" => &AG,
+        "Cu" => &CU,
+        "Al" => &AL,
+        "Cr" => &CR,
+        "Ti" => &TI,
+        "Fe" => &FE,
+        _ => return None,
+    };
+    let lambda: Vec<f32> = table.iter().map(|s| s.0).collect();
+    let eta = PiecewiseLinearSpectrum::new(lambda.clone(), table.iter().map(|s| s.1).collect());
+    let k = PiecewiseLinearSpectrum::new(lambda, table.iter().map(|s| s.2).collect());
+    Some((Spectrum::PiecewiseLinear(eta), Spectrum::PiecewiseLinear(k)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::reflection::fr_conductor;
+    use crate::core::spectrum::SampledWavelengths;
+
+    #[test]
+    fn library_metals_are_shiny() {
+        let lambdas = SampledWavelengths::sample_uniform(0.5);
+        for name in METAL_NAMES {
+            let (eta, k) = metal_ior(name).unwrap();
+            let f0 = fr_conductor(1.0, eta.sample(&lambdas), k.sample(&lambdas));
+            assert!(f0.values.iter().all(|&r| (0.3..1.0).contains(&r)), "{}: {:?}", name, f0.values);
+        }
+        assert!(metal_ior("Xx").is_none());
+    }
+}
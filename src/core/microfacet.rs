@@ -50,6 +50,18 @@ impl TrowbridgeReitzDistribution {
         1.0 / (PI * self.alpha_x * self.alpha_y * cos4 * (1.0 + e) * (1.0 + e))
     }
 
+    // Too narrow to sample or evaluate as a microfacet lobe (alpha at its clamp floor)
+    pub fn effectively_smooth(&self) -> bool {
+        self.alpha_x.max(self.alpha_y) <= 0.001
+    }
+
+    // Density of `sample_wh`: normals visible from w, G1(w) max(0, w.wh) D(wh) / cos(w)
+    pub fn pdf(&self, w: Vector3, wh: Vector3) -> f32 {
+        let cos_theta_w = cos_theta(w).abs();
+        if cos_theta_w == 0.0 { return 0.0; }
+        self.g1(w) / cos_theta_w * self.d(wh) * w.dot(wh).abs()
+    }
+
     // Auxiliary Lambda function for Smith G
     fn lambda(&self, w: Vector3) -> f32 {
         let abs_tan_theta =  w.z.abs().max(1e-9).recip() * (1.0 - w.z * w.z).max(0.0).sqrt(); 
@@ -98,7 +110,8 @@ impl TrowbridgeReitzDistribution {
 
         // D. Warp to Hemisphere
         let h = (1.0 - p.x * p.x).max(0.0).sqrt();
-        p.y = (1.0 - h) / 2.0 * (1.0 + wh.z) + h * p.y;
+        let t = (1.0 + wh.z) / 2.0;
+        p.y = (1.0 - t) * h + t * p.y;
         let pz = (1.0 - p.x*p.x - p.y*p.y).max(0.0).sqrt();
 
         // E. Reproject
@@ -1,6 +1,5 @@
-use crate::core::geometry::{Point2, Vector3};
+use crate::core::geometry::Point2;
 use crate::core::spectrum::SampledSpectrum;
-use std::sync::Arc;
 
 // A simplified MIP Map that currently only holds the base image (Level 0).
 // In Week 6 Day 6, we will extend this to hold the full pyramid.
@@ -6,8 +6,12 @@ pub mod interaction; // NEW
 pub mod primitive;   // NEW
 pub mod spectrum; // <--- NEW
 pub mod reflection;
+pub mod metals;
 pub mod microfacet; // <--- NEW
+pub mod albedo;
 pub mod bsdf; // <--- NEW
+pub mod measured;
+pub mod ies;
 pub mod camera;
 pub mod sampler;   // <--- NEW
 pub mod film;      // <--- NEW
@@ -18,4 +22,9 @@ pub mod imagemap;  // <--- NEW
 pub mod bssrdf; // <--- NEW
 pub mod noise; // <--- NEW
 pub mod material; // <--- NEW
-pub mod light; // <--- NEW
\ No newline at end of file
+pub mod light; // <--- NEW
+pub mod medium;
+pub mod nanovdb;
+pub mod lightsampler;
+pub mod scene;
+pub mod sky;
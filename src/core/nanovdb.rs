@@ -0,0 +1,511 @@
+use std::io;
+
+use crate::core::geometry::{Bounds3, Point3};
+
+// --- NanoVDB Float Grid Reader ---
+//
+// Reads uncompressed float grids from NanoVDB files (.nvdb, format major
+// version 32). A grid is a single flat buffer holding a 4-level tree:
+//
+//   Root (hash of tiles) -> Upper (32^3) -> Lower (16^3) -> Leaf (8^3 voxels)
+//
+// Child links are byte offsets relative to the parent node, so we keep the
+// raw buffer and walk it in place. Layout reference: nanovdb/NanoVDB.h.
+
+const MAGIC_V0: u64 = 0x304244566f6e614e; // "NanoVDB0" (legacy file/grid magic)
+const MAGIC_GRID: u64 = 0x314244566f6e614e; // "NanoVDB1"
+const MAGIC_FILE: u64 = 0x324244566f6e614e; // "NanoVDB2"
+const SUPPORTED_MAJOR: u32 = 32;
+const GRID_TYPE_FLOAT: u32 = 1;
+
+// Sizes / offsets of the fixed-layout structs
+const FILE_HEADER_SIZE: usize = 16;
+const FILE_META_SIZE: usize = 176;
+const GRID_DATA_SIZE: usize = 672;
+const ROOT_DATA_SIZE: usize = 64;
+const ROOT_TILE_SIZE: usize = 32;
+const UPPER_TABLE: usize = 8256; // Upper node: bbox, flags, 2 x 4096-byte masks, stats
+const LOWER_TABLE: usize = 1088; // Lower node: bbox, flags, 2 x 512-byte masks, stats
+const LEAF_VALUES: usize = 96;
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("NanoVDB: {}", msg))
+}
+
+fn read_u16(b: &[u8], off: usize) -> u16 { u16::from_le_bytes(b[off..off + 2].try_into().unwrap()) }
+fn read_u32(b: &[u8], off: usize) -> u32 { u32::from_le_bytes(b[off..off + 4].try_into().unwrap()) }
+fn read_i32(b: &[u8], off: usize) -> i32 { i32::from_le_bytes(b[off..off + 4].try_into().unwrap()) }
+fn read_u64(b: &[u8], off: usize) -> u64 { u64::from_le_bytes(b[off..off + 8].try_into().unwrap()) }
+fn read_i64(b: &[u8], off: usize) -> i64 { i64::from_le_bytes(b[off..off + 8].try_into().unwrap()) }
+fn read_f32(b: &[u8], off: usize) -> f32 { f32::from_le_bytes(b[off..off + 4].try_into().unwrap()) }
+fn read_f64(b: &[u8], off: usize) -> f64 { f64::from_le_bytes(b[off..off + 8].try_into().unwrap()) }
+
+fn read_coord(b: &[u8], off: usize) -> [i32; 3] {
+    [read_i32(b, off), read_i32(b, off + 4), read_i32(b, off + 8)]
+}
+
+fn mask_is_on(b: &[u8], mask_off: usize, n: usize) -> bool {
+    read_u64(b, mask_off + (n >> 6) * 8) & (1u64 << (n & 63)) != 0
+}
+
+fn child_offset(node: usize, rel: i64) -> usize {
+    (node as i64 + rel) as usize
+}
+
+// Byte sizes of the internal / leaf nodes (header + child table or values)
+const UPPER_SIZE: usize = UPPER_TABLE + 8 * 32768;
+const LOWER_SIZE: usize = LOWER_TABLE + 8 * 4096;
+const LEAF_SIZE: usize = LEAF_VALUES + 4 * 512;
+
+// Checked `child_offset`: the child must start and end inside the buffer
+fn checked_child(len: usize, node: usize, rel: i64, size: usize) -> io::Result<usize> {
+    (node as i64)
+        .checked_add(rel)
+        .and_then(|c| usize::try_from(c).ok())
+        .filter(|c| c.checked_add(size).is_some_and(|end| end <= len))
+        .ok_or_else(|| invalid("child node out of range"))
+}
+
+// Checks every node reachable from the root, so that `value` and
+// `for_each_region` can walk the buffer without bounds errors.
+fn validate_tree(d: &[u8], root: usize, table_size: usize) -> io::Result<()> {
+    for i in 0..table_size {
+        let tile = root + ROOT_DATA_SIZE + i * ROOT_TILE_SIZE;
+        let child = read_i64(d, tile + 8);
+        if child == 0 {
+            continue;
+        }
+        let upper = checked_child(d.len(), root, child, UPPER_SIZE)?;
+        for n in 0..32768 {
+            if !mask_is_on(d, upper + 32 + 4096, n) {
+                continue;
+            }
+            let lower = checked_child(d.len(), upper, read_i64(d, upper + UPPER_TABLE + 8 * n), LOWER_SIZE)?;
+            for m in 0..4096 {
+                if mask_is_on(d, lower + 32 + 512, m) {
+                    checked_child(d.len(), lower, read_i64(d, lower + LOWER_TABLE + 8 * m), LEAF_SIZE)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A float grid (density, temperature, ...) loaded from a NanoVDB file.
+/// Values are located at integer index coordinates; `lookup` interpolates
+/// them trilinearly in world space.
+pub struct NanoVDBGrid {
+    data: Vec<u8>,
+    root: usize,
+    table_size: usize,
+    background: f32,
+    index_min: [i32; 3], // Bounding box of active voxels (inclusive)
+    index_max: [i32; 3],
+    mat: [f32; 9],       // Index -> world affine map (row-major 3x3 + translation)
+    inv_mat: [f32; 9],
+    vec: [f32; 3],
+}
+
+impl NanoVDBGrid {
+    /// Loads the float grid called `name` from a .nvdb file
+    pub fn read(filename: &str, name: &str) -> io::Result<Self> {
+        let bytes = std::fs::read(filename)?;
+        let (offset, size) = find_grid(&bytes, name)?
+            .ok_or_else(|| invalid(&format!("no grid named '{}' in {}", name, filename)))?;
+        Self::from_buffer(bytes[offset..offset + size].to_vec(), name)
+    }
+
+    /// Lists the grid names stored in a .nvdb file
+    pub fn grid_names(filename: &str) -> io::Result<Vec<String>> {
+        let bytes = std::fs::read(filename)?;
+        let mut names = Vec::new();
+        for_each_grid(&bytes, |name, _, _| names.push(name.to_string()))?;
+        Ok(names)
+    }
+
+    fn from_buffer(data: Vec<u8>, name: &str) -> io::Result<Self> {
+        if data.len() < GRID_DATA_SIZE + 64 {
+            return Err(invalid("grid buffer too small"));
+        }
+        let magic = read_u64(&data, 0);
+        if magic != MAGIC_V0 && magic != MAGIC_GRID {
+            return Err(invalid("bad grid magic number"));
+        }
+        if read_u32(&data, 16) >> 21 != SUPPORTED_MAJOR {
+            return Err(invalid("unsupported grid version"));
+        }
+        if read_u32(&data, 636) != GRID_TYPE_FLOAT {
+            return Err(invalid(&format!("grid '{}' is not a float grid", name)));
+        }
+
+        // Map (double precision copy, offset 296 + 88)
+        let mut mat = [0.0; 9];
+        let mut inv_mat = [0.0; 9];
+        let mut vec = [0.0; 3];
+        for i in 0..9 {
+            mat[i] = read_f64(&data, 384 + 8 * i) as f32;
+            inv_mat[i] = read_f64(&data, 456 + 8 * i) as f32;
+        }
+        for (i, v) in vec.iter_mut().enumerate() {
+            *v = read_f64(&data, 528 + 8 * i) as f32;
+        }
+
+        // Tree: node offsets are relative to the tree header
+        let tree = GRID_DATA_SIZE;
+        let root = usize::try_from(read_u64(&data, tree + 24))
+            .ok()
+            .and_then(|rel| rel.checked_add(tree))
+            .filter(|root| root.checked_add(ROOT_DATA_SIZE).is_some_and(|end| end <= data.len()))
+            .ok_or_else(|| invalid("root node out of range"))?;
+        let table_size = read_u32(&data, root + 24) as usize;
+        if root + ROOT_DATA_SIZE + table_size * ROOT_TILE_SIZE > data.len() {
+            return Err(invalid("root table out of range"));
+        }
+        validate_tree(&data, root, table_size)?;
+
+        Ok(NanoVDBGrid {
+            root,
+            table_size,
+            background: read_f32(&data, root + 28),
+            index_min: read_coord(&data, root),
+            index_max: read_coord(&data, root + 12),
+            data,
+            mat,
+            inv_mat,
+            vec,
+        })
+    }
+
+    fn index_to_world(&self, p: [f32; 3]) -> Point3 {
+        let m = &self.mat;
+        Point3::new(
+            m[0] * p[0] + m[1] * p[1] + m[2] * p[2] + self.vec[0],
+            m[3] * p[0] + m[4] * p[1] + m[5] * p[2] + self.vec[1],
+            m[6] * p[0] + m[7] * p[1] + m[8] * p[2] + self.vec[2],
+        )
+    }
+
+    fn world_to_index(&self, p: Point3) -> [f32; 3] {
+        let m = &self.inv_mat;
+        let (x, y, z) = (p.x - self.vec[0], p.y - self.vec[1], p.z - self.vec[2]);
+        [
+            m[0] * x + m[1] * y + m[2] * z,
+            m[3] * x + m[4] * y + m[5] * z,
+            m[6] * x + m[7] * y + m[8] * z,
+        ]
+    }
+
+    /// World-space bounds of an index-space box (conservative under rotation)
+    pub fn index_box_to_world(&self, lo: [f32; 3], hi: [f32; 3]) -> Bounds3 {
+        let mut b = Bounds3::new(self.index_to_world(lo), self.index_to_world(lo));
+        for i in 1..8 {
+            let corner = [
+                if i & 1 != 0 { hi[0] } else { lo[0] },
+                if i & 2 != 0 { hi[1] } else { lo[1] },
+                if i & 4 != 0 { hi[2] } else { lo[2] },
+            ];
+            b = b.union_point(self.index_to_world(corner));
+        }
+        b
+    }
+
+    /// World-space region where the grid can be non-background
+    /// (active voxels plus the one-voxel trilinear footprint).
+    pub fn world_bounds(&self) -> Bounds3 {
+        let lo = self.index_min.map(|v| v as f32 - 1.0);
+        let hi = self.index_max.map(|v| v as f32 + 1.0);
+        self.index_box_to_world(lo, hi)
+    }
+
+    pub fn background(&self) -> f32 {
+        self.background
+    }
+
+    /// Value of voxel `ijk` (tree traversal from the root; node offsets were
+    /// checked by `from_buffer`)
+    pub fn value(&self, ijk: [i32; 3]) -> f32 {
+        let d = &self.data;
+        let key = ((ijk[2] as u32 >> 12) as u64)
+            | (((ijk[1] as u32 >> 12) as u64) << 21)
+            | (((ijk[0] as u32 >> 12) as u64) << 42);
+
+        // Root: linear scan over the (small) tile table
+        let tile = (0..self.table_size)
+            .map(|i| self.root + ROOT_DATA_SIZE + i * ROOT_TILE_SIZE)
+            .find(|&t| read_u64(d, t) == key);
+        let Some(tile) = tile else { return self.background; };
+        let child = read_i64(d, tile + 8);
+        if child == 0 {
+            return read_f32(d, tile + 20);
+        }
+
+        // Upper internal node (32^3 children of 128^3 voxels)
+        let upper = child_offset(self.root, child);
+        let n = ((((ijk[0] & 4095) >> 7) << 10) | (((ijk[1] & 4095) >> 7) << 5) | ((ijk[2] & 4095) >> 7)) as usize;
+        let entry = upper + UPPER_TABLE + 8 * n;
+        if !mask_is_on(d, upper + 32 + 4096, n) {
+            return read_f32(d, entry);
+        }
+
+        // Lower internal node (16^3 children of 8^3 voxels)
+        let lower = child_offset(upper, read_i64(d, entry));
+        let n = ((((ijk[0] & 127) >> 3) << 8) | (((ijk[1] & 127) >> 3) << 4) | ((ijk[2] & 127) >> 3)) as usize;
+        let entry = lower + LOWER_TABLE + 8 * n;
+        if !mask_is_on(d, lower + 32 + 512, n) {
+            return read_f32(d, entry);
+        }
+
+        // Leaf
+        let leaf = child_offset(lower, read_i64(d, entry));
+        let n = (((ijk[0] & 7) << 6) | ((ijk[1] & 7) << 3) | (ijk[2] & 7)) as usize;
+        read_f32(d, leaf + LEAF_VALUES + 4 * n)
+    }
+
+    /// Trilinearly interpolated value at a world-space point
+    pub fn lookup(&self, p: Point3) -> f32 {
+        let pi = self.world_to_index(p);
+        let base = pi.map(|v| v.floor() as i32);
+        let f = [pi[0] - base[0] as f32, pi[1] - base[1] as f32, pi[2] - base[2] as f32];
+
+        let v = |dx: i32, dy: i32, dz: i32| self.value([base[0] + dx, base[1] + dy, base[2] + dz]);
+        let lerp = |t: f32, a: f32, b: f32| a * (1.0 - t) + b * t;
+        let d00 = lerp(f[0], v(0, 0, 0), v(1, 0, 0));
+        let d10 = lerp(f[0], v(0, 1, 0), v(1, 1, 0));
+        let d01 = lerp(f[0], v(0, 0, 1), v(1, 0, 1));
+        let d11 = lerp(f[0], v(0, 1, 1), v(1, 1, 1));
+        lerp(f[2], lerp(f[1], d00, d10), lerp(f[1], d01, d11))
+    }
+
+    /// Calls `f(min, max, value_max)` for every tile and leaf of the tree,
+    /// with inclusive index-space voxel ranges. Used to build majorant grids
+    /// without touching every voxel through `value`.
+    pub fn for_each_region<F: FnMut([i32; 3], [i32; 3], f32)>(&self, mut f: F) {
+        let d = &self.data;
+        let region = |origin: [i32; 3], size: i32| (origin, origin.map(|v| v + size - 1));
+
+        for i in 0..self.table_size {
+            let tile = self.root + ROOT_DATA_SIZE + i * ROOT_TILE_SIZE;
+            let key = read_u64(d, tile);
+            let origin = [
+                (((key >> 42) & 0x1fffff) as u32).wrapping_shl(12) as i32,
+                (((key >> 21) & 0x1fffff) as u32).wrapping_shl(12) as i32,
+                ((key & 0x1fffff) as u32).wrapping_shl(12) as i32,
+            ];
+            let child = read_i64(d, tile + 8);
+            if child == 0 {
+                let (lo, hi) = region(origin, 4096);
+                f(lo, hi, read_f32(d, tile + 20));
+                continue;
+            }
+
+            let upper = child_offset(self.root, child);
+            for n in 0..32768usize {
+                let o = [
+                    origin[0] + (((n >> 10) & 31) as i32) * 128,
+                    origin[1] + (((n >> 5) & 31) as i32) * 128,
+                    origin[2] + ((n & 31) as i32) * 128,
+                ];
+                let entry = upper + UPPER_TABLE + 8 * n;
+                if !mask_is_on(d, upper + 32 + 4096, n) {
+                    let (lo, hi) = region(o, 128);
+                    f(lo, hi, read_f32(d, entry));
+                    continue;
+                }
+
+                let lower = child_offset(upper, read_i64(d, entry));
+                for m in 0..4096usize {
+                    let lo8 = [
+                        o[0] + (((m >> 8) & 15) as i32) * 8,
+                        o[1] + (((m >> 4) & 15) as i32) * 8,
+                        o[2] + ((m & 15) as i32) * 8,
+                    ];
+                    let entry = lower + LOWER_TABLE + 8 * m;
+                    let value_max = if mask_is_on(d, lower + 32 + 512, m) {
+                        // Leaf statistics are optional in NanoVDB, so scan the values
+                        let leaf = child_offset(lower, read_i64(d, entry));
+                        (0..512).fold(f32::NEG_INFINITY, |a, k| a.max(read_f32(d, leaf + LEAF_VALUES + 4 * k)))
+                    } else {
+                        read_f32(d, entry)
+                    };
+                    let (lo, hi) = region(lo8, 8);
+                    f(lo, hi, value_max);
+                }
+            }
+        }
+    }
+}
+
+// Walks the segments of a .nvdb file and reports (name, offset, size) of each
+// uncompressed grid buffer.
+fn for_each_grid<F: FnMut(&str, usize, usize)>(bytes: &[u8], mut f: F) -> io::Result<()> {
+    let mut pos = 0;
+    while pos + FILE_HEADER_SIZE <= bytes.len() {
+        let magic = read_u64(bytes, pos);
+        if magic != MAGIC_V0 && magic != MAGIC_FILE {
+            return Err(invalid("bad file magic number"));
+        }
+        if read_u32(bytes, pos + 8) >> 21 != SUPPORTED_MAJOR {
+            return Err(invalid("unsupported file version (expected major version 32)"));
+        }
+        let grid_count = read_u16(bytes, pos + 12) as usize;
+        if read_u16(bytes, pos + 14) != 0 {
+            return Err(invalid("compressed grids (ZIP/BLOSC) are not supported"));
+        }
+        pos += FILE_HEADER_SIZE;
+
+        // All metadata records come first, followed by the grid buffers
+        let mut grids = Vec::with_capacity(grid_count);
+        for _ in 0..grid_count {
+            if pos + FILE_META_SIZE > bytes.len() {
+                return Err(invalid("truncated grid metadata"));
+            }
+            let file_size = usize::try_from(read_u64(bytes, pos + 8))
+                .map_err(|_| invalid("grid buffer size out of range"))?;
+            let name_size = read_u32(bytes, pos + 136) as usize;
+            pos += FILE_META_SIZE;
+            if name_size > bytes.len() - pos {
+                return Err(invalid("truncated grid name"));
+            }
+            let name = String::from_utf8_lossy(&bytes[pos..pos + name_size])
+                .trim_end_matches('\0')
+                .to_string();
+            pos += name_size;
+            grids.push((name, file_size));
+        }
+        for (name, size) in grids {
+            if size > bytes.len() - pos {
+                return Err(invalid("truncated grid buffer"));
+            }
+            f(&name, pos, size);
+            pos += size;
+        }
+    }
+    Ok(())
+}
+
+fn find_grid(bytes: &[u8], name: &str) -> io::Result<Option<(usize, usize)>> {
+    let mut found = None;
+    for_each_grid(bytes, |n, offset, size| {
+        if found.is_none() && n == name {
+            found = Some((offset, size));
+        }
+    })?;
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn put(b: &mut [u8], off: usize, bytes: &[u8]) {
+        b[off..off + bytes.len()].copy_from_slice(bytes);
+    }
+
+    // Float grid with a single leaf holding `voxels`, world = 0.5 * index + (1, 0, 0)
+    fn grid_buffer(voxels: &[([i32; 3], f32)]) -> Vec<u8> {
+        let root = GRID_DATA_SIZE + 64;
+        let upper = root + ROOT_DATA_SIZE + ROOT_TILE_SIZE;
+        let lower = upper + UPPER_SIZE;
+        let leaf = lower + LOWER_SIZE;
+        let mut b = vec![0u8; leaf + LEAF_SIZE];
+
+        put(&mut b, 0, &MAGIC_GRID.to_le_bytes());
+        put(&mut b, 16, &(SUPPORTED_MAJOR << 21).to_le_bytes());
+        put(&mut b, 636, &GRID_TYPE_FLOAT.to_le_bytes());
+        for i in [0, 4, 8] {
+            put(&mut b, 384 + 8 * i, &0.5f64.to_le_bytes());
+            put(&mut b, 456 + 8 * i, &2.0f64.to_le_bytes());
+        }
+        put(&mut b, 528, &1.0f64.to_le_bytes());
+
+        put(&mut b, GRID_DATA_SIZE + 24, &64u64.to_le_bytes());
+        for (i, v) in [0i32, 0, 0, 7, 7, 7].into_iter().enumerate() {
+            put(&mut b, root + 4 * i, &v.to_le_bytes());
+        }
+        put(&mut b, root + 24, &1u32.to_le_bytes());
+        put(&mut b, root + 28, &0.25f32.to_le_bytes());
+        // Tile with key 0 (the 4096^3 block at the origin) -> upper -> lower -> leaf
+        put(&mut b, root + ROOT_DATA_SIZE + 8, &((upper - root) as i64).to_le_bytes());
+        put(&mut b, upper + 32 + 4096, &1u64.to_le_bytes());
+        put(&mut b, upper + UPPER_TABLE, &((lower - upper) as i64).to_le_bytes());
+        put(&mut b, lower + 32 + 512, &1u64.to_le_bytes());
+        put(&mut b, lower + LOWER_TABLE, &((leaf - lower) as i64).to_le_bytes());
+        for &(ijk, v) in voxels {
+            let n = ((ijk[0] << 6) | (ijk[1] << 3) | ijk[2]) as usize;
+            put(&mut b, leaf + LEAF_VALUES + 4 * n, &v.to_le_bytes());
+        }
+        b
+    }
+
+    fn file(grids: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut bytes = MAGIC_FILE.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&(SUPPORTED_MAJOR << 21).to_le_bytes());
+        bytes.extend_from_slice(&(grids.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        for (name, grid) in grids {
+            let mut meta = vec![0u8; FILE_META_SIZE];
+            put(&mut meta, 8, &(grid.len() as u64).to_le_bytes());
+            put(&mut meta, 136, &(name.len() as u32 + 1).to_le_bytes());
+            bytes.extend_from_slice(&meta);
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.push(0);
+        }
+        for (_, grid) in grids {
+            bytes.extend_from_slice(grid);
+        }
+        bytes
+    }
+
+    fn load(bytes: &[u8], name: &str) -> io::Result<NanoVDBGrid> {
+        let (offset, size) = find_grid(bytes, name)?.ok_or_else(|| invalid("missing"))?;
+        NanoVDBGrid::from_buffer(bytes[offset..offset + size].to_vec(), name)
+    }
+
+    #[test]
+    fn reads_voxels_through_the_tree() {
+        let bytes = file(&[
+            ("temperature", grid_buffer(&[])),
+            ("density", grid_buffer(&[([1, 2, 3], 0.5), ([2, 2, 3], 1.5)])),
+        ]);
+        let mut names = Vec::new();
+        for_each_grid(&bytes, |name, _, _| names.push(name.to_string())).unwrap();
+        assert_eq!(names, ["temperature", "density"]);
+
+        let grid = load(&bytes, "density").unwrap();
+        assert_eq!(grid.value([1, 2, 3]), 0.5);
+        assert_eq!(grid.value([2, 2, 3]), 1.5);
+        assert_eq!(grid.value([3, 2, 3]), 0.0);
+        assert_eq!(grid.value([-1, 2, 3]), 0.25, "outside the tile: background");
+
+        // Index (1.5, 2, 3) lies halfway between the two voxels
+        let p = Point3::new(1.0 + 0.5 * 1.5, 1.0, 1.5);
+        assert!((grid.lookup(p) - 1.0).abs() < 1e-5, "{}", grid.lookup(p));
+        let b = grid.world_bounds();
+        assert!((b.min.x - 0.5).abs() < 1e-6 && (b.max.z - 4.0).abs() < 1e-6);
+
+        let mut regions = 0;
+        grid.for_each_region(|_, _, v| {
+            if v == 1.5 {
+                regions += 1;
+            }
+        });
+        assert_eq!(regions, 1);
+    }
+
+    #[test]
+    fn rejects_corrupt_files() {
+        assert!(load(&file(&[("density", grid_buffer(&[]))]), "smoke").is_err());
+
+        let mut grid = grid_buffer(&[]);
+        grid.truncate(grid.len() - 1);
+        assert!(load(&file(&[("density", grid)]), "density").is_err(), "leaf past the end");
+
+        let mut grid = grid_buffer(&[]);
+        put(&mut grid, 636, &2u32.to_le_bytes());
+        assert!(load(&file(&[("density", grid)]), "density").is_err(), "not a float grid");
+
+        let mut bytes = file(&[("density", grid_buffer(&[]))]);
+        put(&mut bytes, 14, &1u16.to_le_bytes());
+        assert!(for_each_grid(&bytes, |_, _, _| ()).is_err(), "compressed");
+    }
+}
@@ -5,19 +5,17 @@ pub struct Perlin {
     perm_x: Vec<usize>,
     perm_y: Vec<usize>,
     perm_z: Vec<usize>,
-    ranfloat: Vec<f32>, 
 }
 
 impl Perlin {
     pub fn new() -> Self {
         let mut rng = RNG::new(1234, 5678); 
 
-        let ranfloat: Vec<f32> = (0..256).map(|_| rng.next_f32()).collect();
         let perm_x = Perlin::perlin_generate_perm(&mut rng);
         let perm_y = Perlin::perlin_generate_perm(&mut rng);
         let perm_z = Perlin::perlin_generate_perm(&mut rng);
 
-        Perlin { perm_x, perm_y, perm_z, ranfloat }
+        Perlin { perm_x, perm_y, perm_z }
     }
 
     fn perlin_generate_perm(rng: &mut RNG) -> Vec<usize> {
@@ -35,16 +33,16 @@ impl Perlin {
         let ww = w * w * w * (w * (w * 6.0 - 15.0) + 10.0);
 
         let mut accum = 0.0;
-        for i in 0..2 {
-            for j in 0..2 {
-                for k in 0..2 {
+        for (i, ci) in c.iter().enumerate() {
+            for (j, cij) in ci.iter().enumerate() {
+                for (k, cijk) in cij.iter().enumerate() {
                     let weight_v = Vector3::new(u - i as f32, v - j as f32, w - k as f32);
                     let idx_i = i as f32; let idx_j = j as f32; let idx_k = k as f32;
                     
                     accum += (idx_i * uu + (1.0 - idx_i) * (1.0 - uu)) *
                              (idx_j * vv + (1.0 - idx_j) * (1.0 - vv)) *
                              (idx_k * ww + (1.0 - idx_k) * (1.0 - ww)) *
-                             c[i][j][k].dot(weight_v);
+                             cijk.dot(weight_v);
                 }
             }
         }
@@ -62,13 +60,13 @@ impl Perlin {
 
         let mut c = [[[Vector3::new(0.0,0.0,0.0); 2]; 2]; 2];
 
-        for di in 0..2 {
-            for dj in 0..2 {
-                for dk in 0..2 {
+        for (di, cdi) in c.iter_mut().enumerate() {
+            for (dj, cdj) in cdi.iter_mut().enumerate() {
+                for (dk, cdk) in cdj.iter_mut().enumerate() {
                     let idx = self.perm_x[((i + di as i32) & 255) as usize] ^
                               self.perm_y[((j + dj as i32) & 255) as usize] ^
                               self.perm_z[((k + dk as i32) & 255) as usize];
-                    c[di][dj][dk] = self.get_gradient(idx); 
+                    *cdk = self.get_gradient(idx);
                 }
             }
         }
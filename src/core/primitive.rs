@@ -1,11 +1,12 @@
 use std::sync::Arc;
 
-use crate::core::geometry::{Bounds3, Point2, Point3, Normal3};
+use crate::core::geometry::{Bounds3, DirectionCone, Point2, Point3, Vector3};
 use crate::core::ray::Ray;
-use crate::core::interaction::SurfaceInteraction;
-use crate::core::transform::Transform;
+use crate::core::interaction::{Interaction, SurfaceInteraction};
 use crate::core::math::hash_float; 
+use crate::core::light::LightLinks;
 use crate::core::material::Material; 
+use crate::core::medium::MediumInterface;
 
 // --- 1. The Shape Trait (Geometry + Sampling Support) ---
 //
@@ -22,15 +23,71 @@ pub trait Shape: Send + Sync {
 
     // --- NEW: Area Light Support ---
     fn area(&self) -> f32;
-    fn sample(&self, u: Point2) -> (Point3, Normal3);
+
+    /// Uniformly samples a point on the surface (p, geometric normal n, uv)
+    fn sample(&self, u: Point2) -> Interaction;
+
+    /// Cone bounding the surface normals (used to bound area light emission)
+    fn normal_bounds(&self) -> DirectionCone {
+        DirectionCone::entire_sphere()
+    }
+
+    /// Samples a point on the surface as seen from `ctx`, returning it with
+    /// its solid angle pdf. Defaults to uniform area sampling.
+    fn sample_solid_angle(&self, ctx: &Interaction, u: Point2) -> Option<(Interaction, f32)> {
+        sample_area_as_solid_angle(self, ctx, u)
+    }
+
+    /// Solid angle density of `sample_solid_angle` for direction `wi` from `ctx`
+    fn pdf_solid_angle(&self, ctx: &Interaction, wi: Vector3) -> f32 {
+        area_pdf_as_solid_angle(self, ctx, wi)
+    }
+}
+
+/// Uniform area sampling with the density converted to solid angle from `ctx`
+pub fn sample_area_as_solid_angle<S: Shape + ?Sized>(
+    shape: &S,
+    ctx: &Interaction,
+    u: Point2,
+) -> Option<(Interaction, f32)> {
+    let it = shape.sample(u);
+    let d = it.p - ctx.p;
+    let dist_sq = d.length_squared();
+    if dist_sq == 0.0 {
+        return None;
+    }
+    // pdf_omega = dist^2 / (area * |cos theta_light|)
+    let cos_theta_light = Vector3::from(it.n).dot(d).abs() / dist_sq.sqrt();
+    let pdf = dist_sq / (shape.area() * cos_theta_light);
+    if !pdf.is_finite() || pdf <= 0.0 {
+        return None;
+    }
+    Some((it, pdf))
+}
+
+/// Density of `sample_area_as_solid_angle`: finds where the ray towards `wi`
+/// hits the shape and converts the uniform area density to solid angle
+pub fn area_pdf_as_solid_angle<S: Shape + ?Sized>(shape: &S, ctx: &Interaction, wi: Vector3) -> f32 {
+    let ray = ctx.spawn_ray(wi);
+    let Some((_, isect)) = shape.intersect(&ray, f32::INFINITY) else {
+        return 0.0;
+    };
+    let dist_sq = (isect.core.p - ctx.p).length_squared();
+    let cos_theta_light = Vector3::from(isect.core.n).dot(-wi).abs();
+    if cos_theta_light == 0.0 {
+        return 0.0;
+    }
+    dist_sq / (shape.area() * cos_theta_light)
 }
 
 // --- 2. The Primitive Trait ---
 // Carries Material through intersection (unchanged)
+pub type PrimitiveHit = (f32, SurfaceInteraction, Option<Arc<dyn Material>>);
+
 pub trait Primitive: Send + Sync {
     fn bounds(&self) -> Bounds3;
     fn intersect(&self, ray: &Ray)
-        -> Option<(f32, SurfaceInteraction, Option<Arc<dyn Material>>)>;
+        -> Option<PrimitiveHit>;
 }
 
 // --- Implementation A: GeometricPrimitive ---
@@ -38,6 +95,9 @@ pub struct GeometricPrimitive {
     pub shape: Arc<dyn Shape>,
     pub material: Option<Arc<dyn Material>>,
     pub alpha: f32, 
+    pub medium_interface: Option<MediumInterface>,
+    pub area_light: Option<usize>, // Index of this surface's light in the scene light list
+    pub light_links: Option<Arc<LightLinks>>,
 }
 
 impl GeometricPrimitive {
@@ -46,7 +106,26 @@ impl GeometricPrimitive {
         material: Option<Arc<dyn Material>>,
         alpha: f32,
     ) -> Self {
-        GeometricPrimitive { shape, material, alpha }
+        GeometricPrimitive { shape, material, alpha, medium_interface: None, area_light: None, light_links: None }
+    }
+
+    // Marks this surface as a boundary between two media.
+    // With `material: None` the surface itself is invisible.
+    pub fn with_medium_interface(mut self, medium_interface: MediumInterface) -> Self {
+        self.medium_interface = Some(medium_interface);
+        self
+    }
+
+    // Links the surface to the area light (by scene light index) that emits from it
+    pub fn with_area_light(mut self, light_index: usize) -> Self {
+        self.area_light = Some(light_index);
+        self
+    }
+
+    // Restricts which lights illuminate this surface
+    pub fn with_light_links(mut self, links: Arc<LightLinks>) -> Self {
+        self.light_links = Some(links);
+        self
     }
 }
 
@@ -58,10 +137,10 @@ impl Primitive for GeometricPrimitive {
     fn intersect(
         &self,
         ray: &Ray,
-    ) -> Option<(f32, SurfaceInteraction, Option<Arc<dyn Material>>)> {
+    ) -> Option<PrimitiveHit> {
         let hit = self.shape.intersect(ray, f32::INFINITY);
 
-        if let Some((t_hit, interaction)) = hit {
+        if let Some((t_hit, mut interaction)) = hit {
             // --- Stochastic Alpha Test ---
             if self.alpha < 1.0 {
                 let u = hash_float(
@@ -74,6 +153,9 @@ impl Primitive for GeometricPrimitive {
                 }
             }
 
+            interaction.medium_interface = self.medium_interface.clone();
+            interaction.area_light = self.area_light;
+            interaction.light_links = self.light_links.clone();
             Some((t_hit, interaction, self.material.clone()))
         } else {
             None
@@ -81,56 +163,6 @@ impl Primitive for GeometricPrimitive {
     }
 }
 
-// --- Implementation B: TransformedPrimitive ---
-pub struct TransformedPrimitive {
-    pub primitive: Arc<dyn Primitive>,
-    pub world_to_primitive: Transform, 
-}
-
-impl TransformedPrimitive {
-    pub fn new(
-        primitive: Arc<dyn Primitive>,
-        object_to_world: Transform,
-    ) -> Self {
-        TransformedPrimitive {
-            primitive,
-            world_to_primitive: object_to_world.inverse(),
-        }
-    }
-}
-
-impl Primitive for TransformedPrimitive {
-    fn bounds(&self) -> Bounds3 {
-        self.primitive.bounds()
-    }
-
-    fn intersect(
-        &self,
-        ray: &Ray,
-    ) -> Option<(f32, SurfaceInteraction, Option<Arc<dyn Material>>)> {
-        let transformed_ray = self.world_to_primitive.transform_ray(ray);
-
-        if let Some((t, mut interaction, mat)) =
-            self.primitive.intersect(&transformed_ray)
-        {
-            let primitive_to_world = self.world_to_primitive.inverse();
-
-            interaction.core.p =
-                primitive_to_world.transform_point(interaction.core.p);
-            interaction.core.n =
-                primitive_to_world.transform_normal(interaction.core.n);
-            interaction.core.wo =
-                primitive_to_world.transform_vector(interaction.core.wo);
-            interaction.shading.n =
-                primitive_to_world.transform_normal(interaction.shading.n);
-
-            Some((t, interaction, mat))
-        } else {
-            None
-        }
-    }
-}
-
 // --- Implementation C: PrimitiveList (The Scene) ---
 pub struct PrimitiveList {
     pub primitives: Vec<Arc<dyn Primitive>>,
@@ -173,7 +205,7 @@ impl Primitive for PrimitiveList {
     fn intersect(
         &self,
         ray: &Ray,
-    ) -> Option<(f32, SurfaceInteraction, Option<Arc<dyn Material>>)> {
+    ) -> Option<PrimitiveHit> {
         let mut closest_t = f32::INFINITY;
         let mut closest_hit = None;
 
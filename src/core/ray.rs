@@ -21,7 +21,7 @@ impl Ray {
         // Default: No differentials (pinhole center ray)
         Ray { 
             o, d, time, 
-            t_max: std::f32::INFINITY,
+            t_max: f32::INFINITY,
             has_differentials: false,
             rx_origin: Point3 { x: 0.0, y: 0.0, z: 0.0 },
             ry_origin: Point3 { x: 0.0, y: 0.0, z: 0.0 },
@@ -34,15 +34,4 @@ impl Ray {
     pub fn at(&self, t: f32) -> Point3 {
         self.o + self.d * t
     }
-
-    // Scale differentials based on Samples Per Pixel (SPP)
-    // If we take 16 samples, the "pixel footprint" is smaller.
-    pub fn scale_differentials(&mut self, spp: f32) {
-        if !self.has_differentials { return; }
-        let s = (1.0 / spp.sqrt()).max(0.125); 
-        self.rx_origin = self.o + (self.rx_origin - self.o) * s;
-        self.ry_origin = self.o + (self.ry_origin - self.o) * s;
-        self.rx_direction = self.d + (self.rx_direction - self.d) * s;
-        self.ry_direction = self.d + (self.ry_direction - self.d) * s;
-    }
 }
\ No newline at end of file
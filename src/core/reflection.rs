@@ -1,4 +1,4 @@
-use crate::core::spectrum::SampledSpectrum;
+use crate::core::spectrum::{SampledSpectrum, N_SPECTRUM_SAMPLES};
 
 // --- Helper: Clamp ---
 fn clamp(val: f32, min: f32, max: f32) -> f32 {
@@ -47,30 +47,88 @@ pub fn fr_dielectric(cos_theta_i: f32, eta_i: f32, eta_t: f32) -> f32 {
 // --- 2. Conductor (Metal) Fresnel ---
 // Metals effectively do not transmit light. They reflect or absorb.
 // Because absorption varies by wavelength (Gold reflects Red, absorbs Blue),
-// we must compute this for the whole Spectrum.
+// we must compute this for the whole Spectrum, one wavelength at a time.
 pub fn fr_conductor(cos_theta_i: f32, eta: SampledSpectrum, k: SampledSpectrum) -> SampledSpectrum {
+    let values = std::array::from_fn(|i| fr_complex(cos_theta_i, eta.values[i], k.values[i]));
+    SampledSpectrum { values }
+}
+
+// Exact unpolarized reflectance for a complex index of refraction eta + i k
+fn fr_complex(cos_theta_i: f32, eta: f32, k: f32) -> f32 {
     let cos_theta_i = clamp(cos_theta_i.abs(), 0.0, 1.0);
     let cos_theta_i2 = cos_theta_i * cos_theta_i;
     let sin_theta_i2 = 1.0 - cos_theta_i2;
-
     let eta2 = eta * eta;
     let k2 = k * k;
 
-    // Complex number expansion |a+bi|^2 logic
-    let t0 = eta2 + k2; // (n^2 + k^2)
-    let t1 = eta * (2.0 * cos_theta_i); 
+    // |eta_complex^2 - sin^2| = a^2 + b^2, with a = Re(sqrt(eta_complex^2 - sin^2))
+    let t0 = eta2 - k2 - sin_theta_i2;
+    let a2_plus_b2 = (t0 * t0 + 4.0 * eta2 * k2).sqrt();
+    let a = (0.5 * (a2_plus_b2 + t0)).max(0.0).sqrt();
 
-    let rs = (t0 - t1 + SampledSpectrum::splat(cos_theta_i2)) / 
-             (t0 + t1 + SampledSpectrum::splat(cos_theta_i2));
+    let t1 = a2_plus_b2 + cos_theta_i2;
+    let t2 = 2.0 * cos_theta_i * a;
+    let rs = (t1 - t2) / (t1 + t2);
 
-    let t2 = t0 * cos_theta_i2 + SampledSpectrum::splat(sin_theta_i2);
-    let t3 = eta * (2.0 * cos_theta_i * sin_theta_i2); 
-    
-    // Approximation commonly used in graphics (PBRT v3 style simplified)
-    // For exact complex fresnel, we can use the PBRT v4 full expansion, 
-    // but this captures the color shift correctly.
-    let rp = rs * (t2 - t3 + SampledSpectrum::splat(sin_theta_i2)) / 
-                  (t2 + t3 + SampledSpectrum::splat(sin_theta_i2));
-
-    (rs + rp) * 0.5
-}
\ No newline at end of file
+    let t3 = cos_theta_i2 * a2_plus_b2 + sin_theta_i2 * sin_theta_i2;
+    let t4 = t2 * sin_theta_i2;
+    let rp = rs * (t3 - t4) / (t3 + t4);
+
+    0.5 * (rs + rp)
+}
+
+// --- 3. Artist-Friendly Conductor IOR ---
+// Gulbrandsen 2014: maps a normal-incidence reflectance r and an edge tint g
+// (the color towards grazing angles) to a complex IOR, per wavelength.
+pub fn conductor_ior_from_edge_tint(r: SampledSpectrum, g: SampledSpectrum) -> (SampledSpectrum, SampledSpectrum) {
+    let mut eta = [0.0; N_SPECTRUM_SAMPLES];
+    let mut k = [0.0; N_SPECTRUM_SAMPLES];
+    for i in 0..N_SPECTRUM_SAMPLES {
+        // r = 1 would need an infinite k
+        let r = clamp(r.values[i], 0.0, 0.99);
+        let g = clamp(g.values[i], 0.0, 1.0);
+        let sqrt_r = r.sqrt();
+        let n = g * (1.0 - r) / (1.0 + r) + (1.0 - g) * (1.0 + sqrt_r) / (1.0 - sqrt_r);
+        let k2 = (r * (n + 1.0) * (n + 1.0) - (n - 1.0) * (n - 1.0)) / (1.0 - r);
+        eta[i] = n;
+        k[i] = k2.max(0.0).sqrt();
+    }
+    (SampledSpectrum { values: eta }, SampledSpectrum { values: k })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conductor_without_absorption_is_a_dielectric() {
+        for eta in [1.2, 1.5, 2.4] {
+            for cos_theta in [0.05, 0.3, 0.7, 1.0] {
+                let conductor = fr_complex(cos_theta, eta, 0.0);
+                let dielectric = fr_dielectric(cos_theta, 1.0, eta);
+                assert!((conductor - dielectric).abs() < 1e-5, "eta {} cos {}: {} vs {}", eta, cos_theta, conductor, dielectric);
+            }
+        }
+    }
+
+    #[test]
+    fn conductor_normal_incidence() {
+        for (eta, k) in [(0.2, 3.0), (1.1, 2.5), (2.9, 3.1)] {
+            let expected = ((eta - 1.0) * (eta - 1.0) + k * k) / ((eta + 1.0) * (eta + 1.0) + k * k);
+            assert!((fr_complex(1.0, eta, k) - expected).abs() < 1e-5);
+            // Grazing light is always fully reflected
+            assert!((fr_complex(0.0, eta, k) - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn edge_tint_reproduces_normal_reflectance() {
+        let r = SampledSpectrum { values: [0.95, 0.7, 0.4, 0.05] };
+        let g = SampledSpectrum { values: [0.2, 0.5, 0.9, 1.0] };
+        let (eta, k) = conductor_ior_from_edge_tint(r, g);
+        let f0 = fr_conductor(1.0, eta, k);
+        for i in 0..N_SPECTRUM_SAMPLES {
+            assert!((f0.values[i] - r.values[i]).abs() < 1e-3, "{} vs {}", f0.values[i], r.values[i]);
+        }
+    }
+}
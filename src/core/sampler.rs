@@ -1,12 +1,23 @@
-use crate::core::math::RNG;
+use crate::core::math::{erf_inv, RNG};
 use crate::core::geometry::{Point2, Point2i};
 
+/// Source of sample values for the integrators.
+/// `li()` only sees this trait, so different samplers can drive the same estimator.
+pub trait Sampler {
+    fn get_2d(&mut self) -> Point2;
+
+    fn get_1d(&mut self) -> f32 {
+        self.get_2d().x
+    }
+}
+
 pub struct StratifiedSampler {
     x_samples: usize,
     y_samples: usize,
     rng: RNG,
     current_pixel: Point2i,
     current_sample: usize,
+    dimension: usize, // 2D dimensions consumed by the current sample
 }
 
 impl StratifiedSampler {
@@ -17,6 +28,7 @@ impl StratifiedSampler {
             rng: RNG::new(0, 0), // Will be re-seeded per pixel
             current_pixel: Point2i { x: 0, y: 0 },
             current_sample: 0,
+            dimension: 0,
         }
     }
 
@@ -27,20 +39,31 @@ impl StratifiedSampler {
     pub fn start_pixel(&mut self, p: Point2i) {
         self.current_pixel = p;
         self.current_sample = 0;
+        self.dimension = 0;
         // Deterministic Seeding: Hash pixel coordinates to get a seed
         let seed = (p.x as u64) << 32 | (p.y as u64);
         self.rng = RNG::new(seed, 1);
     }
 
-    pub fn get_2d(&mut self) -> Point2 {
-        if self.current_sample >= self.samples_per_pixel() {
-            self.current_sample = 0;
-        }
+    /// Begins sample `index` of the current pixel
+    pub fn start_sample(&mut self, index: usize) {
+        self.current_sample = index;
+        self.dimension = 0;
+    }
+}
+
+impl Sampler for StratifiedSampler {
+    fn get_2d(&mut self) -> Point2 {
+        // Each dimension visits the strata in its own pseudo-random order, so
+        // different dimensions of one sample are not correlated
+        let seed = hash_pixel_dimension(self.current_pixel, self.dimension);
+        self.dimension += 1;
+        let n = self.samples_per_pixel();
+        let stratum = permutation_element(self.current_sample as u32 % n as u32, n as u32, seed) as usize;
 
         // Compute grid cell (stratum) indices
-        let stratum_x = self.current_sample % self.x_samples;
-        let stratum_y = self.current_sample / self.x_samples;
-        self.current_sample += 1;
+        let stratum_x = stratum % self.x_samples;
+        let stratum_y = stratum / self.x_samples;
 
         // Jitter within the stratum
         let dx = self.rng.next_f32();
@@ -51,4 +74,162 @@ impl StratifiedSampler {
             y: (stratum_y as f32 + dy) / self.y_samples as f32,
         }
     }
-}
\ No newline at end of file
+}
+
+fn hash_pixel_dimension(p: Point2i, dimension: usize) -> u32 {
+    // splitmix64 finaliser
+    let mut h = ((p.x as u64) << 40) ^ ((p.y as u64) << 20) ^ dimension as u64;
+    h = (h ^ (h >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94d049bb133111eb);
+    (h ^ (h >> 31)) as u32
+}
+
+// Element i of a pseudo-random permutation of 0..l selected by `p`
+// (Kensler, "Correlated Multi-Jittered Sampling")
+fn permutation_element(mut i: u32, l: u32, p: u32) -> u32 {
+    let mut w = l - 1;
+    w |= w >> 1;
+    w |= w >> 2;
+    w |= w >> 4;
+    w |= w >> 8;
+    w |= w >> 16;
+    loop {
+        i ^= p;
+        i = i.wrapping_mul(0xe170893d);
+        i ^= p >> 16;
+        i ^= (i & w) >> 4;
+        i ^= p >> 8;
+        i = i.wrapping_mul(0x0929eb3f);
+        i ^= p >> 23;
+        i ^= (i & w) >> 1;
+        i = i.wrapping_mul(1 | p >> 27);
+        i = i.wrapping_mul(0x6935fa69);
+        i ^= (i & w) >> 11;
+        i = i.wrapping_mul(0x74dcb303);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0x9e501cc3);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0xc860a3df);
+        i &= w;
+        i ^= i >> 5;
+        if i < l {
+            break;
+        }
+    }
+    (i.wrapping_add(p)) % l
+}
+
+// --- Primary Sample Space Sampler (Metropolis Light Transport) ---
+//
+// Kelemen et al. 2002: instead of fresh random numbers, the sampler hands out
+// entries of a persistent vector X in [0,1)^n. Each iteration either replaces
+// X with fresh uniform values (large step) or perturbs it slightly (small
+// step). Entries are mutated lazily, the first time they are read in an
+// iteration, so paths of any length work.
+
+#[derive(Debug, Clone, Copy, Default)]
+struct PrimarySample {
+    value: f32,
+    last_modification_iteration: i64,
+    // State to restore if the proposal is rejected
+    value_backup: f32,
+    modify_backup: i64,
+}
+
+pub struct MLTSampler {
+    rng: RNG,
+    sigma: f32,
+    large_step_probability: f32,
+    x: Vec<PrimarySample>,
+    current_iteration: i64,
+    large_step: bool,
+    last_large_step_iteration: i64,
+    sample_index: usize,
+}
+
+impl MLTSampler {
+    /// Two samplers built with the same `seed` produce identical first vectors,
+    /// which is how a chain replays the bootstrap sample it was started from.
+    pub fn new(seed: u64, sigma: f32, large_step_probability: f32) -> Self {
+        MLTSampler {
+            rng: RNG::new(seed, 0),
+            sigma,
+            large_step_probability,
+            x: Vec::new(),
+            current_iteration: 0,
+            large_step: true,
+            last_large_step_iteration: 0,
+            sample_index: 0,
+        }
+    }
+
+    /// Begin a new proposal (mutation) of the primary sample vector.
+    pub fn start_iteration(&mut self) {
+        self.current_iteration += 1;
+        self.large_step = self.rng.next_f32() < self.large_step_probability;
+        self.sample_index = 0;
+    }
+
+    /// Keep the proposed vector as the new chain state.
+    pub fn accept(&mut self) {
+        if self.large_step {
+            self.last_large_step_iteration = self.current_iteration;
+        }
+    }
+
+    /// Discard the proposed vector and restore the previous chain state.
+    pub fn reject(&mut self) {
+        for xi in self.x.iter_mut() {
+            if xi.last_modification_iteration == self.current_iteration {
+                xi.value = xi.value_backup;
+                xi.last_modification_iteration = xi.modify_backup;
+            }
+        }
+        self.current_iteration -= 1;
+    }
+
+    // Bring X_i up to date with the current iteration (lazy mutation)
+    fn ensure_ready(&mut self, index: usize) {
+        if index >= self.x.len() {
+            self.x.resize(index + 1, PrimarySample::default());
+        }
+        let xi = &mut self.x[index];
+
+        // A large step happened since this entry was last touched: it would
+        // have been replaced by a fresh uniform value then.
+        if xi.last_modification_iteration < self.last_large_step_iteration {
+            xi.value = self.rng.next_f32();
+            xi.last_modification_iteration = self.last_large_step_iteration;
+        }
+
+        xi.value_backup = xi.value;
+        xi.modify_backup = xi.last_modification_iteration;
+
+        if self.large_step {
+            xi.value = self.rng.next_f32();
+        } else {
+            // Apply all pending small steps at once: the sum of n Gaussian
+            // perturbations is a Gaussian with sqrt(n) times the deviation.
+            let n_small = (self.current_iteration - xi.last_modification_iteration) as f32;
+            let normal = std::f32::consts::SQRT_2 * erf_inv(2.0 * self.rng.next_f32() - 1.0);
+            let eff_sigma = self.sigma * n_small.sqrt();
+            xi.value += normal * eff_sigma;
+            xi.value -= xi.value.floor();
+        }
+        xi.last_modification_iteration = self.current_iteration;
+    }
+}
+
+impl Sampler for MLTSampler {
+    fn get_1d(&mut self) -> f32 {
+        let index = self.sample_index;
+        self.sample_index += 1;
+        self.ensure_ready(index);
+        // Guard against value == 1.0 after floating-point wrap-around
+        self.x[index].value.min(1.0 - f32::EPSILON)
+    }
+
+    fn get_2d(&mut self) -> Point2 {
+        Point2 { x: self.get_1d(), y: self.get_1d() }
+    }
+}
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use crate::core::light::{DiffuseAreaLight, Light, LightLinks};
+use crate::core::material::Material;
+use crate::core::medium::MediumInterface;
+use crate::core::primitive::{GeometricPrimitive, Primitive, PrimitiveList, Shape};
+use crate::shapes::curve::{Curve, CurveCommon};
+use crate::shapes::triangle::{Triangle, TriangleMesh};
+
+/// Collects primitives and lights, turning emissive shapes into area lights.
+///
+/// Every shape (or triangle of a mesh) whose material reports an `Emission` gets its own
+/// `DiffuseAreaLight` (sharing the material's emission texture), and the
+/// triangle's primitive stores that light's index so integrators can apply
+/// MIS when a BSDF-sampled ray hits the emitter.
+///
+/// Shapes added after `set_light_links` are only lit by the linked lights,
+/// and shapes added after `set_medium_interface` bound those media.
+pub struct SceneBuilder {
+    pub primitives: Vec<Arc<dyn Primitive>>,
+    pub lights: Vec<Box<dyn Light>>,
+    light_links: Option<Arc<LightLinks>>,
+    medium_interface: Option<MediumInterface>,
+}
+
+impl SceneBuilder {
+    pub fn new() -> Self {
+        SceneBuilder { primitives: Vec::new(), lights: Vec::new(), light_links: None, medium_interface: None }
+    }
+
+    /// Adds a light that has no geometry in the scene; returns its index
+    pub fn add_light(&mut self, light: Box<dyn Light>) -> usize {
+        self.lights.push(light);
+        self.lights.len() - 1
+    }
+
+    /// Sets the light links of shapes added from now on (`LightLinks::All` to reset)
+    pub fn set_light_links(&mut self, links: LightLinks) {
+        self.light_links = match links {
+            LightLinks::All => None,
+            links => Some(Arc::new(links)),
+        };
+    }
+
+    /// Sets the media on either side of shapes added from now on (None to reset)
+    pub fn set_medium_interface(&mut self, medium_interface: Option<MediumInterface>) {
+        self.medium_interface = medium_interface;
+    }
+
+    /// Adds a primitive for `shape`, plus an area light if the material emits;
+    /// returns the index of that light
+    pub fn add_shape(
+        &mut self,
+        shape: Arc<dyn Shape>,
+        material: Option<Arc<dyn Material>>,
+        alpha: f32,
+    ) -> Option<usize> {
+        self.add_shape_part(shape, material, alpha, 1.0)
+    }
+
+    // `add_shape` for a shape carrying `fraction` of its emitter's power
+    fn add_shape_part(
+        &mut self,
+        shape: Arc<dyn Shape>,
+        material: Option<Arc<dyn Material>>,
+        alpha: f32,
+        fraction: f32,
+    ) -> Option<usize> {
+        let mut prim = GeometricPrimitive::new(shape.clone(), material.clone(), alpha);
+        if let Some(links) = &self.light_links {
+            prim = prim.with_light_links(links.clone());
+        }
+        if let Some(medium_interface) = &self.medium_interface {
+            prim = prim.with_medium_interface(medium_interface.clone());
+        }
+
+        let mut light_index = None;
+        if let Some(e) = material.as_ref().and_then(|m| m.emission()) {
+            let mut light = DiffuseAreaLight::from_texture(shape, e.le, e.two_sided);
+            if let Some(spectrum) = e.spectrum {
+                light = light.with_spectrum(spectrum);
+            }
+            if let Some(power) = e.power {
+                light = light.with_power(power.fraction(fraction));
+            }
+            let index = self.add_light(Box::new(light));
+            prim = prim.with_area_light(index);
+            light_index = Some(index);
+        }
+        self.primitives.push(Arc::new(prim));
+        light_index
+    }
+
+    /// Adds one primitive per triangle, plus an area light per triangle if the
+    /// material emits; returns the indices of those lights. An emission power
+    /// given as a flux is split between the triangles by area.
+    pub fn add_mesh(
+        &mut self,
+        mesh: Arc<TriangleMesh>,
+        material: Option<Arc<dyn Material>>,
+        alpha: f32,
+    ) -> Vec<usize> {
+        let triangles: Vec<Arc<dyn Shape>> =
+            (0..mesh.n_triangles).map(|i| Arc::new(Triangle::new(mesh.clone(), i)) as Arc<dyn Shape>).collect();
+        let total_area: f32 = triangles.iter().map(|t| t.area()).sum();
+        triangles
+            .into_iter()
+            .filter_map(|t| {
+                let fraction = if total_area > 0.0 { t.area() / total_area } else { 0.0 };
+                self.add_shape_part(t, material.clone(), alpha, fraction)
+            })
+            .collect()
+    }
+
+    /// Adds a curve as `n_segments` primitives (see `Curve::split`), plus
+    /// area lights if the material emits; power is split between them by area
+    pub fn add_curve(
+        &mut self,
+        curve: Arc<CurveCommon>,
+        n_segments: usize,
+        material: Option<Arc<dyn Material>>,
+        alpha: f32,
+    ) -> Vec<usize> {
+        let pieces: Vec<Arc<dyn Shape>> =
+            Curve::split(curve, n_segments).into_iter().map(|c| Arc::new(c) as Arc<dyn Shape>).collect();
+        let total_area: f32 = pieces.iter().map(|c| c.area()).sum();
+        pieces
+            .into_iter()
+            .filter_map(|c| {
+                let fraction = if total_area > 0.0 { c.area() / total_area } else { 0.0 };
+                self.add_shape_part(c, material.clone(), alpha, fraction)
+            })
+            .collect()
+    }
+
+    /// Finishes the scene, letting lights that depend on its extent see the final bounds
+    pub fn build(mut self) -> (PrimitiveList, Vec<Box<dyn Light>>) {
+        let scene = PrimitiveList::new(self.primitives);
+        let bounds = scene.bounds();
+        for light in &mut self.lights {
+            light.preprocess(&bounds);
+        }
+        (scene, self.lights)
+    }
+}
+
+impl Default for SceneBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
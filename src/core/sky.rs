@@ -0,0 +1,231 @@
+use std::f32::consts::PI;
+
+use crate::core::geometry::Vector3;
+use crate::core::spectrum::{
+    daylight_basis, spectrum_to_photometric, PiecewiseLinearSpectrum, SampledSpectrum, SampledWavelengths, Spectrum,
+};
+
+// --- 1. CIE Daylight Basis ---
+/// Spectra of the CIE daylight model, with their luminances cached so that
+/// a chromaticity + luminance (xyY) converts to a spectrum cheaply.
+pub struct DaylightBasis {
+    s: [Spectrum; 3],
+    y: [f32; 3], // Photometric value of S0, S1, S2
+}
+
+impl DaylightBasis {
+    pub fn new() -> Self {
+        let s = daylight_basis();
+        let y = [spectrum_to_photometric(&s[0]), spectrum_to_photometric(&s[1]), spectrum_to_photometric(&s[2])];
+        DaylightBasis { s, y }
+    }
+
+    /// Daylight spectrum with chromaticity (x, y) and luminance `lum`
+    pub fn spectrum(&self, x: f32, y: f32, lum: f32, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+        let m1 = (-1.3515 - 1.7703 * x + 5.9114 * y) / m;
+        let m2 = (0.0300 - 31.4424 * x + 30.0717 * y) / m;
+
+        let y_s = self.y[0] + m1 * self.y[1] + m2 * self.y[2];
+        if y_s <= 0.0 {
+            return SampledSpectrum::new(0.0);
+        }
+        let s = self.s[0].sample(lambda) + self.s[1].sample(lambda) * m1 + self.s[2].sample(lambda) * m2;
+        s * (lum / y_s)
+    }
+}
+
+impl Default for DaylightBasis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- 2. Preetham Sky ---
+// "A Practical Analytic Model for Daylight" (Preetham, Shirley, Smits 1999).
+// Luminance Y (kcd/m^2) and chromaticity x, y follow the Perez distribution
+// F(theta, gamma), scaled so the zenith matches the fitted zenith values.
+
+// Perez coefficients (A..E) as linear functions of turbidity: [slope, offset]
+const PEREZ_Y: [[f32; 2]; 5] = [[0.1787, -1.4630], [-0.3554, 0.4275], [-0.0227, 5.3251], [0.1206, -2.5771], [-0.0670, 0.3703]];
+const PEREZ_X: [[f32; 2]; 5] = [[-0.0193, -0.2592], [-0.0665, 0.0008], [-0.0004, 0.2125], [-0.0641, -0.8989], [-0.0033, 0.0452]];
+const PEREZ_YC: [[f32; 2]; 5] = [[-0.0167, -0.2608], [-0.0950, 0.0092], [-0.0079, 0.2102], [-0.0441, -1.6537], [-0.0109, 0.0529]];
+
+// Zenith chromaticity: [T^2, T, 1] * M * [theta_s^3, theta_s^2, theta_s, 1]
+const ZENITH_X: [[f32; 4]; 3] = [
+    [0.00166, -0.00375, 0.00209, 0.0],
+    [-0.02903, 0.06377, -0.03202, 0.00394],
+    [0.11693, -0.21196, 0.06052, 0.25886],
+];
+const ZENITH_Y: [[f32; 4]; 3] = [
+    [0.00275, -0.00610, 0.00317, 0.0],
+    [-0.04214, 0.08970, -0.04153, 0.00516],
+    [0.15346, -0.26756, 0.06670, 0.26688],
+];
+
+fn perez_coefficients(table: &[[f32; 2]; 5], turbidity: f32) -> [f32; 5] {
+    table.map(|[a, b]| a * turbidity + b)
+}
+
+fn perez(c: &[f32; 5], cos_theta: f32, gamma: f32) -> f32 {
+    let cos_gamma = gamma.cos();
+    (1.0 + c[0] * (c[1] / cos_theta.max(1e-3)).exp())
+        * (1.0 + c[2] * (c[3] * gamma).exp() + c[4] * cos_gamma * cos_gamma)
+}
+
+fn zenith_chromaticity(m: &[[f32; 4]; 3], turbidity: f32, theta_s: f32) -> f32 {
+    let t = [turbidity * turbidity, turbidity, 1.0];
+    let th = [theta_s.powi(3), theta_s * theta_s, theta_s, 1.0];
+    (0..3).map(|i| t[i] * (0..4).map(|j| m[i][j] * th[j]).sum::<f32>()).sum()
+}
+
+/// Analytic clear sky for a given sun position, turbidity and ground albedo.
+///
+/// World +y is up. Directions below the horizon see a Lambertian ground of
+/// the given albedo lit by the sun and sky (the Preetham model itself has no
+/// ground term).
+pub struct PreethamSky {
+    pub sun_dir: Vector3, // Towards the sun
+    theta_s: f32,
+    perez: [[f32; 5]; 3],  // Y, x, y
+    zenith: [f32; 3],      // Y (kcd/m^2), x, y at the zenith
+    ground_luminance: f32, // kcd/m^2
+    basis: DaylightBasis,
+}
+
+impl PreethamSky {
+    /// `turbidity` runs from 2 (very clear) to 10 (hazy)
+    pub fn new(sun_dir: Vector3, turbidity: f32, ground_albedo: f32) -> Self {
+        let sun_dir = sun_dir.normalize();
+        // The fit is only valid for the sun above the horizon
+        let theta_s = sun_dir.y.clamp(0.0, 1.0).acos();
+        let t = turbidity;
+
+        let chi = (4.0 / 9.0 - t / 120.0) * (PI - 2.0 * theta_s);
+        let zenith_y = ((4.0453 * t - 4.9710) * chi.tan() - 0.2155 * t + 2.4192).max(0.0);
+
+        let mut sky = PreethamSky {
+            sun_dir,
+            theta_s,
+            perez: [
+                perez_coefficients(&PEREZ_Y, t),
+                perez_coefficients(&PEREZ_X, t),
+                perez_coefficients(&PEREZ_YC, t),
+            ],
+            zenith: [zenith_y, zenith_chromaticity(&ZENITH_X, t, theta_s), zenith_chromaticity(&ZENITH_Y, t, theta_s)],
+            ground_luminance: 0.0,
+            basis: DaylightBasis::new(),
+        };
+
+        // Ground: albedo / pi times the illuminance from the sky and sun
+        let sun = SunSpectrum::new(sun_dir, turbidity);
+        let e_sun = sun.luminance * sun_solid_angle() * sun_dir.y.max(0.0);
+        sky.ground_luminance = ground_albedo / PI * (sky.sky_illuminance() + e_sun);
+        sky
+    }
+
+    /// Luminance (kcd/m^2) and chromaticity of the sky in direction `w`
+    pub fn xyy(&self, w: Vector3) -> [f32; 3] {
+        let w = w.normalize();
+        if w.y < 0.0 {
+            return [self.ground_luminance, self.zenith[1], self.zenith[2]];
+        }
+        let gamma = w.dot(self.sun_dir).clamp(-1.0, 1.0).acos();
+        let mut out = [0.0; 3];
+        for (i, v) in out.iter_mut().enumerate() {
+            let f = perez(&self.perez[i], w.y, gamma) / perez(&self.perez[i], 1.0, self.theta_s);
+            *v = self.zenith[i] * f;
+        }
+        out[0] = out[0].max(0.0);
+        out
+    }
+
+    /// Spectral sky radiance in direction `w`, in kcd/m^2-equivalent units
+    pub fn radiance(&self, w: Vector3, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let [lum, x, y] = self.xyy(w);
+        self.basis.spectrum(x, y, lum, lambda)
+    }
+
+    // Horizontal illuminance from the sky dome (cosine-weighted luminance integral)
+    fn sky_illuminance(&self) -> f32 {
+        const N_THETA: usize = 32;
+        const N_PHI: usize = 64;
+        let d_theta = 0.5 * PI / N_THETA as f32;
+        let d_phi = 2.0 * PI / N_PHI as f32;
+        let mut e = 0.0;
+        for i in 0..N_THETA {
+            let theta = (i as f32 + 0.5) * d_theta;
+            let (sin_theta, cos_theta) = theta.sin_cos();
+            for j in 0..N_PHI {
+                let phi = (j as f32 + 0.5) * d_phi;
+                let w = Vector3::new(sin_theta * phi.cos(), cos_theta, sin_theta * phi.sin());
+                e += self.xyy(w)[0] * cos_theta * sin_theta * d_theta * d_phi;
+            }
+        }
+        e
+    }
+}
+
+// --- 3. Sun ---
+
+/// Angular radius of the solar disk (radians)
+pub const SUN_ANGULAR_RADIUS: f32 = 0.004_654;
+
+/// Extraterrestrial luminance of the sun (kcd/m^2)
+const SUN_LUMINANCE: f32 = 1.96e6;
+
+pub fn sun_solid_angle() -> f32 {
+    2.0 * PI * (1.0 - SUN_ANGULAR_RADIUS.cos())
+}
+
+/// Solar radiance after the atmosphere: a 5778K blackbody attenuated by
+/// Rayleigh and aerosol (Angstrom) extinction along the relative air mass,
+/// as in the appendix of Preetham et al.
+pub struct SunSpectrum {
+    pub spectrum: Spectrum, // kcd/m^2-equivalent units
+    pub luminance: f32,
+}
+
+impl SunSpectrum {
+    pub fn new(sun_dir: Vector3, turbidity: f32) -> Self {
+        let sun_dir = sun_dir.normalize();
+        if sun_dir.y <= 0.0 {
+            return SunSpectrum { spectrum: Spectrum::Constant(0.0), luminance: 0.0 };
+        }
+
+        // Relative optical air mass (Kasten & Young)
+        let theta_deg = sun_dir.y.acos().to_degrees();
+        let air_mass = 1.0 / (sun_dir.y + 0.15 * (93.885 - theta_deg).powf(-1.253));
+
+        // Aerosol Angstrom coefficients from turbidity
+        let beta = 0.04608 * turbidity - 0.04586;
+        let alpha = 1.3;
+
+        let blackbody = Spectrum::blackbody(5778.0);
+        let norm = SUN_LUMINANCE / spectrum_to_photometric(&blackbody);
+
+        let mut lambda = Vec::new();
+        let mut values = Vec::new();
+        let mut l = 360.0f32;
+        while l <= 830.0 {
+            let l_um = l * 1e-3;
+            let tau_rayleigh = (-0.008735 * l_um.powf(-4.08) * air_mass).exp();
+            let tau_aerosol = (-beta * l_um.powf(-alpha) * air_mass).exp();
+            lambda.push(l);
+            values.push(blackbody.eval(l) * norm * tau_rayleigh * tau_aerosol);
+            l += 5.0;
+        }
+
+        let spectrum = Spectrum::PiecewiseLinear(PiecewiseLinearSpectrum::new(lambda, values));
+        let luminance = spectrum_to_photometric(&spectrum);
+        SunSpectrum { spectrum, luminance }
+    }
+}
+
+/// Direction towards the sun from elevation and azimuth in degrees
+/// (+y up; azimuth measured from +x towards +z)
+pub fn sun_direction(elevation: f32, azimuth: f32) -> Vector3 {
+    let (sin_e, cos_e) = elevation.to_radians().sin_cos();
+    let (sin_a, cos_a) = azimuth.to_radians().sin_cos();
+    Vector3::new(cos_e * cos_a, sin_e, cos_e * sin_a)
+}
@@ -1,5 +1,4 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub};
-use crate::core::math::Interval; // Assuming we might need math helpers
+use std::ops::{Add, Div, Mul, Sub};
 
 // --- CONSTANTS ---
 pub const N_SPECTRUM_SAMPLES: usize = 4; // The "Sweet Spot"
@@ -54,6 +53,23 @@ impl SampledSpectrum {
         SampledSpectrum { values: [val; N_SPECTRUM_SAMPLES] }
     }
     
+    pub fn is_black(&self) -> bool {
+        self.values.iter().all(|&v| v == 0.0)
+    }
+
+    pub fn max_value(&self) -> f32 {
+        self.values.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b))
+    }
+
+    pub fn average(&self) -> f32 {
+        self.values.iter().sum::<f32>() / N_SPECTRUM_SAMPLES as f32
+    }
+
+    // Component-wise e^x (Beer-Lambert transmittance)
+    pub fn exp(&self) -> Self {
+        SampledSpectrum { values: self.values.map(f32::exp) }
+    }
+
     // Convert RGB to Spectrum (Upsampling)
     // NOTE: This is a placeholder for the "Sigmoid Polynomial" table.
     // We use a constant reflection model for now to allow compilation.
@@ -65,7 +81,7 @@ impl SampledSpectrum {
     }
 
     // Convert Spectrum back to XYZ (Integration)
-    pub fn to_xyz(&self, lambdas: &SampledWavelengths) -> [f32; 3] {
+    pub fn to_xyz(self, lambdas: &SampledWavelengths) -> [f32; 3] {
         let mut x = 0.0;
         let mut y = 0.0;
         let mut z = 0.0;
@@ -92,7 +108,7 @@ impl SampledSpectrum {
     pub fn xyz_to_rgb(xyz: [f32; 3]) -> [f32; 3] {
         let x = xyz[0]; let y = xyz[1]; let z = xyz[2];
         [
-            3.240479 * x - 1.537150 * y - 0.498535 * z,
+            3.240479 * x - 1.53715 * y - 0.498535 * z,
             -0.969256 * x + 1.875991 * y + 0.041556 * z,
             0.055648 * x - 0.204043 * y + 1.057311 * z
         ]
@@ -128,7 +144,7 @@ impl Add for SampledSpectrum {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
         let mut v = [0.0; N_SPECTRUM_SAMPLES];
-        for i in 0..N_SPECTRUM_SAMPLES { v[i] = self.values[i] + rhs.values[i]; }
+        for (i, x) in v.iter_mut().enumerate() { *x = self.values[i] + rhs.values[i]; }
         SampledSpectrum { values: v }
     }
 }
@@ -137,7 +153,7 @@ impl Mul for SampledSpectrum {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
         let mut v = [0.0; N_SPECTRUM_SAMPLES];
-        for i in 0..N_SPECTRUM_SAMPLES { v[i] = self.values[i] * rhs.values[i]; }
+        for (i, x) in v.iter_mut().enumerate() { *x = self.values[i] * rhs.values[i]; }
         SampledSpectrum { values: v }
     }
 }
@@ -146,46 +162,16 @@ impl Mul<f32> for SampledSpectrum {
     type Output = Self;
     fn mul(self, scalar: f32) -> Self {
         let mut v = [0.0; N_SPECTRUM_SAMPLES];
-        for i in 0..N_SPECTRUM_SAMPLES { v[i] = self.values[i] * scalar; }
+        for (x, s) in v.iter_mut().zip(self.values) { *x = s * scalar; }
         SampledSpectrum { values: v }
     }
 }
 
-// --- 3. The Radiometric Wrappers (Updated) ---
-// These now wrap SampledSpectrum instead of the old RGB Spectrum
-
-#[derive(Debug, Clone, Copy)]
-pub struct Flux(pub SampledSpectrum);
-
-#[derive(Debug, Clone, Copy)]
-pub struct Irradiance(pub SampledSpectrum);
-
-#[derive(Debug, Clone, Copy)]
-pub struct Radiance(pub SampledSpectrum);
-
-// Wrapper Math
-impl Add for Radiance {
-    type Output = Radiance;
-    fn add(self, rhs: Self) -> Self { Radiance(self.0 + rhs.0) }
-}
-impl Mul<f32> for Radiance {
-    type Output = Radiance;
-    fn mul(self, scalar: f32) -> Self { Radiance(self.0 * scalar) }
-}
-impl Mul<SampledSpectrum> for Radiance {
-    type Output = Radiance;
-    fn mul(self, color: SampledSpectrum) -> Self { Radiance(self.0 * color) }
-}
-impl Div<f32> for Flux {
-    type Output = Irradiance;
-    fn div(self, area: f32) -> Irradiance { Irradiance(self.0 * (1.0 / area)) }
-}
-
 // --- Week 3 Days 6-7: Blackbody Radiation ---
 
 // Physical Constants
 const C: f32 = 299792458.0;       // Speed of Light [m/s]
-const H: f32 = 6.62606957e-34;    // Planck's Constant [J s]
+const H: f32 = 6.626_07e-34;    // Planck's Constant [J s]
 const KB: f32 = 1.3806488e-23;    // Boltzmann Constant [J/K]
 
 /// Calculates Blackbody Radiance for a given wavelength (nm) and temperature (K)
@@ -218,7 +204,7 @@ impl BlackbodySpectrum {
     pub fn new(temp_k: f32) -> Self {
         // Wien's Displacement Law: Find the peak wavelength (in meters)
         // b approx 2.8977721e-3 m K
-        let lambda_max_meters = 2.8977721e-3 / temp_k;
+        let lambda_max_meters = 2.897_772e-3 / temp_k;
         
         // Convert to nm to query our blackbody function
         let peak_val = blackbody(lambda_max_meters * 1.0e9, temp_k);
@@ -237,21 +223,188 @@ impl BlackbodySpectrum {
     // Helper to generate a SampledSpectrum from this Blackbody
     pub fn sample(&self, lambdas: &SampledWavelengths) -> SampledSpectrum {
         let mut values = [0.0; N_SPECTRUM_SAMPLES];
-        for i in 0..N_SPECTRUM_SAMPLES {
-            values[i] = self.eval(lambdas.lambda[i]);
+        for (v, &l) in values.iter_mut().zip(&lambdas.lambda) {
+            *v = self.eval(l);
         }
         SampledSpectrum { values }
     }
 }
 
+// --- Continuous Spectra (Light Emission) ---
+
+/// Wavelength range used for photometric integrals (CIE observer support)
+const LAMBDA_VISIBLE_MIN: f32 = 360.0;
+const LAMBDA_VISIBLE_MAX: f32 = 830.0;
+
+/// Piecewise-linear spectrum from (wavelength nm, value) samples, zero outside
+#[derive(Debug, Clone)]
+pub struct PiecewiseLinearSpectrum {
+    pub lambda: Vec<f32>, // Increasing
+    pub values: Vec<f32>,
+}
+
+impl PiecewiseLinearSpectrum {
+    pub fn new(lambda: Vec<f32>, values: Vec<f32>) -> Self {
+        assert_eq!(lambda.len(), values.len());
+        PiecewiseLinearSpectrum { lambda, values }
+    }
+
+    pub fn eval(&self, lambda: f32) -> f32 {
+        let n = self.lambda.len();
+        if n == 0 || lambda < self.lambda[0] || lambda > self.lambda[n - 1] {
+            return 0.0;
+        }
+        let i = self.lambda.partition_point(|&l| l <= lambda).clamp(1, n - 1) - 1;
+        if n == 1 {
+            return self.values[0];
+        }
+        let t = (lambda - self.lambda[i]) / (self.lambda[i + 1] - self.lambda[i]);
+        self.values[i] * (1.0 - t) + self.values[i + 1] * t
+    }
+}
+
+/// A spectral distribution defined at every wavelength (unlike
+/// `SampledSpectrum`, which only holds the values at the current samples)
+#[derive(Debug, Clone)]
+pub enum Spectrum {
+    Constant(f32),
+    Blackbody(BlackbodySpectrum), // Peak-normalized
+    PiecewiseLinear(PiecewiseLinearSpectrum),
+}
+
+impl Spectrum {
+    pub fn blackbody(temp_k: f32) -> Self {
+        Spectrum::Blackbody(BlackbodySpectrum::new(temp_k))
+    }
+
+    /// CIE standard illuminants by name: "stdillum-A" (incandescent),
+    /// "stdillum-D50", "stdillum-D65", "stdillum-D75" (daylight) and
+    /// "stdillum-E" (equal energy)
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "stdillum-A" => Some(Spectrum::blackbody(2856.0)),
+            "stdillum-D50" => Some(daylight_spectrum(5003.0)),
+            "stdillum-D65" => Some(daylight_spectrum(6504.0)),
+            "stdillum-D75" => Some(daylight_spectrum(7504.0)),
+            "stdillum-E" => Some(Spectrum::Constant(1.0)),
+            _ => None,
+        }
+    }
+
+    pub fn eval(&self, lambda: f32) -> f32 {
+        match self {
+            Spectrum::Constant(c) => *c,
+            Spectrum::Blackbody(b) => b.eval(lambda),
+            Spectrum::PiecewiseLinear(p) => p.eval(lambda),
+        }
+    }
+
+    pub fn sample(&self, lambdas: &SampledWavelengths) -> SampledSpectrum {
+        SampledSpectrum { values: lambdas.lambda.map(|l| self.eval(l)) }
+    }
+
+    /// Upper bound on the spectrum over the visible range
+    pub fn max_value(&self) -> f32 {
+        match self {
+            Spectrum::Constant(c) => *c,
+            Spectrum::Blackbody(_) => 1.0,
+            Spectrum::PiecewiseLinear(p) => p.values.iter().fold(0.0f32, |a, &b| a.max(b)),
+        }
+    }
+}
+
+/// Luminance of a spectrum relative to a constant spectrum of 1:
+/// `∫ s(λ) ȳ(λ) dλ / ∫ ȳ(λ) dλ`. Lights divide their spectrum by this so
+/// that their scale sets brightness independently of colour.
+pub fn spectrum_to_photometric(s: &Spectrum) -> f32 {
+    integrate_visible(|l| s.eval(l) * cie_y(l)) / integrate_visible(cie_y)
+}
+
+/// Maximum luminous efficacy of radiation (lm/W), reached at 555nm
+pub const K_M: f32 = 683.0;
+
+// ∫ f(λ) dλ over the visible range in 1nm steps
+fn integrate_visible(f: impl Fn(f32) -> f32) -> f32 {
+    let mut sum = 0.0;
+    let mut lambda = LAMBDA_VISIBLE_MIN;
+    while lambda <= LAMBDA_VISIBLE_MAX {
+        sum += f(lambda);
+        lambda += 1.0;
+    }
+    sum
+}
+
+/// Radiometric content `∫ s(λ) dλ`: watts per unit of a spectral power
+/// distribution given in W/nm (likewise for intensity or radiance)
+pub fn spectrum_to_radiometric(s: &Spectrum) -> f32 {
+    integrate_visible(|l| s.eval(l))
+}
+
+/// Luminous content `K_m ∫ s(λ) ȳ(λ) dλ`: lumens per unit of a spectral
+/// power distribution given in W/nm (likewise cd or nits)
+pub fn spectrum_to_luminous(s: &Spectrum) -> f32 {
+    K_M * integrate_visible(|l| s.eval(l) * cie_y(l))
+}
+
+// --- CIE Daylight (D-series) Illuminants ---
+// S0, S1, S2 components of the CIE daylight model, 380..780nm in 10nm steps.
+// A daylight spectrum with chromaticity (x, y) is S0 + M1 * S1 + M2 * S2.
+
+const DAYLIGHT_LAMBDA_MIN: f32 = 380.0;
+const DAYLIGHT_LAMBDA_STEP: f32 = 10.0;
+
+const DAYLIGHT_S0: [f32; 41] = [
+    63.4, 65.8, 94.8, 104.8, 105.9, 96.8, 113.9, 125.6, 125.5, 121.3, 121.3, 113.5, 113.1, 110.8,
+    106.5, 108.8, 105.3, 104.4, 100.0, 96.0, 95.1, 89.1, 90.5, 90.3, 88.4, 84.0, 85.1, 81.9, 82.6,
+    84.9, 81.3, 71.9, 74.3, 76.4, 63.3, 71.7, 77.0, 65.2, 47.7, 68.6, 65.0,
+];
+const DAYLIGHT_S1: [f32; 41] = [
+    38.5, 35.0, 43.4, 46.3, 43.9, 37.1, 36.7, 35.9, 32.6, 27.9, 24.3, 20.1, 16.2, 13.2, 8.6, 6.1,
+    4.2, 1.9, 0.0, -1.6, -3.5, -3.5, -5.8, -7.2, -8.6, -9.5, -10.9, -10.7, -12.0, -14.0, -13.6,
+    -12.0, -13.3, -12.9, -10.6, -11.6, -12.2, -10.2, -7.8, -11.2, -10.4,
+];
+const DAYLIGHT_S2: [f32; 41] = [
+    3.0, 1.2, -1.1, -0.5, -0.7, -1.2, -2.6, -2.9, -2.8, -2.6, -2.6, -1.8, -1.5, -1.3, -1.2, -1.0,
+    -0.5, -0.3, 0.0, 0.2, 0.5, 2.1, 3.2, 4.1, 4.7, 5.1, 6.7, 7.3, 8.6, 9.8, 10.2, 8.3, 9.6, 8.5,
+    7.0, 7.6, 8.0, 6.7, 5.2, 7.4, 6.8,
+];
+
+/// The S0, S1, S2 basis spectra of the daylight model
+pub fn daylight_basis() -> [Spectrum; 3] {
+    let lambda: Vec<f32> = (0..41).map(|i| DAYLIGHT_LAMBDA_MIN + i as f32 * DAYLIGHT_LAMBDA_STEP).collect();
+    [DAYLIGHT_S0, DAYLIGHT_S1, DAYLIGHT_S2]
+        .map(|table| Spectrum::PiecewiseLinear(PiecewiseLinearSpectrum::new(lambda.clone(), table.to_vec())))
+}
+
+/// CIE daylight illuminant with correlated colour temperature `cct`
+/// (4000..25000K), e.g. 6504K for D65. Relative values, 100 at 560nm.
+pub fn daylight_spectrum(cct: f32) -> Spectrum {
+    // Chromaticity on the daylight locus
+    let t = cct.clamp(4000.0, 25000.0) as f64;
+    let x = if t <= 7000.0 {
+        -4.6070e9 / (t * t * t) + 2.9678e6 / (t * t) + 0.09911e3 / t + 0.244063
+    } else {
+        -2.0064e9 / (t * t * t) + 1.9018e6 / (t * t) + 0.24748e3 / t + 0.237040
+    } as f32;
+    let y = -3.0 * x * x + 2.870 * x - 0.275;
+
+    let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+    let m1 = (-1.3515 - 1.7703 * x + 5.9114 * y) / m;
+    let m2 = (0.0300 - 31.4424 * x + 30.0717 * y) / m;
+
+    let values = (0..41).map(|i| DAYLIGHT_S0[i] + m1 * DAYLIGHT_S1[i] + m2 * DAYLIGHT_S2[i]).collect();
+    let lambda = (0..41).map(|i| DAYLIGHT_LAMBDA_MIN + i as f32 * DAYLIGHT_LAMBDA_STEP).collect();
+    Spectrum::PiecewiseLinear(PiecewiseLinearSpectrum::new(lambda, values))
+}
+
 // --- Missing Math Implementations for Week 5 ---
 
 impl Sub for SampledSpectrum {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
         let mut v = [0.0; N_SPECTRUM_SAMPLES];
-        for i in 0..N_SPECTRUM_SAMPLES { 
-            v[i] = self.values[i] - rhs.values[i]; 
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = self.values[i] - rhs.values[i];
         }
         SampledSpectrum { values: v }
     }
@@ -261,9 +414,9 @@ impl Div for SampledSpectrum {
     type Output = Self;
     fn div(self, rhs: Self) -> Self {
         let mut v = [0.0; N_SPECTRUM_SAMPLES];
-        for i in 0..N_SPECTRUM_SAMPLES { 
+        for (i, x) in v.iter_mut().enumerate() {
             // Avoid NaN propagation if dividing by zero (though rare in Fresnel)
-            v[i] = if rhs.values[i] != 0.0 { self.values[i] / rhs.values[i] } else { 0.0 }; 
+            *x = if rhs.values[i] != 0.0 { self.values[i] / rhs.values[i] } else { 0.0 }; 
         }
         SampledSpectrum { values: v }
     }
@@ -1,4 +1,4 @@
-use crate::core::geometry::{Point3, Vector3, Normal3};
+use crate::core::geometry::{Point3, Vector3};
 use crate::core::ray::Ray; // Import Ray
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -83,6 +83,33 @@ impl Transform {
         Transform::new(Matrix4x4 { m })
     }
 
+    /// Rotation by `theta` degrees around `axis`
+    pub fn rotate(theta: f32, axis: Vector3) -> Self {
+        let a = axis.normalize();
+        let (sin_theta, cos_theta) = theta.to_radians().sin_cos();
+        let mut m = [[0.0; 4]; 4];
+        m[0][0] = a.x * a.x + (1.0 - a.x * a.x) * cos_theta;
+        m[0][1] = a.x * a.y * (1.0 - cos_theta) - a.z * sin_theta;
+        m[0][2] = a.x * a.z * (1.0 - cos_theta) + a.y * sin_theta;
+        m[1][0] = a.x * a.y * (1.0 - cos_theta) + a.z * sin_theta;
+        m[1][1] = a.y * a.y + (1.0 - a.y * a.y) * cos_theta;
+        m[1][2] = a.y * a.z * (1.0 - cos_theta) - a.x * sin_theta;
+        m[2][0] = a.x * a.z * (1.0 - cos_theta) - a.y * sin_theta;
+        m[2][1] = a.y * a.z * (1.0 - cos_theta) + a.x * sin_theta;
+        m[2][2] = a.z * a.z + (1.0 - a.z * a.z) * cos_theta;
+        m[3][3] = 1.0;
+        Transform::new(Matrix4x4 { m })
+    }
+
+    /// Translation by `delta`
+    pub fn translate(delta: Vector3) -> Self {
+        let mut m = Matrix4x4::identity().m;
+        m[0][3] = delta.x;
+        m[1][3] = delta.y;
+        m[2][3] = delta.z;
+        Transform::new(Matrix4x4 { m })
+    }
+
     pub fn new(m: Matrix4x4) -> Self {
         match m.inverse() {
             Some(inv) => Transform { m, m_inv: inv },
@@ -109,15 +136,6 @@ impl Transform {
         }
     }
 
-    pub fn transform_normal(&self, n: Normal3) -> Normal3 {
-        let x = n.x; let y = n.y; let z = n.z;
-        Normal3 {
-            x: self.m_inv.m[0][0] * x + self.m_inv.m[1][0] * y + self.m_inv.m[2][0] * z,
-            y: self.m_inv.m[0][1] * x + self.m_inv.m[1][1] * y + self.m_inv.m[2][1] * z,
-            z: self.m_inv.m[0][2] * x + self.m_inv.m[1][2] * y + self.m_inv.m[2][2] * z,
-        }
-    }
-
     pub fn inverse(&self) -> Transform {
         Transform { m: self.m_inv, m_inv: self.m }
     }
@@ -144,5 +162,3 @@ impl Transform {
         }
     }
 }
-
-fn try_inverse(m: &Matrix4x4) -> Option<Matrix4x4> { m.inverse() }
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::core::bsdf::Frame;
+use crate::core::camera::PerspectiveCamera;
+use crate::core::film::Film;
+use crate::core::geometry::Vector3;
+use crate::core::integrator::render_pixels;
+use crate::core::math::sample_cosine_hemisphere;
+use crate::core::primitive::Primitive;
+
+/// Ambient occlusion: the fraction of the cosine-weighted hemisphere above
+/// the first hit that is unoccluded within `max_distance`.
+///
+/// With cosine-weighted directions the estimator is just the visibility of
+/// one sampled ray, so each camera sample traces exactly one AO ray.
+pub struct AOIntegrator {
+    pub max_distance: f32,
+}
+
+impl AOIntegrator {
+    pub fn new(max_distance: f32) -> Self {
+        AOIntegrator { max_distance }
+    }
+
+    pub fn render(&self, scene: &dyn Primitive, camera: &PerspectiveCamera, film: &mut Film) {
+        render_pixels(camera, film, "Ambient Occlusion", |ray, _wavelengths, sampler| {
+            let black = Vector3::new(0.0, 0.0, 0.0);
+            let Some((_, si, _)) = scene.intersect(&ray) else { return black; };
+
+            // Hemisphere on the side facing the viewer
+            let mut n = Vector3::from(si.shading.n);
+            if n.dot(-ray.d) < 0.0 {
+                n = -n;
+            }
+            let w = Frame::from_z(n).from_local(sample_cosine_hemisphere(sampler.get_2d()));
+
+            let occluded = match scene.intersect(&si.core.spawn_ray(w)) {
+                Some((t, _, _)) => t < self.max_distance,
+                None => false,
+            };
+            if occluded { black } else { Vector3::new(1.0, 1.0, 1.0) }
+        });
+    }
+}
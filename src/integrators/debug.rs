@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use crate::core::camera::PerspectiveCamera;
+use crate::core::film::Film;
+use crate::core::geometry::{Normal3, Vector3};
+use crate::core::integrator::render_pixels;
+use crate::core::primitive::Primitive;
+
+/// What the debug integrator visualises at the first hit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DebugMode {
+    ShadingNormals,   // n * 0.5 + 0.5
+    GeometricNormals,
+    UV,               // (u, v, 0)
+    Barycentrics,     // (b0, b1, b2), black for non-triangles
+    Depth,            // Hit distance / max distance, white = far
+    MaterialID,       // Random colour per material instance
+}
+
+impl DebugMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "normals" | "shading_normals" => Some(DebugMode::ShadingNormals),
+            "geometric_normals" => Some(DebugMode::GeometricNormals),
+            "uv" => Some(DebugMode::UV),
+            "barycentrics" => Some(DebugMode::Barycentrics),
+            "depth" => Some(DebugMode::Depth),
+            "material_id" => Some(DebugMode::MaterialID),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DebugMode::ShadingNormals => "shading_normals",
+            DebugMode::GeometricNormals => "geometric_normals",
+            DebugMode::UV => "uv",
+            DebugMode::Barycentrics => "barycentrics",
+            DebugMode::Depth => "depth",
+            DebugMode::MaterialID => "material_id",
+        }
+    }
+}
+
+/// Look-dev / scene-debugging views of the first camera-ray hit.
+pub struct DebugIntegrator {
+    pub mode: DebugMode,
+    pub max_distance: Option<f32>, // Depth normalisation (None = scene diagonal)
+}
+
+impl DebugIntegrator {
+    pub fn new(mode: DebugMode) -> Self {
+        DebugIntegrator { mode, max_distance: None }
+    }
+
+    pub fn render(&self, scene: &dyn Primitive, camera: &PerspectiveCamera, film: &mut Film) {
+        let max_distance = self.max_distance.unwrap_or_else(|| {
+            let b = scene.bounds();
+            (b.max - b.min).length().max(1e-3)
+        });
+        let description = format!("Debug: {}", self.mode.name());
+
+        render_pixels(camera, film, &description, |ray, _wavelengths, _sampler| {
+            let black = Vector3::new(0.0, 0.0, 0.0);
+            let Some((t, si, material)) = scene.intersect(&ray) else { return black; };
+            match self.mode {
+                DebugMode::ShadingNormals => normal_color(si.shading.n),
+                DebugMode::GeometricNormals => normal_color(si.core.n),
+                DebugMode::UV => Vector3::new(si.core.uv.x, si.core.uv.y, 0.0),
+                DebugMode::Barycentrics => match si.barycentrics {
+                    Some([b0, b1, b2]) => Vector3::new(b0, b1, b2),
+                    None => black,
+                },
+                DebugMode::Depth => {
+                    let d = (t / max_distance).clamp(0.0, 1.0);
+                    Vector3::new(d, d, d)
+                }
+                DebugMode::MaterialID => match material {
+                    // The Arc's address identifies the material instance
+                    Some(m) => id_color(Arc::as_ptr(&m) as *const () as usize as u64),
+                    None => black,
+                },
+            }
+        });
+    }
+}
+
+fn normal_color(n: Normal3) -> Vector3 {
+    let n = Vector3::from(n).normalize();
+    Vector3::new(n.x * 0.5 + 0.5, n.y * 0.5 + 0.5, n.z * 0.5 + 0.5)
+}
+
+// Well-spread, stable colour from an integer id (splitmix64 finaliser)
+fn id_color(id: u64) -> Vector3 {
+    let mut h = id.wrapping_add(0x9e3779b97f4a7c15);
+    h = (h ^ (h >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94d049bb133111eb);
+    h ^= h >> 31;
+    let channel = |shift: u32| 0.2 + 0.8 * ((h >> shift) & 0xff) as f32 / 255.0;
+    Vector3::new(channel(0), channel(8), channel(16))
+}
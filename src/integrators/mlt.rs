@@ -0,0 +1,197 @@
+use crate::core::camera::PerspectiveCamera;
+use crate::core::film::Film;
+use crate::core::geometry::{Point2, Vector3};
+use crate::core::integrator::{li, to_rgb};
+use crate::core::light::Light;
+use crate::core::lightsampler::{BVHLightSampler, LightSampler};
+use crate::core::math::{Distribution1D, RNG};
+use crate::core::primitive::Primitive;
+use crate::core::sampler::{MLTSampler, Sampler};
+use crate::core::spectrum::{SampledSpectrum, SampledWavelengths};
+
+/// Primary Sample Space Metropolis Light Transport (Kelemen et al. 2002).
+///
+/// A path is a deterministic function of the vector of random numbers the
+/// path tracer consumes. We run Markov chains over that vector: small steps
+/// explore the neighbourhood of a bright path, large steps jump anywhere.
+/// Radiance comes from the regular path tracer (`li`) driven by an
+/// `MLTSampler` that replays the mutated vector.
+///
+/// 1. Bootstrap: `n_bootstrap` independent paths estimate the image's mean
+///    luminance `b` and give a distribution to pick chain start points from.
+/// 2. Chains: each proposal is accepted with probability I(new) / I(old),
+///    and both states are splatted with their expected weights.
+pub struct MLTIntegrator {
+    pub max_depth: usize,
+    pub n_bootstrap: usize,
+    pub n_chains: usize,
+    pub mutations_per_pixel: usize,
+    pub sigma: f32,
+    pub large_step_probability: f32,
+}
+
+// A full evaluation of one primary sample vector
+struct PathSample {
+    rgb: Vector3,
+    importance: f32, // Scalar contribution (luminance) driving the chain
+    p_raster: Point2,
+}
+
+impl MLTIntegrator {
+    pub fn new(
+        max_depth: usize,
+        n_bootstrap: usize,
+        n_chains: usize,
+        mutations_per_pixel: usize,
+        sigma: f32,
+        large_step_probability: f32,
+    ) -> Self {
+        MLTIntegrator {
+            max_depth,
+            n_bootstrap,
+            n_chains,
+            mutations_per_pixel,
+            sigma,
+            large_step_probability,
+        }
+    }
+
+    // Maps the sampler's vector to a film position + wavelengths and traces it
+    fn l(
+        &self,
+        scene: &dyn Primitive,
+        lights: &[Box<dyn Light>],
+        light_sampler: &dyn LightSampler,
+        camera: &PerspectiveCamera,
+        resolution: Point2,
+        sampler: &mut MLTSampler,
+    ) -> PathSample {
+        let u = sampler.get_2d();
+        let p_raster = Point2 { x: u.x * resolution.x, y: u.y * resolution.y };
+        let ray = camera.generate_ray(p_raster, resolution, 90.0);
+
+        let wavelengths = SampledWavelengths::sample_uniform(sampler.get_1d());
+        let l: SampledSpectrum = li(ray, &wavelengths, scene, lights, light_sampler, sampler, self.max_depth);
+
+        PathSample {
+            rgb: to_rgb(l, &wavelengths),
+            importance: l.to_xyz(&wavelengths)[1].max(0.0),
+            p_raster,
+        }
+    }
+
+    pub fn render(
+        &self,
+        scene: &dyn Primitive,
+        lights: &[Box<dyn Light>],
+        camera: &PerspectiveCamera,
+        film: &mut Film,
+    ) {
+        let resolution = Point2 {
+            x: film.resolution.x as f32,
+            y: film.resolution.y as f32,
+        };
+
+        println!(
+            "Rendering {}x{} image (PSSMLT, {} chains, {} mutations/pixel)...",
+            film.resolution.x, film.resolution.y, self.n_chains, self.mutations_per_pixel
+        );
+
+        let light_sampler = BVHLightSampler::new(lights);
+
+        // === 1. Bootstrap: estimate normalization b ===
+        let bootstrap_weights: Vec<f32> = (0..self.n_bootstrap)
+            .map(|i| {
+                let mut sampler =
+                    MLTSampler::new(i as u64, self.sigma, self.large_step_probability);
+                self.l(scene, lights, &light_sampler, camera, resolution, &mut sampler).importance
+            })
+            .collect();
+
+        if bootstrap_weights.is_empty() {
+            return;
+        }
+        let bootstrap = Distribution1D::new(&bootstrap_weights);
+        let b = bootstrap.func_int;
+        if b == 0.0 {
+            println!("\nAll bootstrap paths carried zero radiance; nothing to render.");
+            return;
+        }
+
+        // Each splat is pre-scaled so the film ends up with the pixel estimate
+        let n_pixels = (film.resolution.x * film.resolution.y) as u64;
+        let total_mutations = self.mutations_per_pixel as u64 * n_pixels;
+        let n_chains = self.n_chains.max(1) as u64;
+        let scale = b / self.mutations_per_pixel as f32;
+
+        // === 2. Run the Markov chains ===
+        for chain in 0..n_chains {
+            let begin = chain * total_mutations / n_chains;
+            let end = ((chain + 1) * total_mutations / n_chains).min(total_mutations);
+            let mut rng = RNG::new(chain, 1);
+
+            // Start from a bootstrap path chosen proportionally to its contribution;
+            // re-seeding the sampler with the same index replays that path.
+            let (bootstrap_index, _) = bootstrap.sample_discrete(rng.next_f32());
+            let mut sampler = MLTSampler::new(
+                bootstrap_index as u64,
+                self.sigma,
+                self.large_step_probability,
+            );
+            let mut current = self.l(scene, lights, &light_sampler, camera, resolution, &mut sampler);
+
+            for _ in begin..end {
+                sampler.start_iteration();
+                let proposed = self.l(scene, lights, &light_sampler, camera, resolution, &mut sampler);
+
+                let accept = if current.importance > 0.0 {
+                    (proposed.importance / current.importance).min(1.0)
+                } else {
+                    1.0
+                };
+
+                // Expected-value splatting: both states contribute every step
+                if accept > 0.0 && proposed.importance > 0.0 {
+                    film.add_splat(
+                        proposed.p_raster,
+                        proposed.rgb * (accept * scale / proposed.importance),
+                    );
+                }
+                if accept < 1.0 && current.importance > 0.0 {
+                    film.add_splat(
+                        current.p_raster,
+                        current.rgb * ((1.0 - accept) * scale / current.importance),
+                    );
+                }
+
+                if rng.next_f32() < accept {
+                    current = proposed;
+                    sampler.accept();
+                } else {
+                    sampler.reject();
+                }
+            }
+
+            print!(".");
+            use std::io::Write;
+            std::io::stdout().flush().unwrap();
+        }
+
+        println!("\nDone!");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrators::tests::{film_mean, wall_reference, wall_scene, RESOLUTION};
+
+    #[test]
+    fn converges_to_the_path_traced_image() {
+        let (scene, lights, camera) = wall_scene();
+        let mut film = Film::new(RESOLUTION);
+        MLTIntegrator::new(5, 20_000, 64, 2048, 0.01, 0.3).render(&scene, &lights, &camera, &mut film);
+        let (mean, reference) = (film_mean(&film), wall_reference());
+        assert!((mean - reference).abs() < 0.05 * reference, "MLT {} vs path {}", mean, reference);
+    }
+}
@@ -0,0 +1,131 @@
+pub mod sppm;
+pub mod mlt;
+pub mod volpath;
+pub mod ao;
+pub mod debug;
+
+use crate::core::camera::PerspectiveCamera;
+use crate::core::film::Film;
+use crate::core::light::Light;
+use crate::core::primitive::Primitive;
+
+use self::ao::AOIntegrator;
+use self::debug::{DebugIntegrator, DebugMode};
+use self::mlt::MLTIntegrator;
+use self::sppm::SPPMIntegrator;
+use self::volpath::VolPathIntegrator;
+
+/// Integrator names accepted by `render_by_name`
+pub const INTEGRATOR_NAMES: &[&str] = &[
+    "path", "volpath", "sppm", "mlt", "ao",
+    "shading_normals", "geometric_normals", "uv", "barycentrics",
+    "depth", "material_id",
+];
+
+/// Renders with the integrator called `name`, using default parameters
+pub fn render_by_name(
+    name: &str,
+    scene: &dyn Primitive,
+    lights: &Vec<Box<dyn Light>>,
+    camera: &PerspectiveCamera,
+    film: &mut Film,
+) -> Result<(), String> {
+    match name {
+        "path" => crate::core::integrator::render(scene, lights, camera, film),
+        "volpath" => VolPathIntegrator::new(5, None).render(scene, lights, camera, film),
+        "sppm" => SPPMIntegrator::new(16, 100_000, 5, 0.05).render(scene, lights, camera, film),
+        "mlt" => MLTIntegrator::new(5, 100_000, 64, 16, 0.01, 0.3).render(scene, lights, camera, film),
+        "ao" => AOIntegrator::new(1.0).render(scene, camera, film),
+        _ => match DebugMode::from_name(name) {
+            Some(mode) => DebugIntegrator::new(mode).render(scene, camera, film),
+            None => {
+                return Err(format!(
+                    "unknown integrator '{}' (expected one of: {})",
+                    name,
+                    INTEGRATOR_NAMES.join(", ")
+                ))
+            }
+        },
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Arc;
+
+    use crate::core::camera::PerspectiveCamera;
+    use crate::core::film::Film;
+    use crate::core::geometry::{Point2, Point2i, Point3, Vector3};
+    use crate::core::light::Light;
+    use crate::core::material::{EmissiveMaterial, MatteMaterial};
+    use crate::core::primitive::PrimitiveList;
+    use crate::core::scene::SceneBuilder;
+    use crate::core::spectrum::SampledSpectrum;
+    use crate::core::texture::ConstantTexture;
+    use crate::core::transform::Transform;
+    use crate::shapes::triangle::TriangleMesh;
+
+    pub const RESOLUTION: Point2i = Point2i { x: 6, y: 6 };
+
+    /// Square of half-size `half` around `center`, parallel to the xy plane
+    /// and facing -z unless `facing_back`
+    pub fn quad(center: Point3, half: f32, facing_back: bool) -> Arc<TriangleMesh> {
+        let corner = |x: f32, y: f32| Point3::new(center.x + x * half, center.y + y * half, center.z);
+        let p = vec![corner(-1.0, -1.0), corner(1.0, -1.0), corner(1.0, 1.0), corner(-1.0, 1.0)];
+        let indices = if facing_back { vec![0, 1, 2, 0, 2, 3] } else { vec![0, 2, 1, 0, 3, 2] };
+        Arc::new(TriangleMesh::new(indices, p, None))
+    }
+
+    /// Diffuse wall at z = 0 that fills the view of `wall_camera`
+    pub fn add_wall(builder: &mut SceneBuilder) {
+        let kd = Arc::new(ConstantTexture::new(SampledSpectrum::new(0.5)));
+        let sigma = Arc::new(ConstantTexture::new(SampledSpectrum::new(0.0)));
+        builder.add_mesh(quad(Point3::new(0.0, 0.0, 0.0), 6.0, false), Some(Arc::new(MatteMaterial::new(kd, sigma))), 1.0);
+    }
+
+    /// Square emitter behind the camera, facing the wall; returns its lights
+    pub fn add_backlight(builder: &mut SceneBuilder, center: Point3, radiance: f32) -> Vec<usize> {
+        let emit = Arc::new(ConstantTexture::new(SampledSpectrum::new(radiance)));
+        builder.add_mesh(quad(center, 1.0, true), Some(Arc::new(EmissiveMaterial::new(emit))), 1.0)
+    }
+
+    pub fn wall_camera() -> PerspectiveCamera {
+        let transform = Transform::look_at(
+            Point3::new(0.0, 0.0, -3.0),
+            Point3::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        let res = Point2 { x: RESOLUTION.x as f32, y: RESOLUTION.y as f32 };
+        PerspectiveCamera::new(transform, res, 90.0)
+    }
+
+    /// A diffuse wall filling the view, lit by a square emitter behind the camera
+    pub fn wall_scene() -> (PrimitiveList, Vec<Box<dyn Light>>, PerspectiveCamera) {
+        let mut builder = SceneBuilder::new();
+        add_wall(&mut builder);
+        add_backlight(&mut builder, Point3::new(0.0, 0.0, -4.0), 4.0);
+        let (scene, lights) = builder.build();
+        (scene, lights, wall_camera())
+    }
+
+    /// Mean over the film of the pixels' channel sums
+    pub fn film_mean(film: &Film) -> f32 {
+        let mut sum = 0.0;
+        for y in 0..film.resolution.y {
+            for x in 0..film.resolution.x {
+                let c = film.pixel(Point2i { x, y });
+                sum += c.x + c.y + c.z;
+            }
+        }
+        sum / (film.resolution.x * film.resolution.y) as f32
+    }
+
+    /// Path-traced reference for `wall_scene`
+    pub fn wall_reference() -> f32 {
+        let (scene, lights, camera) = wall_scene();
+        let mut film = Film::new(RESOLUTION);
+        crate::core::integrator::render(&scene, &lights, &camera, &mut film);
+        film_mean(&film)
+    }
+}
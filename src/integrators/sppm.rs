@@ -0,0 +1,470 @@
+use std::f32::consts::PI;
+
+use crate::core::bsdf::{TransportMode, BSDF};
+use crate::core::camera::PerspectiveCamera;
+use crate::core::film::Film;
+use crate::core::geometry::{Bounds3, Point2, Point2i, Point3, Vector3};
+use crate::core::integrator::{to_rgb, unoccluded};
+use crate::core::interaction::SurfaceInteraction;
+use crate::core::light::Light;
+use crate::core::lightsampler::{BVHLightSampler, LightSampler, PowerLightSampler};
+use crate::core::math::RNG;
+use crate::core::primitive::Primitive;
+use crate::core::ray::Ray;
+use crate::core::spectrum::{SampledSpectrum, SampledWavelengths};
+
+// Radius reduction factor (alpha in Hachisuka & Jensen). 2/3 is the usual choice.
+const GAMMA: f32 = 2.0 / 3.0;
+
+/// Stochastic Progressive Photon Mapping.
+///
+/// Every iteration runs three passes:
+/// 1. Camera pass: one path per pixel is followed through specular bounces
+///    and a visible point is stored at the first non-specular surface.
+/// 2. Photon pass: photons leave the lights and are splatted onto nearby
+///    visible points found through a spatial hash grid.
+/// 3. Update: each pixel's gather radius shrinks and its flux is rescaled.
+///
+/// Memory is bounded by the pixel count (one visible point per pixel and a
+/// hash table with one bucket per pixel), not by the number of photons.
+pub struct SPPMIntegrator {
+    pub n_iterations: usize,
+    pub photons_per_iteration: usize,
+    pub max_depth: usize,
+    pub initial_radius: f32,
+}
+
+// First non-specular hit of a camera path
+struct VisiblePoint {
+    p: Point3,
+    wo: Vector3,
+    bsdf: BSDF,
+    beta: SampledSpectrum,
+}
+
+// Per-pixel state carried across iterations
+struct SPPMPixel {
+    radius: f32,
+    ld: Vector3,          // Accumulated direct lighting (RGB)
+    vp: Option<VisiblePoint>,
+    phi: SampledSpectrum, // Photon flux gathered this iteration
+    m: u32,               // Photons gathered this iteration
+    n: f32,               // Accumulated (reduced) photon count
+    tau: Vector3,         // Accumulated reflected flux (RGB)
+}
+
+// --- Spatial Hash Grid over the visible points ---
+struct VisiblePointGrid {
+    bounds: Bounds3,
+    res: [i32; 3],
+    buckets: Vec<Vec<usize>>,
+}
+
+impl VisiblePointGrid {
+    fn build(pixels: &[SPPMPixel]) -> Option<Self> {
+        // 1. Bounds of all visible points grown by their radius
+        let mut bounds: Option<Bounds3> = None;
+        let mut max_radius = 0.0f32;
+        for pixel in pixels {
+            let Some(vp) = &pixel.vp else { continue; };
+            let r = Vector3::new(pixel.radius, pixel.radius, pixel.radius);
+            let pb = Bounds3::new(vp.p + (-r), vp.p + r);
+            bounds = Some(match bounds {
+                Some(b) => b.union_point(pb.min).union_point(pb.max),
+                None => pb,
+            });
+            max_radius = max_radius.max(pixel.radius);
+        }
+        let bounds = bounds?;
+
+        // 2. Cell size roughly equal to the largest gather radius
+        let diag = bounds.max - bounds.min;
+        let max_diag = diag.x.max(diag.y).max(diag.z);
+        let base_res = (max_diag / max_radius) as i32;
+        let axis_res = |d: f32| ((base_res as f32 * d / max_diag) as i32).max(1);
+        let res = [axis_res(diag.x), axis_res(diag.y), axis_res(diag.z)];
+
+        let mut grid = VisiblePointGrid {
+            bounds,
+            res,
+            buckets: vec![Vec::new(); pixels.len()],
+        };
+
+        // 3. Insert each visible point into every cell its sphere overlaps,
+        // once per bucket even when several of those cells hash together
+        for (index, pixel) in pixels.iter().enumerate() {
+            let Some(vp) = &pixel.vp else { continue; };
+            let r = Vector3::new(pixel.radius, pixel.radius, pixel.radius);
+            let (c_min, _) = grid.to_grid(vp.p + (-r));
+            let (c_max, _) = grid.to_grid(vp.p + r);
+            for z in c_min[2]..=c_max[2] {
+                for y in c_min[1]..=c_max[1] {
+                    for x in c_min[0]..=c_max[0] {
+                        let h = grid.hash([x, y, z]);
+                        if grid.buckets[h].last() != Some(&index) {
+                            grid.buckets[h].push(index);
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(grid)
+    }
+
+    // Cell containing p (clamped) and whether p was inside the grid bounds
+    fn to_grid(&self, p: Point3) -> ([i32; 3], bool) {
+        let d = self.bounds.max - self.bounds.min;
+        let o = p - self.bounds.min;
+        let rel = [o.x / d.x, o.y / d.y, o.z / d.z];
+
+        let mut cell = [0i32; 3];
+        let mut in_bounds = true;
+        for axis in 0..3 {
+            let c = (rel[axis] * self.res[axis] as f32).floor() as i32;
+            in_bounds &= c >= 0 && c < self.res[axis];
+            cell[axis] = c.clamp(0, self.res[axis] - 1);
+        }
+        (cell, in_bounds)
+    }
+
+    fn hash(&self, c: [i32; 3]) -> usize {
+        let h = (c[0].wrapping_mul(73856093) as u32)
+            ^ (c[1].wrapping_mul(19349663) as u32)
+            ^ (c[2].wrapping_mul(83492791) as u32);
+        h as usize % self.buckets.len()
+    }
+
+    // Candidate visible points near p (may include hash collisions)
+    fn lookup(&self, p: Point3) -> &[usize] {
+        let (cell, in_bounds) = self.to_grid(p);
+        if !in_bounds {
+            return &[];
+        }
+        &self.buckets[self.hash(cell)]
+    }
+}
+
+impl SPPMIntegrator {
+    pub fn new(
+        n_iterations: usize,
+        photons_per_iteration: usize,
+        max_depth: usize,
+        initial_radius: f32,
+    ) -> Self {
+        SPPMIntegrator { n_iterations, photons_per_iteration, max_depth, initial_radius }
+    }
+
+    pub fn render(
+        &self,
+        scene: &dyn Primitive,
+        lights: &[Box<dyn Light>],
+        camera: &PerspectiveCamera,
+        film: &mut Film,
+    ) {
+        let res = film.resolution;
+        let res_f = Point2 { x: res.x as f32, y: res.y as f32 };
+        let n_pixels = (res.x * res.y) as usize;
+
+        let mut pixels: Vec<SPPMPixel> = (0..n_pixels)
+            .map(|_| SPPMPixel {
+                radius: self.initial_radius,
+                ld: Vector3::new(0.0, 0.0, 0.0),
+                vp: None,
+                phi: SampledSpectrum::new(0.0),
+                m: 0,
+                n: 0.0,
+                tau: Vector3::new(0.0, 0.0, 0.0),
+            })
+            .collect();
+
+        println!(
+            "Rendering {}x{} image (SPPM, {} iterations x {} photons)...",
+            res.x, res.y, self.n_iterations, self.photons_per_iteration
+        );
+
+        // NEE picks lights by importance at the visible point, photons by power
+        let light_sampler = BVHLightSampler::new(lights);
+        let photon_light_sampler = PowerLightSampler::new(lights);
+
+        for iter in 0..self.n_iterations {
+            // One set of wavelengths per iteration, shared by camera and photon
+            // passes. Camera, photon and wavelength streams are 3 * iter + 0/1/2
+            let lambda = SampledWavelengths::sample_uniform(RNG::new(0, (3 * iter + 2) as u64).next_f32());
+
+            // === 1. Camera pass ===
+            for y in 0..res.y {
+                for x in 0..res.x {
+                    let index = (y * res.x + x) as usize;
+                    let mut rng = RNG::new(index as u64, (3 * iter) as u64);
+                    let raster = Point2 {
+                        x: x as f32 + rng.next_f32(),
+                        y: y as f32 + rng.next_f32(),
+                    };
+                    let ray = camera.generate_ray(raster, res_f, 90.0);
+                    self.trace_camera_path(scene, lights, &light_sampler, ray, &lambda, &mut rng, &mut pixels[index]);
+                }
+            }
+
+            // === 2. Photon pass ===
+            if let Some(grid) = VisiblePointGrid::build(&pixels) {
+                for photon_index in 0..self.photons_per_iteration {
+                    let mut rng = RNG::new(photon_index as u64, (3 * iter + 1) as u64);
+                    self.trace_photon(scene, lights, &photon_light_sampler, &grid, &lambda, &mut rng, &mut pixels);
+                }
+            }
+
+            // === 3. Progressive radius / flux update ===
+            for pixel in pixels.iter_mut() {
+                if pixel.m > 0 {
+                    let m = pixel.m as f32;
+                    let n_new = pixel.n + GAMMA * m;
+                    let r_new = pixel.radius * (n_new / (pixel.n + m)).sqrt();
+                    let flux = match &pixel.vp {
+                        Some(vp) => to_rgb(vp.beta * pixel.phi, &lambda),
+                        None => Vector3::new(0.0, 0.0, 0.0),
+                    };
+                    let shrink = (r_new * r_new) / (pixel.radius * pixel.radius);
+                    pixel.tau = (pixel.tau + flux) * shrink;
+                    pixel.n = n_new;
+                    pixel.radius = r_new;
+                    pixel.m = 0;
+                    pixel.phi = SampledSpectrum::new(0.0);
+                }
+                pixel.vp = None;
+            }
+
+            print!(".");
+            use std::io::Write;
+            std::io::stdout().flush().unwrap();
+        }
+
+        // === Final radiance estimate ===
+        let n_iter = self.n_iterations.max(1) as f32;
+        let n_photons = n_iter * self.photons_per_iteration as f32;
+        for y in 0..res.y {
+            for x in 0..res.x {
+                let pixel = &pixels[(y * res.x + x) as usize];
+                let mut l = pixel.ld * (1.0 / n_iter);
+                if n_photons > 0.0 {
+                    let area = PI * pixel.radius * pixel.radius;
+                    l = l + pixel.tau * (1.0 / (n_photons * area));
+                }
+                film.set_pixel(Point2i { x, y }, l);
+            }
+        }
+
+        println!("\nDone!");
+    }
+
+    // Follows a camera ray through specular bounces, accumulating emission and
+    // direct lighting, and stores a visible point at the first non-specular hit.
+    #[allow(clippy::too_many_arguments)]
+    fn trace_camera_path(
+        &self,
+        scene: &dyn Primitive,
+        lights: &[Box<dyn Light>],
+        light_sampler: &dyn LightSampler,
+        mut ray: Ray,
+        lambda: &SampledWavelengths,
+        rng: &mut RNG,
+        pixel: &mut SPPMPixel,
+    ) {
+        let mut l = SampledSpectrum::new(0.0);
+        let mut beta = SampledSpectrum::new(1.0);
+        let mut specular_bounce = false;
+
+        for depth in 0..self.max_depth {
+            let Some((_, si, material_opt)) = scene.intersect(&ray) else {
+                if depth == 0 || specular_bounce {
+                    for light in lights.iter().filter(|l| l.is_infinite()) {
+                        l = l + beta * light.le(&ray, lambda);
+                    }
+                }
+                break;
+            };
+            let wo = -ray.d;
+
+            // Emission is only visible directly or through specular chains;
+            // everything else is covered by NEE and the photon map.
+            if let Some(mat) = &material_opt {
+                if depth == 0 || specular_bounce {
+                    // Emitters that became lights carry the light's spectrum and scale
+                    l = l + beta * match si.area_light {
+                        Some(light_index) => lights[light_index].l(&si, wo, lambda),
+                        None => mat.emitted(&si),
+                    };
+                }
+            }
+
+            let Some(mat) = material_opt else { break; };
+            let Some(bsdf) = mat.compute_scattering(&si, lambda) else { break; };
+
+            if !bsdf.is_specular() {
+                l = l + beta * sample_direct(scene, lights, light_sampler, &si, &bsdf, wo, lambda, rng);
+                pixel.vp = Some(VisiblePoint { p: si.core.p, wo, bsdf, beta });
+                break;
+            }
+
+            // Specular: keep following the path
+            let u = Point2 { x: rng.next_f32(), y: rng.next_f32() };
+            let Some((f, wi, pdf, is_delta)) = bsdf.sample_f(wo, u) else { break; };
+            if pdf == 0.0 || f.is_black() {
+                break;
+            }
+            let cos_theta = wi.dot(Vector3::from(si.shading.n)).abs();
+            beta = beta * f * (cos_theta / pdf);
+            specular_bounce = is_delta;
+            ray = si.core.spawn_ray(wi);
+        }
+
+        pixel.ld = pixel.ld + to_rgb(l, lambda);
+    }
+
+    // Shoots one photon and deposits its flux at visible points along its path.
+    #[allow(clippy::too_many_arguments)]
+    fn trace_photon(
+        &self,
+        scene: &dyn Primitive,
+        lights: &[Box<dyn Light>],
+        light_sampler: &PowerLightSampler,
+        grid: &VisiblePointGrid,
+        lambda: &SampledWavelengths,
+        rng: &mut RNG,
+        pixels: &mut [SPPMPixel],
+    ) {
+        // 1. Choose a light and sample an emitted ray
+        let Some(sampled) = light_sampler.sample_light(rng.next_f32()) else { return; };
+        let pdf_light = sampled.p;
+
+        let u1 = Point2 { x: rng.next_f32(), y: rng.next_f32() };
+        let u2 = Point2 { x: rng.next_f32(), y: rng.next_f32() };
+        let Some(les) = lights[sampled.light].sample_le(u1, u2, lambda) else { return; };
+        if les.pdf_pos == 0.0 || les.pdf_dir == 0.0 || les.l.is_black() {
+            return;
+        }
+
+        let cos_light = les.n.map_or(1.0, |n| Vector3::from(n).dot(les.ray.d).abs());
+        let mut beta = les.l * (cos_light / (pdf_light * les.pdf_pos * les.pdf_dir));
+        let mut ray = les.ray;
+
+        // 2. Follow the photon through the scene
+        for depth in 0..self.max_depth {
+            let Some((_, si, material_opt)) = scene.intersect(&ray) else { break; };
+
+            // Direct lighting is handled by NEE in the camera pass
+            if depth > 0 {
+                let wi = -ray.d;
+                for &index in grid.lookup(si.core.p) {
+                    let pixel = &mut pixels[index];
+                    let Some(vp) = &pixel.vp else { continue; };
+                    if (vp.p - si.core.p).length_squared() > pixel.radius * pixel.radius {
+                        continue;
+                    }
+                    let phi = beta * vp.bsdf.f(vp.wo, wi);
+                    pixel.phi = pixel.phi + phi;
+                    pixel.m += 1;
+                }
+            }
+
+            let Some(mat) = material_opt else { break; };
+            let Some(bsdf) = mat.compute_scattering(&si, lambda) else { break; };
+
+            let wo = -ray.d;
+            let u = Point2 { x: rng.next_f32(), y: rng.next_f32() };
+            let Some((f, wi, pdf, _)) = bsdf.sample_f_mode(wo, u, TransportMode::Importance) else { break; };
+            if pdf == 0.0 || f.is_black() {
+                break;
+            }
+            let cos_theta = wi.dot(Vector3::from(si.shading.n)).abs();
+            let beta_new = beta * f * (cos_theta / pdf);
+
+            // Russian roulette on the throughput change
+            let q = (1.0 - beta_new.max_value() / beta.max_value()).max(0.0);
+            if rng.next_f32() < q {
+                break;
+            }
+            beta = beta_new * (1.0 / (1.0 - q));
+            ray = si.core.spawn_ray(wi);
+        }
+    }
+}
+
+// One-sample direct lighting estimate (light sampling only, no MIS).
+#[allow(clippy::too_many_arguments)]
+fn sample_direct(
+    scene: &dyn Primitive,
+    lights: &[Box<dyn Light>],
+    light_sampler: &dyn LightSampler,
+    si: &SurfaceInteraction,
+    bsdf: &BSDF,
+    wo: Vector3,
+    lambda: &SampledWavelengths,
+    rng: &mut RNG,
+) -> SampledSpectrum {
+    let Some(sampled) = light_sampler.sample(&si.core, rng.next_f32()) else {
+        return SampledSpectrum::new(0.0);
+    };
+    let light = &lights[sampled.light];
+    let pdf_light_choice = sampled.p;
+
+    let u = Point2 { x: rng.next_f32(), y: rng.next_f32() };
+    let Some(ls) = light.sample_li(&si.core, u, lambda) else {
+        return SampledSpectrum::new(0.0);
+    };
+
+    // Delta lights report no meaningful pdf; treat it as 1
+    let pdf = if light.is_delta() { 1.0 } else { ls.pdf };
+    if pdf <= 0.0 || ls.l.is_black() {
+        return SampledSpectrum::new(0.0);
+    }
+
+    let f = bsdf.f(wo, ls.wi);
+    let cos_theta = Vector3::from(si.shading.n).dot(ls.wi).abs();
+    if cos_theta == 0.0 || f.is_black() || !unoccluded(scene, &si.core, ls.wi, ls.p_light) {
+        return SampledSpectrum::new(0.0);
+    }
+
+    f * ls.l * (cos_theta / (pdf * pdf_light_choice))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bsdf::{BxDF, DiffuseBxDF};
+
+    fn pixel(p: Point3, radius: f32) -> SPPMPixel {
+        let bsdf = BSDF::new(Vector3::new(0.0, 0.0, 1.0), BxDF::Diffuse(DiffuseBxDF::new(SampledSpectrum::new(0.5))));
+        let vp = VisiblePoint { p, wo: Vector3::new(0.0, 0.0, 1.0), bsdf, beta: SampledSpectrum::new(1.0) };
+        let zero = Vector3::new(0.0, 0.0, 0.0);
+        SPPMPixel { radius, ld: zero, vp: Some(vp), phi: SampledSpectrum::new(0.0), m: 0, n: 0.0, tau: zero }
+    }
+
+    // Each sphere overlaps up to 27 cells, which the grid hashes into 3 buckets
+    #[test]
+    fn visible_points_appear_once_per_bucket() {
+        let pixels = vec![
+            pixel(Point3::new(0.0, 0.0, 0.0), 1.0),
+            pixel(Point3::new(10.0, 10.0, 10.0), 1.0),
+            pixel(Point3::new(5.0, 2.0, 8.0), 1.0),
+        ];
+        let grid = VisiblePointGrid::build(&pixels).unwrap();
+        for bucket in &grid.buckets {
+            for index in 0..pixels.len() {
+                assert!(bucket.iter().filter(|&&i| i == index).count() <= 1, "pixel {} repeated in {:?}", index, bucket);
+            }
+        }
+        assert!(grid.lookup(Point3::new(0.1, 0.0, 0.0)).contains(&0));
+    }
+
+    #[test]
+    fn converges_to_the_path_traced_image() {
+        use crate::integrators::tests::{film_mean, wall_reference, wall_scene, RESOLUTION};
+        let (scene, lights, camera) = wall_scene();
+        let mut film = Film::new(RESOLUTION);
+        SPPMIntegrator::new(32, 20_000, 5, 0.2).render(&scene, &lights, &camera, &mut film);
+        let (mean, reference) = (film_mean(&film), wall_reference());
+        assert!(reference > 0.0);
+        assert!((mean - reference).abs() < 0.05 * reference, "SPPM {} vs path {}", mean, reference);
+    }
+}
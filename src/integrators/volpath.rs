@@ -0,0 +1,527 @@
+use std::sync::Arc;
+
+use crate::core::bsdf::BSDF;
+use crate::core::camera::PerspectiveCamera;
+use crate::core::film::Film;
+use crate::core::geometry::{Normal3, Point2, Point3, Vector3};
+use crate::core::integrator::{render_pixels_with_groups, to_rgb, LightGroupRadiance};
+use crate::core::interaction::Interaction;
+use crate::core::light::{light_linked, Light, LightLinks};
+use crate::core::lightsampler::{BVHLightSampler, LightSampler};
+use crate::core::medium::{HGPhaseFunction, Medium};
+use crate::core::primitive::Primitive;
+use crate::core::ray::Ray;
+use crate::core::sampler::Sampler;
+use crate::core::spectrum::{SampledSpectrum, SampledWavelengths};
+
+/// Volumetric path tracer.
+///
+/// Free-flight distances are sampled with delta tracking against the medium's
+/// majorants (null collisions keep the estimator unbiased for heterogeneous
+/// media), shadow rays estimate transmittance with residual ratio tracking,
+/// and NEE is performed from both surface and medium scattering vertices.
+/// Surfaces with a medium interface but no material are invisible boundaries.
+/// Subsurface materials continue refracted paths from a BSSRDF exit point.
+/// Surface light links restrict NEE and emitter hits after specular bounces;
+/// medium vertices see every light.
+pub struct VolPathIntegrator {
+    pub max_depth: usize,
+    pub camera_medium: Option<Arc<dyn Medium>>, // Medium the camera sits in
+}
+
+// Outcome of tracking a ray segment through a medium
+enum MediumEvent {
+    Absorbed,
+    Scattered(Point3, HGPhaseFunction),
+    PassedThrough, // Reached the next surface (or escaped)
+}
+
+// What scatters light at a vertex, for NEE
+enum Scatter<'a> {
+    Surface(&'a BSDF, Normal3),
+    Medium(HGPhaseFunction),
+}
+
+impl VolPathIntegrator {
+    pub fn new(max_depth: usize, camera_medium: Option<Arc<dyn Medium>>) -> Self {
+        VolPathIntegrator { max_depth, camera_medium }
+    }
+
+    pub fn render(
+        &self,
+        scene: &dyn Primitive,
+        lights: &[Box<dyn Light>],
+        camera: &PerspectiveCamera,
+        film: &mut Film,
+    ) {
+        let light_sampler = BVHLightSampler::new(lights);
+        let group_map = film.light_group_map(lights.len());
+        let n_groups = film.light_groups().len();
+        render_pixels_with_groups(camera, film, "Volumetric Path Tracing", |ray, wavelengths, sampler, group_rgb| {
+            let mut groups = LightGroupRadiance::new(&group_map, n_groups);
+            let l = self.li_with_groups(ray, wavelengths, scene, lights, &light_sampler, sampler, &mut groups);
+            for (rgb, &lg) in group_rgb.iter_mut().zip(&groups.l) {
+                *rgb = to_rgb(lg, wavelengths);
+            }
+            to_rgb(l, wavelengths)
+        });
+    }
+
+    /// Radiance arriving along a camera ray; also records each light's
+    /// contribution in `groups`
+    #[allow(clippy::too_many_arguments)]
+    pub fn li_with_groups(
+        &self,
+        mut ray: Ray,
+        lambda: &SampledWavelengths,
+        scene: &dyn Primitive,
+        lights: &[Box<dyn Light>],
+        light_sampler: &dyn LightSampler,
+        sampler: &mut dyn Sampler,
+        groups: &mut LightGroupRadiance,
+    ) -> SampledSpectrum {
+        let mut l = SampledSpectrum::new(0.0);
+        let mut beta = SampledSpectrum::new(1.0);
+        let mut specular_bounce = false;
+        // Light links of the last surface scattered from (None after medium scattering)
+        let mut prev_links: Option<Arc<LightLinks>> = None;
+        let mut depth = 0;
+        let mut medium = self.camera_medium.clone();
+
+        loop {
+            let hit = scene.intersect(&ray);
+            let t_hit = hit.as_ref().map_or(f32::INFINITY, |h| h.0);
+
+            // === 1. Medium interaction along [0, t_hit) ===
+            if let Some(m) = medium.clone() {
+                let emit = depth < self.max_depth;
+                match delta_track(m.as_ref(), &ray, t_hit, lambda, sampler, &mut beta, &mut l, emit) {
+                    MediumEvent::Absorbed => break,
+                    MediumEvent::Scattered(p, phase) => {
+                        if depth >= self.max_depth {
+                            break;
+                        }
+                        depth += 1;
+
+                        let wo = -ray.d;
+                        let it = medium_interaction(p, wo, ray.time);
+                        if let Some((light, ld)) = sample_ld(
+                            &it, Scatter::Medium(phase), medium.clone(),
+                            lambda, scene, lights, light_sampler, sampler,
+                        ) {
+                            l = l + beta * ld;
+                            groups.add(light, beta * ld);
+                        }
+
+                        let Some((p_val, wi, pdf)) = phase.sample_p(wo, sampler.get_2d()) else {
+                            break;
+                        };
+                        beta = beta * (p_val / pdf);
+                        specular_bounce = false;
+                        prev_links = None;
+                        ray = Ray::new(p, wi, ray.time);
+                        continue;
+                    }
+                    MediumEvent::PassedThrough => {}
+                }
+                if beta.is_black() {
+                    break;
+                }
+            }
+
+            // === 2. Surface interaction ===
+            // Escaped scene: environment emission, for the same paths as surface emission
+            let Some((_, si, material_opt)) = hit else {
+                if depth == 0 || specular_bounce {
+                    for (light_index, light) in lights.iter().enumerate().filter(|(_, l)| l.is_infinite()) {
+                        if depth == 0 || light_linked(prev_links.as_deref(), light_index) {
+                            let c = beta * light.le(&ray, lambda);
+                            l = l + c;
+                            groups.add(light_index, c);
+                        }
+                    }
+                }
+                break;
+            };
+
+            // Emission only for camera rays or specular paths (NEE covers the rest)
+            if let Some(mat) = &material_opt {
+                if depth == 0 || specular_bounce {
+                    match si.area_light {
+                        Some(light_index) => {
+                            if depth == 0 || light_linked(prev_links.as_deref(), light_index) {
+                                let c = beta * lights[light_index].l(&si, -ray.d, lambda);
+                                l = l + c;
+                                groups.add(light_index, c);
+                            }
+                        }
+                        None => l = l + beta * mat.emitted(&si),
+                    }
+                }
+            }
+
+            let Some(mat) = material_opt else {
+                // Invisible medium boundary: continue in the medium on the far side
+                if let Some(mi) = &si.medium_interface {
+                    medium = mi.medium_for(ray.d, si.core.n);
+                    ray = si.core.spawn_ray(ray.d);
+                    continue;
+                }
+                break;
+            };
+
+            let Some(bsdf) = mat.compute_scattering(&si, lambda) else { break; };
+            if depth >= self.max_depth {
+                break;
+            }
+            depth += 1;
+
+            // Next Event Estimation (the shadow ray starts in the medium on the light's side)
+            let wo = -ray.d;
+            let ns = si.shading.n;
+            if !lights.is_empty() {
+                let nee_medium = |wi: Vector3| match &si.medium_interface {
+                    Some(mi) => mi.medium_for(wi, si.core.n),
+                    None => medium.clone(),
+                };
+                if let Some((light, ld)) = sample_ld_with(
+                    &si.core, Scatter::Surface(&bsdf, ns), si.light_links.as_deref(), nee_medium,
+                    lambda, scene, lights, light_sampler, sampler,
+                ) {
+                    l = l + beta * ld;
+                    groups.add(light, beta * ld);
+                }
+            }
+
+            // BSDF sampling
+            let Some((f, wi, pdf, is_delta)) = bsdf.sample_f(wo, sampler.get_2d()) else {
+                break;
+            };
+            if pdf == 0.0 || f.is_black() {
+                break;
+            }
+            let cos_theta = wi.dot(Vector3::from(ns)).abs();
+            beta = beta * f * (cos_theta / pdf);
+            specular_bounce = is_delta;
+            prev_links = si.light_links.clone();
+
+            // Subsurface scattering: transmitted light exits elsewhere on the
+            // material and scatters again there through the exit's Sw lobe
+            let (mut wi, mut si) = (wi, si);
+            if wo.dot(Vector3::from(ns)) * wi.dot(Vector3::from(ns)) < 0.0 {
+                if let Some(bssrdf) = mat.bssrdf(&si, lambda) {
+                    let u = sampler.get_2d();
+                    let Some(segment) = bssrdf.sample(u.x, sampler.get_2d()) else { break; };
+                    let Some((exit, weight, sw)) = bssrdf.sample_exit(scene, &mat, &segment, u.y) else { break; };
+                    beta = beta * weight;
+                    if !lights.is_empty() {
+                        let nee_medium = |wi: Vector3| match &exit.medium_interface {
+                            Some(mi) => mi.medium_for(wi, exit.core.n),
+                            None => medium.clone(),
+                        };
+                        if let Some((light, ld)) = sample_ld_with(
+                            &exit.core, Scatter::Surface(&sw, exit.shading.n), exit.light_links.as_deref(), nee_medium,
+                            lambda, scene, lights, light_sampler, sampler,
+                        ) {
+                            l = l + beta * ld;
+                            groups.add(light, beta * ld);
+                        }
+                    }
+
+                    let Some((f, wi_exit, pdf, _)) = sw.sample_f(exit.core.wo, sampler.get_2d()) else { break; };
+                    if pdf == 0.0 || f.is_black() {
+                        break;
+                    }
+                    beta = beta * f * (wi_exit.dot(Vector3::from(exit.shading.n)).abs() / pdf);
+                    specular_bounce = false;
+                    prev_links = exit.light_links.clone();
+                    (wi, si) = (wi_exit, exit);
+                }
+            }
+
+            if let Some(mi) = &si.medium_interface {
+                medium = mi.medium_for(wi, si.core.n);
+            }
+            ray = si.core.spawn_ray(wi);
+
+            // Russian roulette
+            if depth > 3 {
+                let q = (1.0 - beta.max_value()).clamp(0.05, 0.95);
+                if sampler.get_1d() < q {
+                    break;
+                }
+                beta = beta * (1.0 / (1.0 - q));
+            }
+        }
+
+        l
+    }
+}
+
+fn medium_interaction(p: Point3, wo: Vector3, time: f32) -> Interaction {
+    Interaction {
+        p,
+        time,
+        p_error: Vector3::new(0.0, 0.0, 0.0),
+        wo,
+        n: Normal3 { x: 0.0, y: 0.0, z: 0.0 },
+        uv: Point2 { x: 0.0, y: 0.0 },
+    }
+}
+
+// Delta tracking over the medium's majorant segments.
+//
+// Tentative collisions are sampled at rate mu = max(sigma_maj). At each one we
+// choose absorption / real scattering / null scattering with probabilities
+// proportional to the (wavelength-averaged) coefficients and weight `beta` by
+// the spectral ratio, which keeps chromatic media unbiased.
+#[allow(clippy::too_many_arguments)]
+fn delta_track(
+    medium: &dyn Medium,
+    ray: &Ray,
+    t_max: f32,
+    lambda: &SampledWavelengths,
+    sampler: &mut dyn Sampler,
+    beta: &mut SampledSpectrum,
+    l: &mut SampledSpectrum,
+    emit: bool,
+) -> MediumEvent {
+    for seg in medium.majorants(ray, t_max) {
+        let mu = seg.sigma_maj.max_value();
+        if mu <= 0.0 {
+            continue;
+        }
+
+        let mut t = seg.t_min;
+        loop {
+            t -= (1.0 - sampler.get_1d()).ln() / mu;
+            if t >= seg.t_max {
+                break;
+            }
+
+            let p = ray.at(t);
+            let mp = medium.sample_point(p, lambda);
+
+            // Volumetric emission: expected contribution of this collision
+            if emit && !mp.le.is_black() {
+                *l = *l + *beta * mp.sigma_a * mp.le * (1.0 / mu);
+            }
+
+            let sigma_n = SampledSpectrum::new(mu) - mp.sigma_a - mp.sigma_s;
+            let p_a = mp.sigma_a.average() / mu;
+            let p_s = mp.sigma_s.average() / mu;
+            let p_n = sigma_n.average().max(0.0) / mu;
+            let p_sum = p_a + p_s + p_n;
+            if p_sum <= 0.0 {
+                continue;
+            }
+
+            let u = sampler.get_1d() * p_sum;
+            if u < p_a {
+                return MediumEvent::Absorbed;
+            } else if u < p_a + p_s {
+                *beta = *beta * mp.sigma_s * (p_sum / (mu * p_s));
+                return MediumEvent::Scattered(p, mp.phase);
+            } else {
+                *beta = *beta * sigma_n * (p_sum / (mu * p_n));
+                if beta.is_black() {
+                    return MediumEvent::Absorbed;
+                }
+            }
+        }
+    }
+    MediumEvent::PassedThrough
+}
+
+// Residual ratio tracking: the part of sigma_t below each segment's minorant
+// is integrated analytically, only the residual is tracked stochastically.
+fn segment_transmittance(
+    medium: &dyn Medium,
+    ray: &Ray,
+    t_max: f32,
+    lambda: &SampledWavelengths,
+    sampler: &mut dyn Sampler,
+) -> SampledSpectrum {
+    let mut tr = SampledSpectrum::new(1.0);
+    for seg in medium.majorants(ray, t_max) {
+        let len = seg.t_max - seg.t_min;
+        tr = tr * (seg.sigma_min * -len).exp();
+
+        let mu = (seg.sigma_maj - seg.sigma_min).max_value();
+        if mu <= 0.0 {
+            continue;
+        }
+
+        let mut t = seg.t_min;
+        loop {
+            t -= (1.0 - sampler.get_1d()).ln() / mu;
+            if t >= seg.t_max {
+                break;
+            }
+            let mp = medium.sample_point(ray.at(t), lambda);
+            let sigma_r = mp.sigma_a + mp.sigma_s - seg.sigma_min;
+            tr = tr * (SampledSpectrum::new(1.0) - sigma_r * (1.0 / mu));
+
+            // Russian roulette once the estimate gets small
+            let m = tr.max_value();
+            if m <= 0.0 {
+                return SampledSpectrum::new(0.0);
+            }
+            if m < 0.1 {
+                if sampler.get_1d() < 0.5 {
+                    return SampledSpectrum::new(0.0);
+                }
+                tr = tr * 2.0;
+            }
+        }
+    }
+    tr
+}
+
+// Transmittance from `from` to `p_light`, passing through invisible medium
+// boundaries. Any surface with a material blocks the ray.
+fn transmittance(
+    scene: &dyn Primitive,
+    from: &Interaction,
+    wi: Vector3,
+    p_light: Point3,
+    mut medium: Option<Arc<dyn Medium>>,
+    lambda: &SampledWavelengths,
+    sampler: &mut dyn Sampler,
+) -> SampledSpectrum {
+    let mut tr = SampledSpectrum::new(1.0);
+    let mut ray = from.spawn_ray(wi);
+
+    loop {
+        let t_light = (p_light - ray.o).length() - 1e-3;
+        let hit = scene.intersect(&ray).filter(|h| h.0 < t_light);
+        let t_end = hit.as_ref().map_or(t_light.max(0.0), |h| h.0);
+
+        if let Some(m) = &medium {
+            tr = tr * segment_transmittance(m.as_ref(), &ray, t_end, lambda, sampler);
+            if tr.is_black() {
+                return tr;
+            }
+        }
+
+        let Some((_, si, material_opt)) = hit else { return tr; };
+        if material_opt.is_some() {
+            return SampledSpectrum::new(0.0);
+        }
+        let Some(mi) = &si.medium_interface else {
+            return SampledSpectrum::new(0.0);
+        };
+        medium = mi.medium_for(ray.d, si.core.n);
+        ray = si.core.spawn_ray(ray.d);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sample_ld(
+    it: &Interaction,
+    scatter: Scatter,
+    medium: Option<Arc<dyn Medium>>,
+    lambda: &SampledWavelengths,
+    scene: &dyn Primitive,
+    lights: &[Box<dyn Light>],
+    light_sampler: &dyn LightSampler,
+    sampler: &mut dyn Sampler,
+) -> Option<(usize, SampledSpectrum)> {
+    sample_ld_with(it, scatter, None, |_| medium.clone(), lambda, scene, lights, light_sampler, sampler)
+}
+
+// One-sample NEE through media (light sampling only), returning the sampled
+// light's index with the estimate. `medium_for` gives the medium the shadow
+// ray starts in for a given direction; lights outside `links` contribute nothing.
+#[allow(clippy::too_many_arguments)]
+fn sample_ld_with<M>(
+    it: &Interaction,
+    scatter: Scatter,
+    links: Option<&LightLinks>,
+    medium_for: M,
+    lambda: &SampledWavelengths,
+    scene: &dyn Primitive,
+    lights: &[Box<dyn Light>],
+    light_sampler: &dyn LightSampler,
+    sampler: &mut dyn Sampler,
+) -> Option<(usize, SampledSpectrum)>
+where
+    M: Fn(Vector3) -> Option<Arc<dyn Medium>>,
+{
+    let sampled = light_sampler.sample(it, sampler.get_1d())?;
+    if !light_linked(links, sampled.light) {
+        return None;
+    }
+    let light = &lights[sampled.light];
+    let pdf_light_choice = sampled.p;
+
+    let u_light = sampler.get_2d();
+    let ls = light.sample_li(it, u_light, lambda)?;
+
+    // Delta lights report no meaningful pdf; treat it as 1
+    let pdf = if light.is_delta() { 1.0 } else { ls.pdf };
+    if pdf <= 0.0 || ls.l.is_black() {
+        return None;
+    }
+
+    let f = match scatter {
+        Scatter::Surface(bsdf, ns) => {
+            let cos_theta = Vector3::from(ns).dot(ls.wi).abs();
+            bsdf.f(it.wo, ls.wi) * cos_theta
+        }
+        Scatter::Medium(phase) => SampledSpectrum::new(phase.p(it.wo, ls.wi)),
+    };
+    if f.is_black() {
+        return None;
+    }
+
+    let tr = transmittance(scene, it, ls.wi, ls.p_light, medium_for(ls.wi), lambda, sampler);
+    Some((sampled.light, f * ls.l * tr * (1.0 / (pdf * pdf_light_choice))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::geometry::Point2i;
+    use crate::core::material::EmissiveMaterial;
+    use crate::core::medium::HomogeneousMedium;
+    use crate::core::sampler::StratifiedSampler;
+    use crate::core::scene::SceneBuilder;
+    use crate::core::texture::ConstantTexture;
+    use crate::integrators::tests::quad;
+
+    // Without scattering events (max depth 0), a ray through fog only sees an
+    // emitter attenuated by exp(-sigma_t * d)
+    #[test]
+    fn homogeneous_transmittance_matches_beer_lambert() {
+        let mut builder = SceneBuilder::new();
+        let emit = Arc::new(ConstantTexture::new(SampledSpectrum::new(4.0)));
+        builder.add_mesh(quad(Point3::new(0.0, 0.0, 2.0), 1.0, false), Some(Arc::new(EmissiveMaterial::new(emit))), 1.0);
+        let (scene, lights) = builder.build();
+        let light_sampler = BVHLightSampler::new(&lights);
+
+        let (sigma_a, sigma_s) = (0.3, 0.5);
+        let fog = Arc::new(HomogeneousMedium::new(SampledSpectrum::new(sigma_a), SampledSpectrum::new(sigma_s), 0.0));
+        let expected = 4.0 * (-(sigma_a + sigma_s) * 2.0f32).exp();
+
+        // Residual ratio tracking is exact for a homogeneous medium
+        let ray = Ray::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), 0.0);
+        let lambda = SampledWavelengths::sample_uniform(0.5);
+        let mut sampler = StratifiedSampler::new(32, 32);
+        let tr = segment_transmittance(fog.as_ref(), &ray, 2.0, &lambda, &mut sampler);
+        assert!((4.0 * tr.values[0] - expected).abs() < 1e-5, "{} vs {}", 4.0 * tr.values[0], expected);
+
+        // Delta tracking reaches the emitter with that probability
+        let integrator = VolPathIntegrator::new(0, Some(fog));
+        sampler.start_pixel(Point2i { x: 0, y: 0 });
+        let mut sum = 0.0;
+        let n = sampler.samples_per_pixel();
+        for s in 0..n {
+            sampler.start_sample(s);
+            let mut groups = LightGroupRadiance::new(&[], 0);
+            sum += integrator.li_with_groups(ray, &lambda, &scene, &lights, &light_sampler, &mut sampler, &mut groups).values[0];
+        }
+        let mean = sum / n as f32;
+        assert!((mean - expected).abs() < 0.03 * expected, "{} vs {}", mean, expected);
+    }
+}
@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use crate::core::camera::PerspectiveCamera;
+use crate::core::geometry::{Point3, Vector3};
+use crate::core::ies::IesProfile;
+use crate::core::light::{
+    AreaPower, DistantLight, GoniometricLight, ImageInfiniteLight, Light, LightLinks, PointLight, PointPower, ProjectionLight, SkyLight, SkyPower, SpotLight, SunLight,
+    UniformInfiniteLight,
+};
+use crate::core::material::{EmissiveMaterial, Material, MatteMaterial};
+use crate::core::scene::SceneBuilder;
+use crate::core::spectrum::{SampledSpectrum, Spectrum};
+use crate::core::texture::{CloudTexture, ConstantTexture};
+use crate::core::transform::Transform;
+use crate::shapes::cylinder::Cylinder;
+use crate::shapes::disk::Disk;
+use crate::shapes::sphere::Sphere;
+use crate::shapes::triangle::TriangleMesh;
+
+use super::{asset, camera, cuboid, rect, Demo};
+
+// Scenes with lights in physical units are dim interiors and the film has
+// no exposure control, so their photometric values are multiplied by this
+const EXPOSURE: f32 = 100.0;
+
+// White emitter of the given power on the front face
+fn glow(power: AreaPower) -> Arc<dyn Material> {
+    Arc::new(EmissiveMaterial::new(Arc::new(ConstantTexture::new(SampledSpectrum::new(1.0)))).with_power(power))
+}
+
+// A CIE standard illuminant known to `Spectrum::named`
+fn illuminant(name: &str) -> Spectrum {
+    Spectrum::named(name).expect("unknown standard illuminant")
+}
+
+// Grey matte material of reflectance `r`
+fn matte(r: f32) -> Arc<dyn Material> {
+    let constant = |v: f32| Arc::new(ConstantTexture::new(SampledSpectrum::new(v)));
+    Arc::new(MatteMaterial::new(constant(r), constant(0.0)))
+}
+
+fn floor(builder: &mut SceneBuilder) {
+    builder.add_mesh(rect(Point3::new(-4.0, 0.0, -4.0), Vector3::new(0.0, 0.0, 8.0), Vector3::new(8.0, 0.0, 0.0)), Some(matte(0.6)), 1.0);
+}
+
+fn back_wall(builder: &mut SceneBuilder) {
+    builder.add_mesh(rect(Point3::new(-4.0, 0.0, 3.0), Vector3::new(0.0, 4.0, 0.0), Vector3::new(8.0, 0.0, 0.0)), Some(matte(0.6)), 1.0);
+}
+
+fn tall_block(builder: &mut SceneBuilder) {
+    builder.add_mesh(cuboid(Point3::new(-1.6, 0.0, 0.4), Point3::new(-0.6, 1.4, 1.4)), Some(matte(0.4)), 1.0);
+}
+
+fn small_block(builder: &mut SceneBuilder) {
+    builder.add_mesh(cuboid(Point3::new(0.7, 0.0, 0.0), Point3::new(1.5, 0.8, 0.8)), Some(matte(0.4)), 1.0);
+}
+
+// Floor and two blocks shared by the light scenes
+fn blocks(builder: &mut SceneBuilder) {
+    floor(builder);
+    tall_block(builder);
+    small_block(builder);
+}
+
+// The blocks in front of a back wall
+fn room(builder: &mut SceneBuilder) {
+    blocks(builder);
+    back_wall(builder);
+}
+
+// Camera looking into the room
+fn room_camera() -> PerspectiveCamera {
+    camera(Point3::new(0.0, 1.6, -3.0), Point3::new(0.0, 0.8, 1.0))
+}
+
+/// The room lit by a two-sided daylight panel with cloudy emission
+/// averaging 300 nits; the builder turns each of its triangles into an area light
+pub fn panel() -> Demo {
+    let mut builder = SceneBuilder::new();
+    room(&mut builder);
+    let cloud = EmissiveMaterial::new(Arc::new(CloudTexture::new(3.0)))
+        .with_two_sided(true)
+        .with_spectrum(illuminant("stdillum-D65"))
+        .with_power(AreaPower::Nits(300.0 * EXPOSURE));
+    let cloud: Arc<dyn Material> = Arc::new(cloud);
+    builder.add_mesh(rect(Point3::new(-0.2, 1.2, -0.2), Vector3::new(0.4, 0.0, 0.0), Vector3::new(0.0, 0.6, 0.0)), Some(cloud), 1.0);
+    Demo::new(builder, room_camera())
+}
+
+/// The room lit by delta lights: an incandescent bulb radiating 2 W of
+/// visible light, a 400 cd white spot on the tall block and 2 lux of cool
+/// light from a distant sun
+pub fn lamps() -> Demo {
+    let mut builder = SceneBuilder::new();
+    room(&mut builder);
+    let bulb = PointLight::new(Point3::new(0.8, 2.2, -0.8), illuminant("stdillum-A"), 1.0);
+    builder.add_light(Box::new(bulb.with_power(PointPower::Watts(2.0 * EXPOSURE))));
+    let spot = SpotLight::new(Point3::new(-2.5, 3.0, -1.0), Point3::new(-1.1, 1.0, 0.9), Spectrum::Constant(1.0), 1.0, 20.0, 5.0);
+    builder.add_light(Box::new(spot.with_power(PointPower::Candela(400.0 * EXPOSURE))));
+    let sun = DistantLight::new(Point3::new(2.0, 3.0, -1.0), Point3::new(0.0, 0.0, 0.0), Spectrum::blackbody(8000.0), 1.0);
+    builder.add_light(Box::new(sun.with_illuminance(2.0 * EXPOSURE)));
+    Demo::new(builder, room_camera())
+}
+
+/// The blocks under an environment map (equirect, +y up) giving 10 lux,
+/// turned by an optional angle in degrees about the vertical; a uniform
+/// 3 nit grey sky without one
+pub fn environment(assets: &[String]) -> Result<Demo, String> {
+    const USAGE: &str = "environment [map.hdr [rotation]]";
+    let mut builder = SceneBuilder::new();
+    blocks(&mut builder);
+    let light: Box<dyn Light> = match assets.first() {
+        Some(filename) => {
+            let degrees: f32 = assets.get(1).map_or(Ok(0.0), |a| a.parse()).map_err(|_| format!("usage: {}", USAGE))?;
+            let rotation = Transform::rotate(degrees, Vector3::new(0.0, 1.0, 0.0));
+            let map = ImageInfiniteLight::new(filename, rotation, 1.0).map_err(|e| e.to_string())?;
+            Box::new(map.with_power(SkyPower::Lux(10.0 * EXPOSURE)))
+        }
+        None => Box::new(UniformInfiniteLight::new(Spectrum::Constant(1.0), 1.0).with_power(SkyPower::Nits(3.0 * EXPOSURE))),
+    };
+    builder.add_light(light);
+    Ok(Demo::new(builder, room_camera()))
+}
+
+/// The blocks under a daylight sky and sun, with the sun's elevation,
+/// azimuth (degrees) and the turbidity optionally given
+pub fn sky(assets: &[String]) -> Result<Demo, String> {
+    const USAGE: &str = "sky [elevation [azimuth [turbidity]]]";
+    let arg = |i: usize, default: f32| -> Result<f32, String> {
+        assets.get(i).map_or(Ok(default), |a| a.parse()).map_err(|_| format!("usage: {}", USAGE))
+    };
+    let (elevation, azimuth, turbidity) = (arg(0, 35.0)?, arg(1, 120.0)?, arg(2, 3.0)?);
+
+    // The model's radiance is in kcd/m^2; bring it down to the other scenes' exposure
+    const EXPOSURE: f32 = 0.0002;
+    let mut builder = SceneBuilder::new();
+    blocks(&mut builder);
+    builder.add_light(Box::new(SkyLight::new(elevation, azimuth, turbidity, 0.3, EXPOSURE)));
+    builder.add_light(Box::new(SunLight::new(elevation, azimuth, turbidity, EXPOSURE)));
+    Ok(Demo::new(builder, room_camera()))
+}
+
+/// The room lit by a fixture with an IES photometric profile, hung by the
+/// back wall so its beam shape shows on it. The fixture emits the given
+/// lumens, else the lamp's rated lumens for relative photometry, else the
+/// file's absolute candela values.
+pub fn ies(assets: &[String]) -> Result<Demo, String> {
+    const USAGE: &str = "ies <file.ies> [lumens]";
+    let profile = IesProfile::read(asset(assets, 0, USAGE)?).map_err(|e| e.to_string())?;
+    let lumens = match assets.get(1) {
+        Some(a) => Some(a.parse::<f32>().map_err(|_| format!("usage: {}", USAGE))?),
+        None => Some(profile.lumens).filter(|&lm| lm > 0.0),
+    };
+
+    let mut builder = SceneBuilder::new();
+    room(&mut builder);
+    let fixture = GoniometricLight::new(
+        Point3::new(0.0, 3.0, 1.8),
+        Point3::new(0.0, 0.0, 1.8),
+        profile,
+        illuminant("stdillum-A"),
+        EXPOSURE,
+    );
+    let fixture = match lumens {
+        Some(lm) => fixture.with_power(PointPower::Lumens(lm * EXPOSURE)),
+        None => fixture,
+    };
+    builder.add_light(Box::new(fixture));
+    Ok(Demo::new(builder, room_camera()))
+}
+
+/// The room lit by a 100 lm pico projector throwing an image onto the back
+/// wall from beside the camera
+pub fn projector(assets: &[String]) -> Result<Demo, String> {
+    let filename = asset(assets, 0, "projector <image>")?;
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    let light_to_render = Transform::look_at(Point3::new(1.0, 2.0, -2.5), Point3::new(0.0, 1.8, 3.0), up);
+    let projector = ProjectionLight::new(light_to_render, filename, 30.0, 1.0).map_err(|e| e.to_string())?;
+
+    let mut builder = SceneBuilder::new();
+    room(&mut builder);
+    builder.add_light(Box::new(projector.with_power(PointPower::Lumens(100.0 * EXPOSURE))));
+    Ok(Demo::new(builder, room_camera()))
+}
+
+/// The room lit by analytic shape emitters: a ball radiating 1.25 W, a
+/// 100 nit ceiling disk, a 500 lm tube in front of the back wall, and a
+/// 100 lm panel above the tall block that is sampled by solid angle
+pub fn shapes() -> Demo {
+    let mut builder = SceneBuilder::new();
+    room(&mut builder);
+    builder.add_shape(Arc::new(Sphere::new(Point3::new(0.2, 1.2, -0.3), 0.15)), Some(glow(AreaPower::Watts(1.25 * EXPOSURE))), 1.0);
+    builder.add_shape(Arc::new(Disk::new(Point3::new(0.0, 3.0, 0.5), Vector3::new(0.0, -1.0, 0.0), 0.3)), Some(glow(AreaPower::Nits(100.0 * EXPOSURE))), 1.0);
+    builder.add_shape(Arc::new(Cylinder::new(Point3::new(-2.0, 2.6, 2.0), Point3::new(2.0, 2.6, 2.0), 0.03)), Some(glow(AreaPower::Lumens(500.0 * EXPOSURE))), 1.0);
+
+    let (p, e1, e2) = (Point3::new(-1.3, 2.2, 0.7), Vector3::new(0.4, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.4));
+    let panel = TriangleMesh::new(vec![0, 1, 2, 0, 2, 3], vec![p, p + e1, p + e1 + e2, p + e2], None)
+        .with_solid_angle_sampling(true);
+    builder.add_mesh(Arc::new(panel), Some(glow(AreaPower::Lumens(100.0 * EXPOSURE))), 1.0);
+    Demo::new(builder, room_camera())
+}
+
+/// The room lit by a warm key light and a cool fill, with the tall block
+/// linked to the key only and the small block to everything but the key.
+/// Each light is also written as its own light group layer.
+pub fn linked() -> Demo {
+    let mut builder = SceneBuilder::new();
+    let key = builder.add_light(Box::new(PointLight::new(Point3::new(-2.0, 2.5, -1.0), Spectrum::blackbody(2700.0), 0.06)));
+    let fill = builder.add_light(Box::new(PointLight::new(Point3::new(2.5, 2.0, -1.5), Spectrum::blackbody(9000.0), 0.04)));
+    floor(&mut builder);
+    back_wall(&mut builder);
+    builder.set_light_links(LightLinks::Include(vec![key]));
+    tall_block(&mut builder);
+    builder.set_light_links(LightLinks::Exclude(vec![key]));
+    small_block(&mut builder);
+    builder.set_light_links(LightLinks::All);
+    Demo::new(builder, room_camera()).with_light_group("key", &[key]).with_light_group("fill", &[fill])
+}
@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use crate::core::camera::PerspectiveCamera;
+use crate::core::geometry::{Normal3, Point2, Point3, Vector3};
+use crate::core::imagemap::ImageTexture;
+use crate::core::material::{
+    CoatedConductorMaterial, CoatedDiffuseMaterial, Coating, ConductorMaterial, DielectricMaterial, EmissiveMaterial, HairAbsorption, HairMaterial, Material, MatteMaterial,
+    MeasuredMaterial, MixMaterial, PrincipledMaterial, SubsurfaceCoefficients, SubsurfaceMaterial,
+};
+use crate::core::math::RNG;
+use crate::core::metals::METAL_NAMES;
+use crate::core::scene::SceneBuilder;
+use crate::core::spectrum::SampledSpectrum;
+use crate::core::texture::{
+    ConstantTexture, MarbleTexture, NoiseTexture, PlanarMapping2D, SphericalMapping2D, Texture, UVMapping2D,
+};
+use crate::core::transform::Transform;
+use crate::shapes::curve::{CurveCommon, CurveType};
+use crate::shapes::sphere::Sphere;
+use crate::shapes::triangle::TriangleMesh;
+
+use super::{asset, camera, rect, Demo};
+
+fn constant(v: f32) -> Arc<dyn Texture> {
+    Arc::new(ConstantTexture::new(SampledSpectrum::new(v)))
+}
+
+// Floor, a marbled back wall and an overhead light shared by the material scenes
+fn stage(builder: &mut SceneBuilder) {
+    let floor: Arc<dyn Material> = Arc::new(MatteMaterial::new(constant(0.6), constant(0.0)));
+    builder.add_mesh(rect(Point3::new(-4.0, 0.0, -4.0), Vector3::new(0.0, 0.0, 8.0), Vector3::new(8.0, 0.0, 0.0)), Some(floor), 1.0);
+    let wall: Arc<dyn Material> = Arc::new(MatteMaterial::new(Arc::new(MarbleTexture::new(2.0)), constant(0.0)));
+    builder.add_mesh(rect(Point3::new(-4.0, 0.0, 2.0), Vector3::new(0.0, 4.0, 0.0), Vector3::new(8.0, 0.0, 0.0)), Some(wall), 1.0);
+
+    let light: Arc<dyn Material> = Arc::new(EmissiveMaterial::new(constant(0.15)));
+    builder.add_mesh(rect(Point3::new(-1.0, 3.0, -1.0), Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0)), Some(light), 1.0);
+}
+
+// Ball of the given radius resting on the floor at (x, z)
+fn ball(builder: &mut SceneBuilder, x: f32, z: f32, radius: f32, material: Arc<dyn Material>) {
+    builder.add_shape(Arc::new(Sphere::new(Point3::new(x, radius, z), radius)), Some(material), 1.0);
+}
+
+// Camera looking at the row of balls
+fn stage_camera() -> PerspectiveCamera {
+    camera(Point3::new(0.0, 0.9, -1.2), Point3::new(0.0, 0.5, 0.6))
+}
+
+/// Glass balls in front of the marbled wall: smooth glass, frosted glass
+/// and smooth water
+pub fn glass() -> Demo {
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+    ball(&mut builder, -1.0, 0.6, 0.4, Arc::new(DielectricMaterial::new(constant(1.5), constant(0.0))));
+    ball(&mut builder, 0.0, 0.6, 0.4, Arc::new(DielectricMaterial::new(constant(1.5), constant(0.3))));
+    ball(&mut builder, 1.0, 0.6, 0.4, Arc::new(DielectricMaterial::new(constant(1.33), constant(0.0))));
+    Demo::new(builder, stage_camera())
+}
+
+/// The measured metals in a row (gold, silver, copper, aluminium, chromium,
+/// titanium, iron), behind them a pale gold given by its edge tint and
+/// brushed aluminium with anisotropic roughness
+pub fn metals() -> Demo {
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+    for (i, name) in METAL_NAMES.iter().enumerate() {
+        let metal = ConductorMaterial::named(name, constant(0.1)).expect("metal from the library");
+        ball(&mut builder, -1.2 + 0.4 * i as f32, 0.2, 0.17, Arc::new(metal));
+    }
+    let tinted = ConductorMaterial::from_edge_tint(constant(0.8), constant(0.95), constant(0.15));
+    ball(&mut builder, -0.5, 1.1, 0.35, Arc::new(tinted));
+    let brushed = ConductorMaterial::named("Al", constant(0.05)).expect("metal from the library");
+    ball(&mut builder, 0.5, 1.1, 0.35, Arc::new(brushed.with_anisotropic_roughness(constant(0.05), constant(0.4))));
+    Demo::new(builder, stage_camera())
+}
+
+/// Principled balls showing one feature each. Front: tinted specular on a
+/// plastic, anisotropic metal, sheen on a rough cloth, clearcoat over a dark
+/// base. Back: transmission, subsurface, a thin-walled sheet and emission.
+pub fn principled() -> Demo {
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+    let principled = |base: f32, metallic: f32, roughness: f32| PrincipledMaterial::new(constant(base), constant(metallic), constant(roughness));
+    let front = [
+        principled(0.7, 0.0, 0.3).with_specular(constant(0.5), constant(1.0)),
+        principled(0.8, 1.0, 0.3).with_anisotropic(constant(0.8)),
+        principled(0.5, 0.0, 0.8).with_sheen(constant(1.0), constant(0.5)),
+        principled(0.2, 0.0, 0.6).with_clearcoat(constant(1.0), constant(0.9)),
+    ];
+    let back = [
+        principled(0.9, 0.0, 0.05).with_transmission(constant(1.0), constant(1.5)),
+        principled(0.8, 0.0, 0.4).with_subsurface(constant(1.0)),
+        principled(0.8, 0.0, 0.3).with_transmission(constant(0.5), constant(1.5)).with_thin_walled(constant(0.5)),
+        principled(0.5, 0.0, 0.5).with_emission(constant(0.02)),
+    ];
+    for (i, material) in front.into_iter().enumerate() {
+        ball(&mut builder, -1.05 + 0.7 * i as f32, 0.2, 0.25, Arc::new(material));
+    }
+    for (i, material) in back.into_iter().enumerate() {
+        ball(&mut builder, -1.05 + 0.7 * i as f32, 1.1, 0.3, Arc::new(material));
+    }
+    // From higher up so the front row doesn't hide the back one
+    Demo::new(builder, camera(Point3::new(0.0, 1.4, -0.5), Point3::new(0.0, 0.3, 0.7)))
+}
+
+/// Coated balls: a grey diffuse base under a smooth clear coat, the
+/// same under a rough high-index coat, a white base under a thick absorbing
+/// coat walked deeper, and copper under a clear coat
+pub fn coated() -> Demo {
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+    let clear = CoatedDiffuseMaterial::new(constant(0.5), Coating::new(constant(0.0)));
+    let rough = CoatedDiffuseMaterial::new(constant(0.5), Coating::new(constant(0.3)).with_eta(constant(2.0)));
+    let absorbing = Coating::new(constant(0.05)).with_medium(constant(0.5), constant(0.0), 0.0).with_walk(32, 4);
+    let varnished = CoatedDiffuseMaterial::new(constant(0.9), absorbing);
+    let copper = ConductorMaterial::named("Cu", constant(0.2)).expect("metal from the library");
+    let lacquered = CoatedConductorMaterial::new(copper, Coating::new(constant(0.0)));
+    let materials: [Arc<dyn Material>; 4] = [Arc::new(clear), Arc::new(rough), Arc::new(varnished), Arc::new(lacquered)];
+    for (i, material) in materials.into_iter().enumerate() {
+        ball(&mut builder, -1.2 + 0.8 * i as f32, 0.6, 0.35, material);
+    }
+    Demo::new(builder, stage_camera())
+}
+
+// Tuft of `n` hair strands rooted around `center`, leaning outwards as they rise
+fn tuft(builder: &mut SceneBuilder, rng: &mut RNG, center: Point3, n: usize, material: Arc<dyn Material>) {
+    for _ in 0..n {
+        let offset = Vector3::new(rng.next_f32() - 0.5, 0.0, rng.next_f32() - 0.5) * 0.3;
+        let root = center + offset;
+        let lean = offset * 1.5;
+        let cp = [root, root + Vector3::new(0.0, 0.4, 0.0), root + lean + Vector3::new(0.0, 0.8, 0.0), root + lean * 2.0 + Vector3::new(0.0, 1.0, 0.0)];
+        builder.add_curve(Arc::new(CurveCommon::new(CurveType::Cylinder, cp, 0.02, 0.006)), 2, Some(material.clone()), 1.0);
+    }
+}
+
+/// Three tufts of hair: brown from its melanin concentration, light hair
+/// from the color it should look and one from its absorption coefficient.
+/// Ribbon grass blades turning towards the light and flat straws grow
+/// in front.
+pub fn hair() -> Demo {
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+    let mut rng = RNG::new(7, 0);
+    let brown = HairMaterial::melanin(constant(1.3), constant(0.2)).with_roughness(constant(0.25), constant(0.3));
+    let light = HairMaterial::new(HairAbsorption::Reflectance(constant(0.7))).with_scale_angle(3.0);
+    let absorbing = HairMaterial::new(HairAbsorption::SigmaA(constant(0.5))).with_eta(1.6);
+    for (x, material) in [(-1.0, brown), (0.0, light), (1.0, absorbing)] {
+        tuft(&mut builder, &mut rng, Point3::new(x, 0.0, 0.8), 20, Arc::new(material));
+    }
+
+    let grass: Arc<dyn Material> = Arc::new(MatteMaterial::new(constant(0.4), constant(0.0)));
+    for i in 0..16 {
+        let root = Point3::new(-1.6 + 0.2 * i as f32, 0.0, 0.1 + 0.1 * rng.next_f32());
+        let bend = Vector3::new(0.1 * (rng.next_f32() - 0.5), 0.0, -0.1);
+        let cp = [root, root + Vector3::new(0.0, 0.1, 0.0), root + bend + Vector3::new(0.0, 0.2, 0.0), root + bend * 2.5 + Vector3::new(0.0, 0.25, 0.0)];
+        let blade = CurveCommon::new(CurveType::Ribbon, cp, 0.06, 0.0).with_normals(Normal3 { x: 0.0, y: 0.0, z: -1.0 }, Normal3 { x: 0.0, y: 0.7, z: -0.7 });
+        builder.add_curve(Arc::new(blade), 1, Some(grass.clone()), 1.0);
+    }
+    let straw: Arc<dyn Material> = Arc::new(MatteMaterial::new(constant(0.8), constant(0.0)));
+    for i in 0..10 {
+        let root = Point3::new(-1.35 + 0.3 * i as f32, 0.0, 0.4);
+        let tip = root + Vector3::new(0.2 * (rng.next_f32() - 0.5), 0.3 + 0.2 * rng.next_f32(), 0.0);
+        let cp = [root, root + (tip - root) * (1.0 / 3.0), root + (tip - root) * (2.0 / 3.0), tip];
+        builder.add_curve(Arc::new(CurveCommon::new(CurveType::Flat, cp, 0.04, 0.01)), 1, Some(straw.clone()), 1.0);
+    }
+    Demo::new(builder, camera(Point3::new(0.0, 0.8, -0.9), Point3::new(0.0, 0.5, 0.8)))
+}
+
+/// A ball of a measured material from a MERL .binary or RGL .bsdf file
+pub fn measured(assets: &[String]) -> Result<Demo, String> {
+    let filename = asset(assets, 0, "measured <file.binary | file.bsdf>")?;
+    let material = MeasuredMaterial::read(filename).map_err(|e| e.to_string())?;
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+    ball(&mut builder, 0.0, 0.6, 0.5, Arc::new(material));
+    Ok(Demo::new(builder, stage_camera()))
+}
+
+/// Subsurface balls: a pale one from its diffuse color and mean free path,
+/// a waxy one from absorption and scattering coefficients per centimeter,
+/// and a frosted forward-scattering one
+pub fn subsurface() -> Demo {
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+    let pale = SubsurfaceMaterial::from_reflectance(constant(0.8), constant(0.05));
+    let coefficients = SubsurfaceCoefficients::Coefficients { sigma_a: constant(0.05), sigma_s: constant(2.0) };
+    let wax = SubsurfaceMaterial::new(coefficients).with_scale(100.0);
+    let frosted = SubsurfaceMaterial::from_reflectance(constant(0.6), constant(0.1)).with_roughness(constant(0.3)).with_medium(1.5, 0.5);
+    let materials = [pale, wax, frosted];
+    for (i, material) in materials.into_iter().enumerate() {
+        ball(&mut builder, -1.0 + i as f32, 0.6, 0.4, Arc::new(material));
+    }
+    Demo::new(builder, stage_camera())
+}
+
+/// Mixed balls: noisy rust over copper picked per shading point, the same
+/// shaded with both lobes, and dark dirt over a white plastic in veins
+pub fn mix() -> Demo {
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+    let rusty = || {
+        let copper: Arc<dyn Material> = Arc::new(ConductorMaterial::named("Cu", constant(0.15)).expect("metal from the library"));
+        let rust: Arc<dyn Material> = Arc::new(MatteMaterial::new(constant(0.25), constant(20.0)));
+        MixMaterial::new(copper, rust, Arc::new(NoiseTexture::new(6.0)))
+    };
+    let plastic: Arc<dyn Material> = Arc::new(PrincipledMaterial::new(constant(0.9), constant(0.0), constant(0.2)));
+    let dirt: Arc<dyn Material> = Arc::new(MatteMaterial::new(constant(0.1), constant(0.0)));
+    let dirty = MixMaterial::new(plastic, dirt, Arc::new(MarbleTexture::new(3.0)));
+    let materials = [rusty(), rusty().with_lobe_sum(), dirty];
+    for (i, material) in materials.into_iter().enumerate() {
+        ball(&mut builder, -1.0 + i as f32, 0.6, 0.4, Arc::new(material));
+    }
+    Demo::new(builder, stage_camera())
+}
+
+/// An image on the floor by its uv coordinates, tiled on the back wall by
+/// a planar projection and wrapped around a ball by a spherical one
+pub fn textured(assets: &[String]) -> Result<Demo, String> {
+    let filename = asset(assets, 0, "textured <image>")?;
+    let image = |mapping| -> Result<Arc<dyn Material>, String> {
+        let texture = ImageTexture::new(mapping, filename).map_err(|e| e.to_string())?;
+        Ok(Arc::new(MatteMaterial::new(Arc::new(texture), constant(0.0))))
+    };
+
+    let mut builder = SceneBuilder::new();
+    let (p, e1, e2) = (Point3::new(-1.5, 0.0, -0.5), Vector3::new(3.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 2.5));
+    let uv = vec![Point2 { x: 0.0, y: 1.0 }, Point2 { x: 1.0, y: 1.0 }, Point2 { x: 1.0, y: 0.0 }, Point2 { x: 0.0, y: 0.0 }];
+    let floor = TriangleMesh::new(vec![0, 2, 1, 0, 3, 2], vec![p, p + e1, p + e1 + e2, p + e2], Some(uv));
+    builder.add_mesh(Arc::new(floor), Some(image(Box::<UVMapping2D>::default())?), 1.0);
+
+    // A one-meter image above the ball; lookups clamp to the edge texels around it
+    let planar = PlanarMapping2D { vs: Vector3::new(1.0, 0.0, 0.0), vt: Vector3::new(0.0, -1.0, 0.0), ds: 0.5, dt: 2.0 };
+    builder.add_mesh(rect(Point3::new(-4.0, 0.0, 2.0), Vector3::new(0.0, 4.0, 0.0), Vector3::new(8.0, 0.0, 0.0)), Some(image(Box::new(planar))?), 1.0);
+
+    let center = Point3::new(0.0, 0.5, 0.8);
+    let spherical = SphericalMapping2D::new(Transform::translate(Vector3::new(-center.x, -center.y, -center.z)));
+    builder.add_shape(Arc::new(Sphere::new(center, 0.5)), Some(image(Box::new(spherical))?), 1.0);
+
+    let light: Arc<dyn Material> = Arc::new(EmissiveMaterial::new(constant(0.15)));
+    builder.add_mesh(rect(Point3::new(-1.0, 3.0, -1.0), Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0)), Some(light), 1.0);
+    Ok(Demo::new(builder, stage_camera()))
+}
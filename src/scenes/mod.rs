@@ -0,0 +1,156 @@
+pub mod lights;
+pub mod materials;
+pub mod volumes;
+
+use std::sync::Arc;
+
+use crate::core::camera::PerspectiveCamera;
+use crate::core::geometry::{Point2, Point2i, Point3, Vector3};
+use crate::core::material::{EmissiveMaterial, PrincipledMaterial};
+use crate::core::scene::SceneBuilder;
+use crate::core::spectrum::SampledSpectrum;
+use crate::core::texture::{ConstantTexture, MarbleTexture};
+use crate::core::transform::Transform;
+use crate::shapes::triangle::TriangleMesh;
+
+/// Image size of every demo scene
+pub const RESOLUTION: Point2i = Point2i { x: 400, y: 300 };
+
+/// A demo scene: what to build, the camera looking at it and the light
+/// groups to write as separate layers
+pub struct Demo {
+    pub builder: SceneBuilder,
+    pub camera: PerspectiveCamera,
+    pub light_groups: Vec<(String, Vec<usize>)>,
+}
+
+impl Demo {
+    pub fn new(builder: SceneBuilder, camera: PerspectiveCamera) -> Self {
+        Demo { builder, camera, light_groups: Vec::new() }
+    }
+
+    /// Adds a light group named `name` holding the lights at these scene indices
+    pub fn with_light_group(mut self, name: &str, lights: &[usize]) -> Self {
+        self.light_groups.push((name.to_string(), lights.to_vec()));
+        self
+    }
+}
+
+/// Scene names accepted by `build_by_name`
+pub const SCENE_NAMES: &[&str] = &["marble", "panel", "lamps", "environment", "sky", "ies", "projector", "shapes", "linked", "glass", "metals", "principled", "coated", "hair", "measured", "subsurface", "mix", "textured", "fog", "smoke", "volume"];
+
+/// Sets up the scene called `name`. `assets` are the remaining command-line
+/// arguments, for scenes that load files.
+pub fn build_by_name(name: &str, assets: &[String]) -> Result<Demo, String> {
+    match name {
+        "marble" => Ok(marble()),
+        "panel" => Ok(lights::panel()),
+        "lamps" => Ok(lights::lamps()),
+        "environment" => lights::environment(assets),
+        "sky" => lights::sky(assets),
+        "ies" => lights::ies(assets),
+        "projector" => lights::projector(assets),
+        "shapes" => Ok(lights::shapes()),
+        "linked" => Ok(lights::linked()),
+        "glass" => Ok(materials::glass()),
+        "metals" => Ok(materials::metals()),
+        "principled" => Ok(materials::principled()),
+        "coated" => Ok(materials::coated()),
+        "hair" => Ok(materials::hair()),
+        "measured" => materials::measured(assets),
+        "subsurface" => Ok(materials::subsurface()),
+        "mix" => Ok(materials::mix()),
+        "textured" => materials::textured(assets),
+        "fog" => Ok(volumes::fog()),
+        "smoke" => Ok(volumes::smoke()),
+        "volume" => volumes::volume(assets),
+        _ => Err(format!(
+            "unknown scene '{}' (expected one of: {})",
+            name,
+            SCENE_NAMES.join(", ")
+        )),
+    }
+}
+
+/// The `index`th asset argument, or an error naming what the scene expects
+pub fn asset<'a>(assets: &'a [String], index: usize, usage: &str) -> Result<&'a str, String> {
+    assets.get(index).map(String::as_str).ok_or_else(|| format!("usage: {}", usage))
+}
+
+/// Perspective camera at `from` looking at `to`, y up
+pub fn camera(from: Point3, to: Point3) -> PerspectiveCamera {
+    let transform = Transform::look_at(from, to, Vector3::new(0.0, 1.0, 0.0));
+    let res = Point2 { x: RESOLUTION.x as f32, y: RESOLUTION.y as f32 };
+    PerspectiveCamera::new(transform, res, 90.0)
+}
+
+/// Parallelogram with corner `p` and edges `e1`, `e2`, facing e1 x e2
+pub fn rect(p: Point3, e1: Vector3, e2: Vector3) -> Arc<TriangleMesh> {
+    let vertices = vec![p, p + e1, p + e1 + e2, p + e2];
+    Arc::new(TriangleMesh::new(vec![0, 1, 2, 0, 2, 3], vertices, None))
+}
+
+/// Axis-aligned box between `min` and `max`, facing outwards
+pub fn cuboid(min: Point3, max: Point3) -> Arc<TriangleMesh> {
+    let vertices = (0..8)
+        .map(|i| {
+            Point3::new(
+                if i & 1 == 0 { min.x } else { max.x },
+                if i & 2 == 0 { min.y } else { max.y },
+                if i & 4 == 0 { min.z } else { max.z },
+            )
+        })
+        .collect();
+    let faces: [[usize; 4]; 6] = [
+        [0, 4, 6, 2], // -x
+        [1, 3, 7, 5], // +x
+        [0, 1, 5, 4], // -y
+        [2, 6, 7, 3], // +y
+        [0, 2, 3, 1], // -z
+        [4, 5, 7, 6], // +z
+    ];
+    let indices = faces.iter().flat_map(|f| [f[0], f[1], f[2], f[0], f[2], f[3]]).collect();
+    Arc::new(TriangleMesh::new(indices, vertices, None))
+}
+
+// Principled marble triangle under a small triangle light
+fn marble() -> Demo {
+    // Use MarbleTexture as a base color example (could be any texture)
+    let base_tex = Arc::new(MarbleTexture::new(4.0));
+    // Metallic: try 0.0 for dielectric, 1.0 for metal. Change to test metalness.
+    let metallic_tex = Arc::new(ConstantTexture::new(SampledSpectrum::new(0.0))); // 0.0 = dielectric
+    // Roughness: 0.2 roughness for glossy
+    let roughness_tex = Arc::new(ConstantTexture::new(SampledSpectrum::splat(0.2)));
+
+    let principled_mat = Arc::new(PrincipledMaterial::new(base_tex.clone(), metallic_tex.clone(), roughness_tex.clone()));
+
+    // High intensity for small light
+    let light_emit = Arc::new(ConstantTexture::new(SampledSpectrum::new(50.0)));
+    let light_mat = Arc::new(EmissiveMaterial::new(light_emit));
+
+    let mut builder = SceneBuilder::new();
+
+    // A. Marble Triangle (Object)
+    let v_obj = vec![
+        Point3::new(-1.0, -1.0, 0.0),
+        Point3::new( 1.0, -1.0, 0.0),
+        Point3::new( 0.0,  1.0, 0.0),
+    ];
+    let idx_obj = vec![0, 2, 1]; // Normal points -Z (Towards Camera)
+    let mesh_obj = Arc::new(TriangleMesh::new(idx_obj, v_obj, None));
+    builder.add_mesh(mesh_obj, Some(principled_mat), 1.0);
+
+    // B. Area Light (Placed between camera and object)
+    let v_light = vec![
+        Point3::new(-0.5, 1.5, -1.0),
+        Point3::new( 0.5, 1.5, -1.0),
+        Point3::new( 0.0, 1.5, -0.5), // Angled slightly back towards object
+    ];
+    let idx_light = vec![0, 1, 2];
+    let mesh_light = Arc::new(TriangleMesh::new(idx_light, v_light, None));
+
+    // The emissive material makes the builder create the area light
+    builder.add_mesh(mesh_light, Some(light_mat), 1.0);
+
+    Demo::new(builder, camera(Point3::new(0.0, 0.0, -3.0), Point3::new(0.0, 0.0, 0.0)))
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use crate::core::geometry::{Bounds3, Point3, Vector3};
+use crate::core::material::{EmissiveMaterial, Material, MatteMaterial};
+use crate::core::medium::{
+    BlackbodyEmission, GridMedium, HomogeneousMedium, Medium, MediumInterface, NanoVDBMedium, SampledGrid,
+};
+use crate::core::noise::Perlin;
+use crate::core::scene::SceneBuilder;
+use crate::core::spectrum::SampledSpectrum;
+use crate::core::texture::ConstantTexture;
+
+use super::{asset, camera, cuboid, rect, Demo};
+
+// Floor, back wall and an overhead light shared by the volume scenes
+fn stage(builder: &mut SceneBuilder) {
+    let constant = |v: f32| Arc::new(ConstantTexture::new(SampledSpectrum::new(v)));
+    let matte: Arc<dyn Material> = Arc::new(MatteMaterial::new(constant(0.6), constant(0.0)));
+    builder.add_mesh(rect(Point3::new(-4.0, 0.0, -4.0), Vector3::new(0.0, 0.0, 8.0), Vector3::new(8.0, 0.0, 0.0)), Some(matte.clone()), 1.0);
+    builder.add_mesh(rect(Point3::new(-4.0, 0.0, 3.0), Vector3::new(0.0, 4.0, 0.0), Vector3::new(8.0, 0.0, 0.0)), Some(matte), 1.0);
+
+    let light: Arc<dyn Material> = Arc::new(EmissiveMaterial::new(constant(0.15)));
+    builder.add_mesh(rect(Point3::new(-0.5, 3.0, -0.5), Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0)), Some(light), 1.0);
+}
+
+// Invisible box whose inside is filled with `medium`
+fn add_volume(builder: &mut SceneBuilder, min: Point3, max: Point3, medium: Arc<dyn Medium>) {
+    builder.set_medium_interface(Some(MediumInterface::new(Some(medium), None)));
+    builder.add_mesh(cuboid(min, max), None, 1.0);
+    builder.set_medium_interface(None);
+}
+
+/// A block in a box of homogeneous fog
+pub fn fog() -> Demo {
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+    let block: Arc<dyn Material> = Arc::new(MatteMaterial::new(
+        Arc::new(ConstantTexture::new(SampledSpectrum::new(0.3))),
+        Arc::new(ConstantTexture::new(SampledSpectrum::new(20.0))),
+    ));
+    builder.add_mesh(cuboid(Point3::new(-0.4, 0.0, 0.6), Point3::new(0.4, 1.2, 1.4)), Some(block), 1.0);
+
+    let fog = HomogeneousMedium::new(SampledSpectrum::new(0.01), SampledSpectrum::new(0.12), 0.6);
+    add_volume(&mut builder, Point3::new(-2.5, 0.01, -1.5), Point3::new(2.5, 2.8, 2.5), Arc::new(fog));
+    Demo::new(builder, camera(Point3::new(0.0, 1.2, -3.5), Point3::new(0.0, 0.8, 1.0)))
+}
+
+/// A procedural smoke plume whose hot base glows
+pub fn smoke() -> Demo {
+    let mut builder = SceneBuilder::new();
+    stage(&mut builder);
+
+    // Fractal noise inside a cone narrowing upwards; the temperature falls
+    // off with height
+    const N: usize = 32;
+    let noise = Perlin::new();
+    let mut density = Vec::with_capacity(N * N * N);
+    let mut temperature = Vec::with_capacity(N * N * N);
+    for z in 0..N {
+        for y in 0..N {
+            for x in 0..N {
+                let p = Point3::new((x as f32 + 0.5) / N as f32, (y as f32 + 0.5) / N as f32, (z as f32 + 0.5) / N as f32);
+                let r = ((p.x - 0.5).powi(2) + (p.z - 0.5).powi(2)).sqrt() / (0.5 - 0.3 * p.y);
+                let puff = 0.5 + 0.5 * noise.fbm(Point3::new(p.x * 4.0, p.y * 4.0, p.z * 4.0), 4);
+                let d = (puff * (1.0 - r)).max(0.0);
+                density.push(d);
+                temperature.push(if d > 0.0 { 2000.0 * (1.0 - 2.5 * p.y).max(0.0) } else { 0.0 });
+            }
+        }
+    }
+
+    let bounds = Bounds3::new(Point3::new(-1.0, 0.01, -0.5), Point3::new(1.0, 2.5, 1.5));
+    let smoke = GridMedium::new(bounds, SampledSpectrum::new(1.0), SampledSpectrum::new(6.0), 0.3, SampledGrid::new(N, N, N, density))
+        .with_temperature(SampledGrid::new(N, N, N, temperature), BlackbodyEmission::new(2.0));
+    add_volume(&mut builder, bounds.min, bounds.max, Arc::new(smoke));
+    Demo::new(builder, camera(Point3::new(0.0, 1.2, -3.0), Point3::new(0.0, 1.0, 0.5)))
+}
+
+/// A density grid loaded from a NanoVDB file (with blackbody emission if it
+/// has a "temperature" grid) or from a raw dense grid, normalized so its
+/// densest voxel is 1
+pub fn volume(assets: &[String]) -> Result<Demo, String> {
+    const USAGE: &str = "volume <file.nvdb | file.raw nx ny nz>";
+    let filename = asset(assets, 0, USAGE)?;
+    let (sigma_a, sigma_s) = (SampledSpectrum::new(1.0), SampledSpectrum::new(6.0));
+    let (bounds, medium): (Bounds3, Arc<dyn Medium>) = if filename.to_lowercase().ends_with(".nvdb") {
+        let medium = NanoVDBMedium::read(filename, sigma_a, sigma_s, 0.3, 2.0).map_err(|e| e.to_string())?;
+        (medium.bounds, Arc::new(medium))
+    } else {
+        let dim = |i: usize| -> Result<usize, String> {
+            asset(assets, i, USAGE)?.parse().map_err(|_| format!("usage: {}", USAGE))
+        };
+        let grid = SampledGrid::read_raw(filename, dim(1)?, dim(2)?, dim(3)?).map_err(|e| e.to_string())?;
+        let scale = grid.max_value();
+        let (sigma_a, sigma_s) = if scale > 0.0 { (sigma_a * (1.0 / scale), sigma_s * (1.0 / scale)) } else { (sigma_a, sigma_s) };
+        let bounds = Bounds3::new(Point3::new(-1.0, 0.0, -1.0), Point3::new(1.0, 2.0, 1.0));
+        (bounds, Arc::new(GridMedium::new(bounds, sigma_a, sigma_s, 0.3, grid)))
+    };
+
+    // Frame the volume: camera in front, a light above it and a floor below
+    let mut builder = SceneBuilder::new();
+    let center = bounds.centroid();
+    let size = (bounds.max - bounds.min).length();
+    let constant = |v: f32| Arc::new(ConstantTexture::new(SampledSpectrum::new(v)));
+    let floor: Arc<dyn Material> = Arc::new(MatteMaterial::new(constant(0.6), constant(0.0)));
+    let (x0, z0) = (center.x - 2.0 * size, center.z - 2.0 * size);
+    builder.add_mesh(rect(Point3::new(x0, bounds.min.y, z0), Vector3::new(0.0, 0.0, 4.0 * size), Vector3::new(4.0 * size, 0.0, 0.0)), Some(floor), 1.0);
+    let light: Arc<dyn Material> = Arc::new(EmissiveMaterial::new(constant(0.15)));
+    let half = 0.25 * size;
+    let corner = Point3::new(center.x - half, bounds.max.y + 0.5 * size, center.z - half);
+    builder.add_mesh(rect(corner, Vector3::new(2.0 * half, 0.0, 0.0), Vector3::new(0.0, 0.0, 2.0 * half)), Some(light), 1.0);
+    add_volume(&mut builder, bounds.min, bounds.max, medium);
+
+    let eye = Point3::new(center.x, center.y + 0.2 * size, center.z - 1.2 * size);
+    Ok(Demo::new(builder, camera(eye, center)))
+}
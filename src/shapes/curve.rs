@@ -0,0 +1,360 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+
+use crate::core::bsdf::Frame;
+use crate::core::geometry::{Bounds3, Normal3, Point2, Point3, Vector3};
+use crate::core::interaction::{Interaction, SurfaceInteraction};
+use crate::core::primitive::Shape;
+use crate::core::ray::Ray;
+
+// --- Curve Shapes (Hair / Fur / Grass) ---
+// Cubic Bézier curves swept with a width that varies linearly along the
+// curve. Intersection follows pbrt: the curve is projected into a frame
+// where the ray runs down +z from the origin and split recursively until
+// each piece is close to a line segment, which is tested directly.
+
+/// How the curve's width is oriented
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CurveType {
+    /// Ribbon that always faces the ray (cheap far-away hair)
+    Flat,
+    /// Flat ribbon shaded as if it were a cylinder (hair BSDFs need this)
+    Cylinder,
+    /// Ribbon oriented by normals at the two endpoints (grass blades)
+    Ribbon,
+}
+
+// --- The Curve Data ---
+pub struct CurveCommon {
+    pub curve_type: CurveType,
+    pub cp: [Point3; 4],
+    pub width: [f32; 2],
+    n: Option<[Vector3; 2]>,
+    normal_angle: f32,
+    inv_sin_normal_angle: f32,
+}
+
+impl CurveCommon {
+    pub fn new(curve_type: CurveType, cp: [Point3; 4], width0: f32, width1: f32) -> Self {
+        CurveCommon {
+            curve_type,
+            cp,
+            width: [width0, width1],
+            n: None,
+            normal_angle: 0.0,
+            inv_sin_normal_angle: 0.0,
+        }
+    }
+
+    /// Endpoint normals for `CurveType::Ribbon`; without them a ribbon is flat
+    pub fn with_normals(mut self, n0: Normal3, n1: Normal3) -> Self {
+        let (n0, n1) = (Vector3::from(n0).normalize(), Vector3::from(n1).normalize());
+        self.normal_angle = n0.dot(n1).clamp(-1.0, 1.0).acos();
+        self.inv_sin_normal_angle = 1.0 / self.normal_angle.sin();
+        self.n = Some([n0, n1]);
+        self
+    }
+
+    // Ribbon normal at u, slerped between the endpoint normals
+    fn normal(&self, u: f32) -> Option<Vector3> {
+        let [n0, n1] = self.n?;
+        if self.normal_angle < 1e-4 {
+            return Some(n0);
+        }
+        let s0 = ((1.0 - u) * self.normal_angle).sin() * self.inv_sin_normal_angle;
+        let s1 = (u * self.normal_angle).sin() * self.inv_sin_normal_angle;
+        Some(n0 * s0 + n1 * s1)
+    }
+
+    fn width_at(&self, u: f32) -> f32 {
+        lerp(u, self.width[0], self.width[1])
+    }
+}
+
+// --- The Curve Shape ---
+// The [u_min, u_max] piece of a curve; long curves are split into several
+// pieces so each one gets tight bounds.
+#[derive(Clone)]
+pub struct Curve {
+    pub common: Arc<CurveCommon>,
+    pub u_min: f32,
+    pub u_max: f32,
+}
+
+impl Curve {
+    pub fn new(common: Arc<CurveCommon>, u_min: f32, u_max: f32) -> Self {
+        Curve { common, u_min, u_max }
+    }
+
+    /// The whole curve as `n_segments` equal pieces in u
+    pub fn split(common: Arc<CurveCommon>, n_segments: usize) -> Vec<Curve> {
+        let n = n_segments.max(1);
+        (0..n)
+            .map(|i| Curve::new(common.clone(), i as f32 / n as f32, (i + 1) as f32 / n as f32))
+            .collect()
+    }
+
+    fn control_points(&self) -> [Vector3; 4] {
+        let cp = self.common.cp.map(Vector3::from);
+        cubic_bezier_control_points(&cp, self.u_min, self.u_max)
+    }
+
+    fn max_width(&self, u0: f32, u1: f32) -> f32 {
+        self.common.width_at(u0).max(self.common.width_at(u1))
+    }
+
+    // Closest hit with the (sub)curve `cp`, given in ray space, over [u0, u1]
+    #[allow(clippy::too_many_arguments)]
+    fn recursive_intersect(
+        &self,
+        ray: &Ray,
+        ray_frame: &Frame,
+        t_max: f32,
+        cp: &[Vector3; 4],
+        u0: f32,
+        u1: f32,
+        depth: u32,
+    ) -> Option<(f32, SurfaceInteraction)> {
+        let ray_length = ray.d.length();
+
+        if depth > 0 {
+            // Split in half and recurse into the halves whose bounds overlap the ray
+            let split = subdivide_cubic_bezier(cp);
+            let u = [u0, (u0 + u1) / 2.0, u1];
+            let mut closest: Option<(f32, SurfaceInteraction)> = None;
+            for seg in 0..2 {
+                let cps = [split[3 * seg], split[3 * seg + 1], split[3 * seg + 2], split[3 * seg + 3]];
+                let t_limit = closest.as_ref().map_or(t_max, |c| c.0);
+                let half_width = 0.5 * self.max_width(u[seg], u[seg + 1]);
+                if !ray_overlaps(&cps, half_width, ray_length * t_limit) {
+                    continue;
+                }
+                if let Some(hit) = self.recursive_intersect(ray, ray_frame, t_limit, &cps, u[seg], u[seg + 1], depth - 1) {
+                    closest = Some(hit);
+                }
+            }
+            return closest;
+        }
+
+        // Test the ray origin against the perpendiculars at both segment ends
+        let edge = (cp[1].y - cp[0].y) * -cp[0].y + cp[0].x * (cp[0].x - cp[1].x);
+        if edge < 0.0 {
+            return None;
+        }
+        let edge = (cp[2].y - cp[3].y) * -cp[3].y + cp[3].x * (cp[3].x - cp[2].x);
+        if edge < 0.0 {
+            return None;
+        }
+
+        // Parameter w along the segment closest to the ray
+        let segment = Point2 { x: cp[3].x - cp[0].x, y: cp[3].y - cp[0].y };
+        let denom = segment.x * segment.x + segment.y * segment.y;
+        if denom == 0.0 {
+            return None;
+        }
+        let w = (-cp[0].x * segment.x - cp[0].y * segment.y) / denom;
+        let u = lerp(w, u0, u1).clamp(u0, u1);
+
+        // Ribbons look thinner when seen edge-on
+        let mut hit_width = self.common.width_at(u);
+        let ribbon_normal = match self.common.curve_type {
+            CurveType::Ribbon => self.common.normal(u),
+            _ => None,
+        };
+        if let Some(n) = ribbon_normal {
+            hit_width *= n.dot(ray.d).abs() / ray_length;
+        }
+
+        let (pc, dpcdw) = evaluate_cubic_bezier(cp, w.clamp(0.0, 1.0));
+        let dist_sq = pc.x * pc.x + pc.y * pc.y;
+        if dist_sq > hit_width * hit_width * 0.25 {
+            return None;
+        }
+        if pc.z < 0.0 || pc.z > ray_length * t_max {
+            return None;
+        }
+        let t_hit = pc.z / ray_length;
+
+        // v across the width, 0.5 on the centerline
+        let dist = dist_sq.sqrt();
+        let edge_func = dpcdw.x * -pc.y + pc.x * dpcdw.y;
+        let v = if edge_func > 0.0 { 0.5 + dist / hit_width } else { 0.5 - dist / hit_width };
+
+        // Surface derivatives: dpdu along the curve, dpdv across the width
+        let cp_world = self.common.cp.map(Vector3::from);
+        let (_, dpdu) = evaluate_cubic_bezier(&cp_world, u);
+        let dpdv = match ribbon_normal {
+            Some(n) => n.cross(dpdu).normalize() * hit_width,
+            None => {
+                let dpdu_plane = ray_frame.to_local(dpdu);
+                let mut dpdv_plane = Vector3::new(-dpdu_plane.y, dpdu_plane.x, 0.0).normalize() * hit_width;
+                if self.common.curve_type == CurveType::Cylinder {
+                    // Rotate dpdv so the normal sweeps a half cylinder across the width
+                    let theta = lerp(v, -PI / 2.0, PI / 2.0);
+                    dpdv_plane = rotate(dpdv_plane, dpdu_plane.normalize(), -theta);
+                }
+                ray_frame.from_local(dpdv_plane)
+            }
+        };
+        if dpdu.length_squared() == 0.0 || dpdv.length_squared() == 0.0 {
+            return None;
+        }
+
+        let n = Normal3::from(dpdu.cross(dpdv).normalize());
+        let mut si = SurfaceInteraction::new(ray.at(t_hit), Vector3::new(0.0, 0.0, 0.0), Point2 { x: u, y: v }, -ray.d, n, ray.time);
+        si.dpdu = dpdu;
+        si.dpdv = dpdv;
+        si.shading.dpdu = dpdu;
+        si.shading.dpdv = dpdv;
+        Some((t_hit, si))
+    }
+}
+
+impl Shape for Curve {
+    fn bounds(&self) -> Bounds3 {
+        let cp = self.control_points();
+        let half_width = 0.5 * self.max_width(self.u_min, self.u_max);
+        let r = Vector3::new(half_width, half_width, half_width);
+        cp.iter().fold(Bounds3::new(point(cp[0] - r), point(cp[0] + r)), |b, &p| {
+            b.union_point(point(p - r)).union_point(point(p + r))
+        })
+    }
+
+    // Length along the curve times the average width (a ribbon's area)
+    fn area(&self) -> f32 {
+        let cp = self.control_points();
+        let length = (cp[1] - cp[0]).length() + (cp[2] - cp[1]).length() + (cp[3] - cp[2]).length();
+        length * 0.5 * (self.common.width_at(self.u_min) + self.common.width_at(self.u_max))
+    }
+
+    // Uniform in (u, v) rather than area, with the ribbon facing sideways;
+    // good enough for the rare emissive curve
+    fn sample(&self, u: Point2) -> Interaction {
+        let cp = self.control_points();
+        let (p, dpdu) = evaluate_cubic_bezier(&cp, u.x);
+        let side = match self.common.normal(lerp(u.x, self.u_min, self.u_max)) {
+            Some(n) => n.cross(dpdu),
+            None => Frame::from_z(dpdu.normalize()).from_local(Vector3::new(1.0, 0.0, 0.0)),
+        }
+        .normalize();
+        let width = self.common.width_at(lerp(u.x, self.u_min, self.u_max));
+        Interaction {
+            p: point(p + side * ((u.y - 0.5) * width)),
+            time: 0.0,
+            p_error: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            wo: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            n: Normal3::from(dpdu.cross(side).normalize()),
+            uv: Point2 { x: lerp(u.x, self.u_min, self.u_max), y: u.y },
+        }
+    }
+
+    fn intersect(&self, ray: &Ray, t_max: f32) -> Option<(f32, SurfaceInteraction)> {
+        let ray_length = ray.d.length();
+        if ray_length == 0.0 {
+            return None;
+        }
+
+        // Ray space: origin at the ray origin, z down the ray
+        let ray_frame = Frame::from_z(ray.d * (1.0 / ray_length));
+        let o = Vector3::from(ray.o);
+        let cp = self.control_points().map(|p| ray_frame.to_local(p - o));
+
+        let half_width = 0.5 * self.max_width(self.u_min, self.u_max);
+        if !ray_overlaps(&cp, half_width, ray_length * t_max) {
+            return None;
+        }
+
+        // Enough splits that the pieces deviate from straight lines by
+        // less than 5% of the width
+        let l0 = (0..2)
+            .map(|i| {
+                let d = cp[i] - cp[i + 1] * 2.0 + cp[i + 2];
+                d.x.abs().max(d.y.abs()).max(d.z.abs())
+            })
+            .fold(0.0f32, f32::max);
+        let eps = self.common.width[0].max(self.common.width[1]) * 0.05;
+        let r0 = (std::f32::consts::SQRT_2 * 6.0 * l0 / (8.0 * eps)).log2() * 0.5;
+        let max_depth = if r0.is_finite() { r0.ceil().clamp(0.0, 10.0) as u32 } else { 0 };
+
+        self.recursive_intersect(ray, &ray_frame, t_max, &cp, self.u_min, self.u_max, max_depth)
+    }
+}
+
+// --- Bézier Helpers ---
+
+fn lerp(t: f32, a: f32, b: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn point(v: Vector3) -> Point3 {
+    Point3::new(v.x, v.y, v.z)
+}
+
+fn lerp_v(t: f32, a: Vector3, b: Vector3) -> Vector3 {
+    a + (b - a) * t
+}
+
+// Whether ray-space control points, padded by half_width, straddle the
+// ray (the z axis) between 0 and z_max
+fn ray_overlaps(cp: &[Vector3; 4], half_width: f32, z_max: f32) -> bool {
+    let (mut lo, mut hi) = (cp[0], cp[0]);
+    for p in &cp[1..] {
+        lo = Vector3::new(lo.x.min(p.x), lo.y.min(p.y), lo.z.min(p.z));
+        hi = Vector3::new(hi.x.max(p.x), hi.y.max(p.y), hi.z.max(p.z));
+    }
+    lo.x - half_width <= 0.0
+        && hi.x + half_width >= 0.0
+        && lo.y - half_width <= 0.0
+        && hi.y + half_width >= 0.0
+        && hi.z + half_width >= 0.0
+        && lo.z - half_width <= z_max
+}
+
+// Blossom p(u0, u1, u2) of a cubic Bézier
+fn blossom_cubic_bezier(cp: &[Vector3; 4], u0: f32, u1: f32, u2: f32) -> Vector3 {
+    let a = [lerp_v(u0, cp[0], cp[1]), lerp_v(u0, cp[1], cp[2]), lerp_v(u0, cp[2], cp[3])];
+    let b = [lerp_v(u1, a[0], a[1]), lerp_v(u1, a[1], a[2])];
+    lerp_v(u2, b[0], b[1])
+}
+
+// Control points of the [u_min, u_max] piece of a cubic Bézier
+fn cubic_bezier_control_points(cp: &[Vector3; 4], u_min: f32, u_max: f32) -> [Vector3; 4] {
+    [
+        blossom_cubic_bezier(cp, u_min, u_min, u_min),
+        blossom_cubic_bezier(cp, u_min, u_min, u_max),
+        blossom_cubic_bezier(cp, u_min, u_max, u_max),
+        blossom_cubic_bezier(cp, u_max, u_max, u_max),
+    ]
+}
+
+// Both halves of a cubic Bézier; they share the middle point
+fn subdivide_cubic_bezier(cp: &[Vector3; 4]) -> [Vector3; 7] {
+    [
+        cp[0],
+        (cp[0] + cp[1]) * 0.5,
+        (cp[0] + cp[1] * 2.0 + cp[2]) * 0.25,
+        (cp[0] + cp[1] * 3.0 + cp[2] * 3.0 + cp[3]) * 0.125,
+        (cp[1] + cp[2] * 2.0 + cp[3]) * 0.25,
+        (cp[2] + cp[3]) * 0.5,
+        cp[3],
+    ]
+}
+
+// Point and derivative of a cubic Bézier at u (de Casteljau)
+fn evaluate_cubic_bezier(cp: &[Vector3; 4], u: f32) -> (Vector3, Vector3) {
+    let a = [lerp_v(u, cp[0], cp[1]), lerp_v(u, cp[1], cp[2]), lerp_v(u, cp[2], cp[3])];
+    let b = [lerp_v(u, a[0], a[1]), lerp_v(u, a[1], a[2])];
+    let deriv = if (b[1] - b[0]).length_squared() > 0.0 {
+        (b[1] - b[0]) * 3.0
+    } else {
+        // Degenerate ends (coincident control points): use the chord
+        cp[3] - cp[0]
+    };
+    (lerp_v(u, b[0], b[1]), deriv)
+}
+
+// Rotation of v by theta around the unit axis (Rodrigues)
+fn rotate(v: Vector3, axis: Vector3, theta: f32) -> Vector3 {
+    let (sin, cos) = theta.sin_cos();
+    v * cos + axis.cross(v) * sin + axis * (axis.dot(v) * (1.0 - cos))
+}
@@ -0,0 +1,96 @@
+use std::f32::consts::PI;
+
+use crate::core::bsdf::Frame;
+use crate::core::geometry::{Bounds3, Normal3, Point2, Point3, Vector3};
+use crate::core::interaction::{Interaction, SurfaceInteraction};
+use crate::core::primitive::Shape;
+use crate::core::ray::Ray;
+use crate::shapes::disk::circle_bounds;
+
+// --- The Cylinder Shape ---
+// Open (uncapped) cylinder between `p0` and `p1` with outward-facing normals.
+#[derive(Clone)]
+pub struct Cylinder {
+    pub p0: Point3,
+    pub p1: Point3,
+    pub radius: f32,
+    height: f32,
+    frame: Frame, // z = axis from p0 to p1
+}
+
+impl Cylinder {
+    pub fn new(p0: Point3, p1: Point3, radius: f32) -> Self {
+        let axis = p1 - p0;
+        Cylinder { p0, p1, radius, height: axis.length(), frame: Frame::from_z(axis.normalize()) }
+    }
+
+    // Surface point at angle phi and height z along the axis, uv = (phi / 2pi, z / height)
+    fn interaction_at(&self, phi: f32, z: f32) -> Interaction {
+        let n = self.frame.from_local(Vector3::new(phi.cos(), phi.sin(), 0.0));
+        Interaction {
+            p: self.p0 + self.frame.from_local(Vector3::new(0.0, 0.0, z)) + n * self.radius,
+            time: 0.0,
+            p_error: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            wo: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            n: Normal3::from(n),
+            uv: Point2 { x: phi / (2.0 * PI), y: z / self.height },
+        }
+    }
+}
+
+impl Shape for Cylinder {
+    fn bounds(&self) -> Bounds3 {
+        let axis = self.frame.from_local(Vector3::new(0.0, 0.0, 1.0));
+        circle_bounds(self.p0, axis, self.radius).union(circle_bounds(self.p1, axis, self.radius))
+    }
+
+    fn area(&self) -> f32 {
+        2.0 * PI * self.radius * self.height
+    }
+
+    fn sample(&self, u: Point2) -> Interaction {
+        self.interaction_at(2.0 * PI * u.y, u.x * self.height)
+    }
+
+    fn intersect(&self, ray: &Ray, t_max: f32) -> Option<(f32, SurfaceInteraction)> {
+        // Work in the cylinder's frame, where it is x^2 + y^2 = r^2, 0 <= z <= height
+        let o = self.frame.to_local(ray.o - self.p0);
+        let d = self.frame.to_local(ray.d);
+
+        let a = d.x * d.x + d.y * d.y;
+        if a == 0.0 {
+            return None; // Parallel to the axis
+        }
+        let half_b = o.x * d.x + o.y * d.y;
+        let c = o.x * o.x + o.y * o.y - self.radius * self.radius;
+        let discrim = half_b * half_b - a * c;
+        if discrim < 0.0 {
+            return None;
+        }
+        let root = discrim.sqrt();
+        let q = if half_b < 0.0 { -half_b + root } else { -half_b - root };
+        let (mut t0, mut t1) = (q / a, c / q);
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+
+        // Nearest root in range whose hit lies between the end caps
+        for t in [t0, t1] {
+            if t <= 0.0 || t >= t_max || !t.is_finite() {
+                continue;
+            }
+            let z = o.z + t * d.z;
+            if !(0.0..=self.height).contains(&z) {
+                continue;
+            }
+            let mut phi = (o.y + t * d.y).atan2(o.x + t * d.x);
+            if phi < 0.0 {
+                phi += 2.0 * PI;
+            }
+            let it = self.interaction_at(phi, z);
+            let si = SurfaceInteraction::new(it.p, it.p_error, it.uv, -ray.d, it.n, ray.time);
+            return Some((t, si));
+        }
+        None
+    }
+}
@@ -0,0 +1,91 @@
+use std::f32::consts::PI;
+
+use crate::core::bsdf::Frame;
+use crate::core::geometry::{Bounds3, DirectionCone, Normal3, Point2, Point3, Vector3};
+use crate::core::interaction::{Interaction, SurfaceInteraction};
+use crate::core::math::sample_uniform_disk_polar;
+use crate::core::primitive::Shape;
+use crate::core::ray::Ray;
+
+// --- The Disk Shape ---
+// World-space disk facing `normal`; one-sided emitters light the +normal side.
+#[derive(Clone)]
+pub struct Disk {
+    pub center: Point3,
+    pub normal: Vector3,
+    pub radius: f32,
+    frame: Frame, // z = normal
+}
+
+impl Disk {
+    pub fn new(center: Point3, normal: Vector3, radius: f32) -> Self {
+        let normal = normal.normalize();
+        Disk { center, normal, radius, frame: Frame::from_z(normal) }
+    }
+
+    // Surface point at local disk coordinates (x, y), uv = (phi / 2pi, 1 - r / radius)
+    fn interaction_at(&self, x: f32, y: f32) -> Interaction {
+        let mut phi = y.atan2(x);
+        if phi < 0.0 {
+            phi += 2.0 * PI;
+        }
+        let r = (x * x + y * y).sqrt();
+        Interaction {
+            p: self.center + self.frame.from_local(Vector3::new(x, y, 0.0)),
+            time: 0.0,
+            p_error: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            wo: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            n: Normal3::from(self.normal),
+            uv: Point2 { x: phi / (2.0 * PI), y: 1.0 - r / self.radius },
+        }
+    }
+}
+
+/// Bounds of a circle of radius `r` around `center` in the plane with unit normal `n`
+pub(crate) fn circle_bounds(center: Point3, n: Vector3, r: f32) -> Bounds3 {
+    let e = Vector3::new(
+        r * (1.0 - n.x * n.x).max(0.0).sqrt(),
+        r * (1.0 - n.y * n.y).max(0.0).sqrt(),
+        r * (1.0 - n.z * n.z).max(0.0).sqrt(),
+    );
+    Bounds3::new(center + -e, center + e)
+}
+
+impl Shape for Disk {
+    fn bounds(&self) -> Bounds3 {
+        circle_bounds(self.center, self.normal, self.radius)
+    }
+
+    fn area(&self) -> f32 {
+        PI * self.radius * self.radius
+    }
+
+    fn sample(&self, u: Point2) -> Interaction {
+        let pd = sample_uniform_disk_polar(u);
+        self.interaction_at(pd.x * self.radius, pd.y * self.radius)
+    }
+
+    fn normal_bounds(&self) -> DirectionCone {
+        DirectionCone::new(self.normal, 1.0)
+    }
+
+    fn intersect(&self, ray: &Ray, t_max: f32) -> Option<(f32, SurfaceInteraction)> {
+        let denom = ray.d.dot(self.normal);
+        if denom == 0.0 {
+            return None;
+        }
+        let t = (self.center - ray.o).dot(self.normal) / denom;
+        if t <= 0.0 || t >= t_max {
+            return None;
+        }
+
+        let local = self.frame.to_local(ray.at(t) - self.center);
+        if local.x * local.x + local.y * local.y > self.radius * self.radius {
+            return None;
+        }
+
+        let it = self.interaction_at(local.x, local.y);
+        let si = SurfaceInteraction::new(it.p, it.p_error, it.uv, -ray.d, it.n, ray.time);
+        Some((t, si))
+    }
+}
@@ -1 +1,5 @@
-pub mod triangle;
\ No newline at end of file
+pub mod triangle;
+pub mod sphere;
+pub mod disk;
+pub mod cylinder;
+pub mod curve;
@@ -0,0 +1,134 @@
+use std::f32::consts::PI;
+
+use crate::core::bsdf::Frame;
+use crate::core::geometry::{Bounds3, Normal3, Point2, Point3, Vector3};
+use crate::core::interaction::{Interaction, SurfaceInteraction};
+use crate::core::math::{sample_uniform_sphere, uniform_cone_pdf};
+use crate::core::primitive::{area_pdf_as_solid_angle, sample_area_as_solid_angle, Shape};
+use crate::core::ray::Ray;
+
+// --- The Sphere Shape ---
+// World-space sphere with outward-facing normals. As an area light it is
+// sampled uniformly within the cone it subtends from the shading point.
+#[derive(Clone)]
+pub struct Sphere {
+    pub center: Point3,
+    pub radius: f32,
+}
+
+impl Sphere {
+    pub fn new(center: Point3, radius: f32) -> Self {
+        Sphere { center, radius }
+    }
+
+    // Surface point for an outward unit normal n, with uv = (phi / 2pi, theta / pi) around +z
+    fn interaction_at(&self, n: Vector3) -> Interaction {
+        let mut phi = n.y.atan2(n.x);
+        if phi < 0.0 {
+            phi += 2.0 * PI;
+        }
+        let theta = n.z.clamp(-1.0, 1.0).acos();
+        Interaction {
+            p: self.center + n * self.radius,
+            time: 0.0,
+            p_error: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            wo: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            n: Normal3::from(n),
+            uv: Point2 { x: phi / (2.0 * PI), y: theta / PI },
+        }
+    }
+
+    // cos of the half-angle of the cone subtended from p, None if p is inside
+    fn cos_theta_max(&self, p: Point3) -> Option<f32> {
+        let dist_sq = (self.center - p).length_squared();
+        let r_sq = self.radius * self.radius;
+        if dist_sq <= r_sq {
+            return None;
+        }
+        Some((1.0 - r_sq / dist_sq).max(0.0).sqrt())
+    }
+}
+
+impl Shape for Sphere {
+    fn bounds(&self) -> Bounds3 {
+        let r = Vector3::new(self.radius, self.radius, self.radius);
+        Bounds3::new(self.center + -r, self.center + r)
+    }
+
+    fn area(&self) -> f32 {
+        4.0 * PI * self.radius * self.radius
+    }
+
+    fn sample(&self, u: Point2) -> Interaction {
+        self.interaction_at(sample_uniform_sphere(u))
+    }
+
+    fn sample_solid_angle(&self, ctx: &Interaction, u: Point2) -> Option<(Interaction, f32)> {
+        let Some(cos_theta_max) = self.cos_theta_max(ctx.p) else {
+            return sample_area_as_solid_angle(self, ctx, u);
+        };
+        let dist = (self.center - ctx.p).length();
+        let sin_theta_max = self.radius / dist;
+        let sin2_theta_max = sin_theta_max * sin_theta_max;
+
+        // Angle theta from the cone axis; tiny cones use a Taylor expansion
+        // to avoid cancellation in 1 - cos_theta_max
+        let mut one_minus_cos_theta_max = 1.0 - cos_theta_max;
+        let mut cos_theta = (cos_theta_max - 1.0) * u.x + 1.0;
+        let mut sin2_theta = 1.0 - cos_theta * cos_theta;
+        if sin2_theta_max < 0.000_685 {
+            sin2_theta = sin2_theta_max * u.x;
+            cos_theta = (1.0 - sin2_theta).sqrt();
+            one_minus_cos_theta_max = sin2_theta_max / 2.0;
+        }
+
+        // Angle alpha from the sphere center to the point hit along that direction
+        let cos_alpha = sin2_theta / sin_theta_max
+            + cos_theta * (1.0 - sin2_theta / sin2_theta_max).max(0.0).sqrt();
+        let sin_alpha = (1.0 - cos_alpha * cos_alpha).max(0.0).sqrt();
+        let phi = 2.0 * PI * u.y;
+
+        let frame = Frame::from_z((self.center - ctx.p).normalize());
+        let n = frame.from_local(Vector3::new(-sin_alpha * phi.cos(), -sin_alpha * phi.sin(), -cos_alpha));
+        Some((self.interaction_at(n), 1.0 / (2.0 * PI * one_minus_cos_theta_max)))
+    }
+
+    fn pdf_solid_angle(&self, ctx: &Interaction, wi: Vector3) -> f32 {
+        match self.cos_theta_max(ctx.p) {
+            // Directions outside the cone miss the sphere
+            Some(cos_theta_max) if wi.normalize().dot((self.center - ctx.p).normalize()) < cos_theta_max => 0.0,
+            Some(cos_theta_max) => uniform_cone_pdf(cos_theta_max),
+            None => area_pdf_as_solid_angle(self, ctx, wi),
+        }
+    }
+
+    fn intersect(&self, ray: &Ray, t_max: f32) -> Option<(f32, SurfaceInteraction)> {
+        // Solve |o + t d - c|^2 = r^2, with the discriminant computed from the
+        // closest approach to reduce cancellation for distant spheres
+        let oc = ray.o - self.center;
+        let a = ray.d.length_squared();
+        let half_b = oc.dot(ray.d);
+        let c = oc.length_squared() - self.radius * self.radius;
+        let l = oc - ray.d * (half_b / a);
+        let discrim = a * (self.radius * self.radius - l.length_squared());
+        if discrim < 0.0 {
+            return None;
+        }
+        let root = discrim.sqrt();
+        let q = if half_b < 0.0 { -half_b + root } else { -half_b - root };
+        let (mut t0, mut t1) = (q / a, c / q);
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+
+        let t = if t0 > 0.0 { t0 } else { t1 };
+        if t <= 0.0 || t >= t_max || !t.is_finite() {
+            return None;
+        }
+
+        let n = (ray.at(t) - self.center).normalize();
+        let it = self.interaction_at(n);
+        let si = SurfaceInteraction::new(it.p, it.p_error, it.uv, -ray.d, it.n, ray.time);
+        Some((t, si))
+    }
+}
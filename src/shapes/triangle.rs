@@ -1,35 +1,49 @@
 use std::sync::Arc;
 
-use crate::core::geometry::{Point3, Vector3, Normal3, Point2, Bounds3};
+use crate::core::geometry::{Point3, Vector3, Normal3, Point2, Bounds3, DirectionCone};
 use crate::core::ray::Ray;
-use crate::core::interaction::{SurfaceInteraction};
-use crate::core::math::{difference_of_products, sample_uniform_triangle};
-use crate::core::primitive::Shape;
+use crate::core::interaction::{Interaction, SurfaceInteraction};
+use crate::core::math::{
+    difference_of_products, sample_spherical_triangle, sample_uniform_triangle, spherical_triangle_area,
+};
+use crate::core::primitive::{area_pdf_as_solid_angle, sample_area_as_solid_angle, Shape};
+
+// Spherical sampling is only worthwhile (and numerically stable) for triangles
+// subtending a moderate solid angle; outside this range fall back to area sampling
+const MIN_SPHERICAL_SAMPLE_AREA: f32 = 3e-4;
+const MAX_SPHERICAL_SAMPLE_AREA: f32 = 6.22;
 
 // --- The Mesh Data ---
 pub struct TriangleMesh {
     pub n_triangles: usize,
     pub vertex_indices: Vec<usize>,
     pub p: Vec<Point3>,
-    pub n: Option<Vec<Normal3>>,
     pub uv: Option<Vec<Point2>>,
+    /// Sample emitters by the solid angle they subtend instead of by area
+    pub solid_angle_sampling: bool,
 }
 
 impl TriangleMesh {
     pub fn new(
         indices: Vec<usize>,
         p: Vec<Point3>,
-        n: Option<Vec<Normal3>>,
         uv: Option<Vec<Point2>>,
     ) -> Self {
         TriangleMesh {
             n_triangles: indices.len() / 3,
             vertex_indices: indices,
             p,
-            n,
             uv,
+            solid_angle_sampling: false,
         }
     }
+
+    /// Enables spherical-triangle sampling for light sampling of this mesh,
+    /// which reduces noise from large emitters close to the shading point
+    pub fn with_solid_angle_sampling(mut self, enabled: bool) -> Self {
+        self.solid_angle_sampling = enabled;
+        self
+    }
 }
 
 // --- The Triangle Shape ---
@@ -46,6 +60,54 @@ impl Triangle {
             v_index: tri_number * 3,
         }
     }
+
+    fn vertices(&self) -> [Point3; 3] {
+        let idx = &self.mesh.vertex_indices;
+        [self.mesh.p[idx[self.v_index]], self.mesh.p[idx[self.v_index + 1]], self.mesh.p[idx[self.v_index + 2]]]
+    }
+
+    // Solid angle subtended from `p`, if spherical sampling should be used there
+    fn spherical_sample_area(&self, p: Point3) -> Option<f32> {
+        if !self.mesh.solid_angle_sampling {
+            return None;
+        }
+        let [p0, p1, p2] = self.vertices();
+        let area = spherical_triangle_area((p0 - p).normalize(), (p1 - p).normalize(), (p2 - p).normalize());
+        (MIN_SPHERICAL_SAMPLE_AREA..=MAX_SPHERICAL_SAMPLE_AREA).contains(&area).then_some(area)
+    }
+
+    // Surface point with barycentrics `b` (geometric normal, interpolated uv)
+    fn interaction_at(&self, b: [f32; 3]) -> Interaction {
+        let [b0, b1, b2] = b;
+        let idx = &self.mesh.vertex_indices;
+        let [p0, p1, p2] = self.vertices();
+
+        // P = P0 + b1*(P1-P0) + b2*(P2-P0)
+        let p = p0 + (p1 - p0) * b1 + (p2 - p0) * b2;
+
+        // Geometric normal (not shading normal)
+        let n = Normal3::from((p1 - p0).cross(p2 - p0).normalize());
+
+        // Same uv interpolation as intersect()
+        let uv = if let Some(uvs) = &self.mesh.uv {
+            let (uv0, uv1, uv2) = (uvs[idx[self.v_index]], uvs[idx[self.v_index + 1]], uvs[idx[self.v_index + 2]]);
+            Point2 {
+                x: b0 * uv0.x + b1 * uv1.x + b2 * uv2.x,
+                y: b0 * uv0.y + b1 * uv1.y + b2 * uv2.y,
+            }
+        } else {
+            Point2 { x: 0.0, y: 0.0 }
+        };
+
+        Interaction {
+            p,
+            time: 0.0,
+            p_error: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            wo: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            n,
+            uv,
+        }
+    }
 }
 
 impl Shape for Triangle {
@@ -66,26 +128,36 @@ impl Shape for Triangle {
         0.5 * (p1 - p0).cross(p2 - p0).length()
     }
 
-    fn sample(&self, u: Point2) -> (Point3, Normal3) {
+    fn sample(&self, u: Point2) -> Interaction {
         // Area-preserving barycentric sampling
         let b = sample_uniform_triangle(u);
-        let b0 = b.x;
-        let b1 = b.y;
-        let b2 = 1.0 - b0 - b1;
+        self.interaction_at([b.x, b.y, 1.0 - b.x - b.y])
+    }
+
+    fn sample_solid_angle(&self, ctx: &Interaction, u: Point2) -> Option<(Interaction, f32)> {
+        if self.spherical_sample_area(ctx.p).is_none() {
+            return sample_area_as_solid_angle(self, ctx, u);
+        }
+        let (b, pdf) = sample_spherical_triangle(self.vertices(), ctx.p, u)?;
+        Some((self.interaction_at(b), pdf))
+    }
+
+    fn pdf_solid_angle(&self, ctx: &Interaction, wi: Vector3) -> f32 {
+        let Some(solid_angle) = self.spherical_sample_area(ctx.p) else {
+            return area_pdf_as_solid_angle(self, ctx, wi);
+        };
+        if self.intersect(&ctx.spawn_ray(wi), f32::INFINITY).is_none() {
+            return 0.0;
+        }
+        1.0 / solid_angle
+    }
 
+    fn normal_bounds(&self) -> DirectionCone {
         let idx = &self.mesh.vertex_indices;
         let p0 = self.mesh.p[idx[self.v_index]];
         let p1 = self.mesh.p[idx[self.v_index + 1]];
         let p2 = self.mesh.p[idx[self.v_index + 2]];
-
-        // FIX: Point interpolation using vectors
-        // P = P0 + b1*(P1-P0) + b2*(P2-P0)
-        let p = p0 + (p1 - p0) * b1 + (p2 - p0) * b2;
-
-        // Geometric normal (not shading normal)
-        let n = Normal3::from((p1 - p0).cross(p2 - p0).normalize());
-
-        (p, n)
+        DirectionCone::new((p1 - p0).cross(p2 - p0), 1.0)
     }
 
     fn intersect(&self, ray: &Ray, t_max: f32) -> Option<(f32, SurfaceInteraction)> {
@@ -194,7 +266,7 @@ impl Shape for Triangle {
         let p_hit = ray.at(t);
         let p_error = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
 
-        let interaction = SurfaceInteraction::new(
+        let mut interaction = SurfaceInteraction::new(
             p_hit,
             p_error,
             uv,
@@ -202,6 +274,7 @@ impl Shape for Triangle {
             n_geom,
             ray.time,
         );
+        interaction.barycentrics = Some([b0, b1, b2]);
 
         Some((t, interaction))
     }